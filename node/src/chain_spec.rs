@@ -54,7 +54,33 @@ use serde::{Deserialize, Serialize};
 ///       "5HpG9w8EBLe5XCrbczpwq5TSXvedjrBGCwqxK1iQ7qUsSWFc",
 ///       "1000000000000000000"
 ///     ]
-///   ]
+///   ],
+///   "offchainComputingInfra": { // Optional, see `GenesisConfig` of the infra pallet
+///     "impls": [
+///       [101, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", "OptOut"]
+///     ],
+///     "implBuilds": [
+///       [101, 1, null]
+///     ],
+///     "workers": [
+///       [
+///         "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", // SS58 format of the owner
+///         "5HpG9w8EBLe5XCrbczpwq5TSXvedjrBGCwqxK1iQ7qUsSWFc", // SS58 format of the worker
+///         101
+///       ]
+///     ]
+///   },
+///   "offchainComputingPool": { // Optional, see `GenesisConfig` of the pool pallet
+///     "pools": [
+///       [101, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", 101, "DemoOnly", true, false]
+///     ],
+///     "jobPolicies": [
+///       [101, 1, "Public", null, null]
+///     ],
+///     "authorizedWorkers": [
+///       [101, "5HpG9w8EBLe5XCrbczpwq5TSXvedjrBGCwqxK1iQ7qUsSWFc"]
+///     ]
+///   }
 /// }
 /// ```
 #[derive(Deserialize, Debug, Clone)]
@@ -63,6 +89,8 @@ struct GenesisConfigProfile {
 	root_key: AccountId,
 	initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
 	endowed_accounts: Vec<(AccountId, String)>,
+	offchain_computing_infra: Option<serde_json::Value>,
+	offchain_computing_pool: Option<serde_json::Value>,
 }
 
 // The URL for the telemetry server.
@@ -129,23 +157,23 @@ fn chain_spec_for(
 		.with_id(id)
 		.with_chain_type(chain_type)
 		.with_properties(chain_properties())
-		.with_genesis_config_patch(
-			genesis_config(
-				// Initial PoA authorities
-				genesis_profile.initial_authorities,
-				// Sudo account
-				genesis_profile.root_key,
-				// Pre-funded accounts
-				genesis_profile
-					.endowed_accounts
-					.into_iter()
-					.map(|(k, amount)| (k, u128::from_str(&amount).expect("Bad amount")))
-					.collect(),
-				true,
-			)
-		)
-		.build()
-	)
+		.with_genesis_config_patch(genesis_config(
+			// Initial PoA authorities
+			genesis_profile.initial_authorities,
+			// Sudo account
+			genesis_profile.root_key,
+			// Pre-funded accounts
+			genesis_profile
+				.endowed_accounts
+				.into_iter()
+				.map(|(k, amount)| (k, u128::from_str(&amount).expect("Bad amount")))
+				.collect(),
+			// Pre-seeded off-chain computing implementations, workers and pools
+			genesis_profile.offchain_computing_infra,
+			genesis_profile.offchain_computing_pool,
+			true,
+		))
+		.build())
 }
 
 /// Configure initial storage state for FRAME modules.
@@ -153,6 +181,8 @@ fn genesis_config(
 	initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<(AccountId, u128)>,
+	offchain_computing_infra: Option<serde_json::Value>,
+	offchain_computing_pool: Option<serde_json::Value>,
 	_enable_println: bool,
 ) -> serde_json::Value {
 	assert!(
@@ -167,7 +197,7 @@ fn genesis_config(
 		"All the genesis accounts must be endowed; qed."
 	);
 
-	serde_json::json!({
+	let mut config = serde_json::json!({
 		"balances": {
 			"balances": endowed_accounts,
 		},
@@ -180,7 +210,16 @@ fn genesis_config(
 		"sudo": {
 			"key": Some(root_key),
 		},
-	})
+	});
+
+	if let Some(offchain_computing_infra) = offchain_computing_infra {
+		config["offchainComputingInfra"] = offchain_computing_infra;
+	}
+	if let Some(offchain_computing_pool) = offchain_computing_pool {
+		config["offchainComputingPool"] = offchain_computing_pool;
+	}
+
+	config
 }
 
 fn chain_properties() -> Properties {
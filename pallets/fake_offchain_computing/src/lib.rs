@@ -102,10 +102,14 @@ pub mod pallet {
 		WorkerNotExists,
 	}
 
+	// This pallet is only for testing the lifecycle hooks, so calls are weighed by a fixed
+	// execution time plus their storage accesses instead of benchmarks
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(
+			Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 1))
+		)]
 		pub fn start(origin: OriginFor<T>, worker: T::AccountId) -> DispatchResult {
 			Self::ensure_owner_or_root(origin, &worker)?;
 
@@ -119,7 +123,9 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(
+			Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1))
+		)]
 		pub fn stop(origin: OriginFor<T>, worker: T::AccountId) -> DispatchResult {
 			Self::ensure_owner_or_root(origin, &worker)?;
 
@@ -161,7 +167,11 @@ pub mod pallet {
 			Ok(())
 		}
 
-		fn after_online(worker: &T::AccountId) {
+		fn after_online(
+			worker: &T::AccountId,
+			_payload: &OnlinePayload<T::ImplId>,
+			_verified_attestation: &VerifiedAttestation,
+		) {
 			log!(info, "after_online: {:?}", worker);
 
 			<RunningWorkers<T>>::insert(worker, ());
@@ -204,6 +214,24 @@ pub mod pallet {
 			log!(info, "after_refresh_attestation: {:?}", worker);
 		}
 
+		fn can_upgrade_build(
+			worker: &T::AccountId,
+			_payload: &OnlinePayload<T::ImplId>,
+			_verified_attestation: &VerifiedAttestation,
+		) -> DispatchResult {
+			log!(info, "can_upgrade_build: {:?}", worker);
+
+			ensure!(!<BlockedWorkers<T>>::contains_key(worker), Error::<T>::Blocked);
+
+			Ok(())
+		}
+
+		fn can_decrease_deposit(worker: &T::AccountId) -> DispatchResult {
+			log!(info, "can_decrease_deposit: {:?}", worker);
+
+			Ok(())
+		}
+
 		fn after_requesting_offline(worker: &T::AccountId) {
 			log!(info, "after_requesting_offline: {:?}", worker);
 		}
@@ -22,12 +22,9 @@ use frame_support::{
 	assert_ok, derive_impl, parameter_types,
 	traits::{OnFinalize, OnInitialize},
 };
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::{ConstBool, ConstU128, ConstU16, ConstU32, ConstU64};
-use sp_runtime::{
-	traits::IdentityLookup,
-	BuildStorage,
-};
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -98,14 +95,20 @@ impl pallet_offchain_computing_infra::Config for Test {
 	type Randomness = RandomnessCollectiveFlip;
 	type ImplId = u32;
 	type RegisterImplOrigin = EnsureSigned<Self::AccountId>;
+	type WorkerForceOrigin = EnsureRoot<Self::AccountId>;
 	type RegisterWorkerDeposit = ConstU128<{ 100 * DOLLARS }>;
 	type RegisterImplDeposit = ConstU128<{ DOLLARS }>;
 	type ImplMetadataDepositBase = ConstU128<{ DOLLARS }>;
 	type ImplMetadataDepositPerByte = ConstU128<{ CENTS }>;
 	type ImplMetadataLimit = ConstU32<50>;
 	type MaxImplBuilds = ConstU32<4>;
+	type MaxImplBuildPublishers = ConstU32<2>;
 	type HandleUnresponsivePerBlockLimit = ConstU32<3>;
 	type CollectingHeartbeatsDurationInBlocks = ConstU32<6>;
+	type MinCollectingHeartbeatsDurationInBlocks = ConstU32<4>;
+	type WorkerDepositUnbondingDurationInBlocks = ConstU32<10>;
+	type ReapWorkerAfterInactiveBlocks = ConstU32<20>;
+	type WorkerReaperFee = ConstU128<{ DOLLARS }>;
 	type DisallowOptOutAttestation = ConstBool<false>;
 	type WeightInfo = ();
 	type OffchainWorkerLifecycleHooks = ();
@@ -23,6 +23,7 @@ use std::fmt;
 
 use scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::{
 	bounded::{BoundedVec, ConstU32, Get},
 	RuntimeDebug,
@@ -31,6 +32,19 @@ use sp_core::{
 pub type ImplBuildVersion = u32;
 pub type ImplBuildMagicBytes = BoundedVec<u8, ConstU32<64>>;
 pub type ImplSpecVersion = u32;
+/// The curve25519 public key which the worker published for receiving encrypted messages.
+pub type EncryptionPublicKey = [u8; 32];
+pub type WorkerCapabilityKey = BoundedVec<u8, ConstU32<32>>;
+/// The capabilities that a worker declared, at most 16 entries.
+pub type WorkerCapabilities = BoundedVec<WorkerCapability, ConstU32<16>>;
+
+/// A capability of the worker, the value is a resource number (e.g. `memory_mb` => `16384`),
+/// or `1` for a tag (e.g. `api.example.com` => `1`).
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct WorkerCapability {
+	pub key: WorkerCapabilityKey,
+	pub value: u64,
+}
 
 #[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
 pub enum OfflineReason {
@@ -44,7 +58,18 @@ pub enum OfflineReason {
 }
 
 /// The type of how the worker do attestation
-#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
 pub enum AttestationMethod {
 	/// Opt out, should only use for dev and testing
 	OptOut,
@@ -112,6 +137,12 @@ pub struct OnlinePayload<ImplId> {
 	pub impl_spec_version: ImplBuildVersion,
 	pub impl_build_version: ImplBuildVersion,
 	pub impl_build_magic_bytes: ImplBuildMagicBytes,
+	/// The encryption public key will be published with the attestation
+	pub encryption_public_key: Option<EncryptionPublicKey>,
+	/// The capabilities will be attested if the attestation carries the payload's signature
+	pub capabilities: WorkerCapabilities,
+	/// How many jobs the worker could process concurrently, `None` for the global maximum
+	pub job_slots: Option<u32>,
 }
 
 /// Worker's status
@@ -162,8 +193,7 @@ pub struct WorkerInfo<AccountId, Balance, ImplId> {
 	/// Account that owning the worker.
 	/// This field is readonly once set
 	pub owner: AccountId,
-	/// Reserved balance on register.
-	/// This field is readonly once set
+	/// Reserved balance, it's set on register and the owner could increase or decrease it.
 	pub deposit: Balance,
 	/// Status
 	pub status: WorkerStatus,
@@ -186,15 +216,48 @@ pub struct WorkerInfo<AccountId, Balance, ImplId> {
 	pub uptime: Option<u64>,
 }
 
+/// The worker's deposit decreased by the owner, it's still held until `unlock_at`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct WorkerDepositUnbonding<Balance, BlockNumber> {
+	/// The amount to withdraw
+	pub amount: Balance,
+	/// The block that the amount could be withdrawn
+	pub unlock_at: BlockNumber,
+}
+
+/// The window that an online worker should send its next heartbeat.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct HeartbeatWindow<BlockNumber> {
+	/// The worker can't send the heartbeat before this block
+	pub next_heartbeat: BlockNumber,
+	/// The worker will be unresponsive at this block if it hasn't sent the heartbeat
+	pub deadline: BlockNumber,
+}
+
+/// How often workers of an implementation have to send heartbeats, relative to the runtime's
+/// collecting heartbeats duration.
 #[derive(
 	Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Default,
 )]
-pub enum FlipFlopStage {
+pub enum HeartbeatPeriodClass {
+	/// Half of the collecting heartbeats duration
+	Fast,
+	/// The collecting heartbeats duration
 	#[default]
-	Flip,
-	Flop,
-	FlipToFlop,
-	FlopToFlip,
+	Standard,
+	/// Twice the collecting heartbeats duration
+	Slow,
+}
+
+impl HeartbeatPeriodClass {
+	/// The collecting heartbeats duration of this class, derived from the standard duration.
+	pub fn collecting_duration(&self, standard: u32) -> u32 {
+		match self {
+			HeartbeatPeriodClass::Fast => standard / 2,
+			HeartbeatPeriodClass::Standard => standard,
+			HeartbeatPeriodClass::Slow => standard.saturating_mul(2),
+		}
+	}
 }
 
 /// Information about an implementation.
@@ -211,6 +274,10 @@ pub struct ImplInfo<ImplId, AccountId, Balance> {
 	pub attestation_method: AttestationMethod,
 	/// The total number of outstanding workers running this implementation.
 	pub workers_count: u32,
+	/// The minimum deposit a worker of this implementation has to hold.
+	pub worker_deposit_floor: Balance,
+	/// How often workers of this implementation have to send heartbeats.
+	pub heartbeat_period_class: HeartbeatPeriodClass,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
@@ -220,6 +287,25 @@ pub enum ImplBuildStatus {
 	Deprecated,
 	/// Blocked will forbid workers online, and heartbeat, so they have to upgrade
 	Retired,
+	/// Only workers admitted by the build's canary rollout could online with this build
+	Canary,
+}
+
+/// How a canary build rolls out to workers
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CanaryRollout<AccountId> {
+	/// At most this number of workers could run the build
+	MaxWorkers(u32),
+	/// Only the listed workers could run the build, at most 32 workers
+	AllowList(BoundedVec<AccountId, ConstU32<32>>),
+}
+
+/// The results of jobs processed by workers running a build
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+pub struct ImplBuildHealth {
+	pub jobs_succeeded: u64,
+	/// Jobs that not reported success, including the retried ones
+	pub jobs_failed: u64,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
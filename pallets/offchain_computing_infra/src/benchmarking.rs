@@ -23,14 +23,16 @@
 
 use frame_benchmarking::{account, impl_benchmark_test_suite, v2::*, whitelisted_caller};
 use frame_support::{
-	assert_ok, fail,
-	traits::{fungible::Mutate, tokens::Fortitude},
+	assert_ok,
+	traits::{fungible::Mutate, tokens::Fortitude, EnsureOrigin, Hooks},
+	BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, Account, RawOrigin};
 use sp_runtime::{
 	app_crypto::{sr25519, KeyTypeId, RuntimePublic},
 	SaturatedConversion, Saturating,
 };
+use sp_std::prelude::*;
 
 use primitives::{AttestationMethod, OnlinePayload};
 
@@ -73,6 +75,9 @@ fn mock_online_payload_and_attestation<T: Config>(
 		impl_spec_version: 1,
 		impl_build_version: 1,
 		impl_build_magic_bytes: Default::default(),
+		encryption_public_key: None,
+		capabilities: Default::default(),
+		job_slots: None,
 	};
 
 	let attestation = Attestation::OptOut;
@@ -127,6 +132,29 @@ fn add_mock_online_worker<T: Config>(
 	worker
 }
 
+fn set_mock_impl_metadata<T: Config>(owner: &T::AccountId, impl_id: T::ImplId) {
+	let metadata: BoundedVec<u8, T::ImplMetadataLimit> =
+		vec![0u8; T::ImplMetadataLimit::get() as usize].try_into().unwrap();
+	assert_ok!(OffchainComputingInfra::<T>::update_impl_metadata(
+		RawOrigin::Signed(owner.clone()).into(),
+		impl_id,
+		Some(metadata)
+	));
+}
+
+fn fund_impl_owner<T: Config>(owner: &T::AccountId) {
+	let balance = T::ImplMetadataDepositPerByte::get()
+		.saturating_mul(T::ImplMetadataLimit::get().into())
+		.saturating_add(T::ImplMetadataDepositBase::get())
+		.saturating_add((100 * DOLLARS).saturated_into::<BalanceOf<T>>());
+	T::Currency::set_balance(&owner, balance);
+}
+
+fn advance_blocks<T: Config>(blocks: u32) {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now.saturating_add(blocks.into()));
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -178,6 +206,45 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// This is the slow path, the worker shall offline first
+	#[benchmark]
+	fn force_deregister_worker() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
+		let worker = add_mock_online_worker::<T>(&worker_public, &owner, None);
+		let origin = T::WorkerForceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(worker.clone()));
+
+		assert_eq!(Workers::<T>::contains_key(&worker), false);
+		assert_eq!(WorkerHeartbeatWindows::<T>::contains_key(&worker), false);
+
+		Ok(())
+	}
+
+	// This is the slow path, the unresponsive worker shall offline first
+	#[benchmark]
+	fn reap_worker() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
+		let worker = add_mock_online_worker::<T>(&worker_public, &owner, None);
+		OffchainComputingInfra::<T>::set_worker_unresponsive(&worker);
+		advance_blocks::<T>(T::ReapWorkerAfterInactiveBlocks::get());
+
+		let reaper: T::AccountId = account("reaper", 0, 0);
+		T::Currency::set_balance(&reaper, T::Currency::minimum_balance());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(reaper.clone()), T::Lookup::unlookup(worker.clone()));
+
+		assert_eq!(Workers::<T>::contains_key(&worker), false);
+		assert_eq!(WorkerInactiveSince::<T>::contains_key(&worker), false);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn transfer_to_worker() -> Result<(), BenchmarkError> {
 		let owner: T::AccountId = whitelisted_caller();
@@ -222,6 +289,83 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn increase_worker_deposit() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
+		let worker = add_mock_worker::<T>(&worker_public, &owner, impl_id);
+
+		let deposit = Workers::<T>::get(&worker).expect("WorkerInfo should has value").deposit;
+		let amount = (10 * DOLLARS).saturated_into::<BalanceOf<T>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), T::Lookup::unlookup(worker.clone()), amount);
+
+		let worker_info = Workers::<T>::get(&worker).expect("WorkerInfo should has value");
+		assert_eq!(worker_info.deposit, deposit.saturating_add(amount));
+
+		Ok(())
+	}
+
+	// This is the slow path, there is an unbonding deposit already
+	#[benchmark]
+	fn decrease_worker_deposit() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
+		let worker = add_mock_worker::<T>(&worker_public, &owner, impl_id);
+
+		let amount = (5 * DOLLARS).saturated_into::<BalanceOf<T>>();
+		assert_ok!(OffchainComputingInfra::<T>::increase_worker_deposit(
+			RawOrigin::Signed(owner.clone()).into(),
+			T::Lookup::unlookup(worker.clone()),
+			amount.saturating_add(amount)
+		));
+		assert_ok!(OffchainComputingInfra::<T>::decrease_worker_deposit(
+			RawOrigin::Signed(owner.clone()).into(),
+			T::Lookup::unlookup(worker.clone()),
+			amount
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), T::Lookup::unlookup(worker.clone()), amount);
+
+		let unbonding =
+			WorkerDepositUnbondings::<T>::get(&worker).expect("Unbonding should has value");
+		assert_eq!(unbonding.amount, amount.saturating_add(amount));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_unbonded_worker_deposit() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
+		let worker = add_mock_worker::<T>(&worker_public, &owner, impl_id);
+
+		let amount = (10 * DOLLARS).saturated_into::<BalanceOf<T>>();
+		assert_ok!(OffchainComputingInfra::<T>::increase_worker_deposit(
+			RawOrigin::Signed(owner.clone()).into(),
+			T::Lookup::unlookup(worker.clone()),
+			amount
+		));
+		assert_ok!(OffchainComputingInfra::<T>::decrease_worker_deposit(
+			RawOrigin::Signed(owner.clone()).into(),
+			T::Lookup::unlookup(worker.clone()),
+			amount
+		));
+		advance_blocks::<T>(T::WorkerDepositUnbondingDurationInBlocks::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), T::Lookup::unlookup(worker.clone()));
+
+		assert_eq!(WorkerDepositUnbondings::<T>::contains_key(&worker), false);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn online() -> Result<(), BenchmarkError> {
 		let owner: T::AccountId = whitelisted_caller();
@@ -271,6 +415,33 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn upgrade_build() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
+		let worker = add_mock_online_worker::<T>(&worker_public, &owner, Some(impl_id.clone()));
+		assert_ok!(OffchainComputingInfra::<T>::register_impl_build(
+			RawOrigin::Signed(owner.clone()).into(),
+			impl_id.clone(),
+			2u32,
+			None
+		));
+		let (mut payload, attestation) =
+			mock_online_payload_and_attestation::<T>(&worker_public, impl_id.clone());
+		payload.impl_build_version = 2;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(worker.clone()), payload, attestation);
+
+		let worker_info = Workers::<T>::get(&worker).expect("WorkerInfo should has value");
+		assert_eq!(worker_info.impl_build_version, Some(2));
+		assert_eq!(ImplBuilds::<T>::get(&impl_id, 1).map(|info| info.workers_count), Some(0));
+		assert_eq!(ImplBuilds::<T>::get(&impl_id, 2).map(|info| info.workers_count), Some(1));
+
+		Ok(())
+	}
+
 	// This is the slow path,
 	// worker shall offline immediately instead of becoming `RequestingOffline`
 	#[benchmark]
@@ -342,43 +513,338 @@ mod benchmarks {
 		let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
 		let worker = add_mock_online_worker::<T>(&worker_public, &owner, None);
 
-		let stage = FlipOrFlop::<T>::get();
-		// Simulate to the next stage
-		match stage {
-			FlipFlopStage::Flip => {
-				assert_eq!(FlopSet::<T>::contains_key(&worker), true);
-				FlopSet::<T>::insert(&worker, BlockNumberFor::<T>::zero());
-				FlipOrFlop::<T>::set(FlipFlopStage::Flop);
-			},
-			FlipFlopStage::Flop => {
-				assert_eq!(FlipSet::<T>::contains_key(&worker), true);
-				FlipSet::<T>::insert(&worker, BlockNumberFor::<T>::zero());
-				FlipOrFlop::<T>::set(FlipFlopStage::Flip);
-			},
-			_ => fail!("Other stages is unexpected"),
-		};
+		// Simulate the worker is due to send the heartbeat
+		let mut window =
+			WorkerHeartbeatWindows::<T>::get(&worker).expect("Online worker should have a window");
+		window.next_heartbeat = BlockNumberFor::<T>::zero();
+		WorkerHeartbeatWindows::<T>::insert(&worker, window.clone());
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(worker.clone()));
 
-		let stage = FlipOrFlop::<T>::get();
-		match stage {
-			FlipFlopStage::Flip => {
-				assert_eq!(FlipSet::<T>::contains_key(&worker), false);
-				assert_eq!(FlopSet::<T>::contains_key(&worker), true);
-			},
-			FlipFlopStage::Flop => {
-				assert_eq!(FlipSet::<T>::contains_key(&worker), true);
-				assert_eq!(FlopSet::<T>::contains_key(&worker), false);
-			},
-			_ => fail!("Other stages is unexpected"),
-		};
+		assert_eq!(HeartbeatDeadlines::<T>::contains_key(window.deadline, &worker), false);
+		assert_eq!(WorkerHeartbeatWindows::<T>::contains_key(&worker), true);
 
 		Ok(())
 	}
 
 	// TODO: benchmark other paths of heartbeat
 
+	// 10k workers are waiting for other deadlines, `w` workers are due in the block,
+	// the weight should be only related to `w`
+	#[benchmark]
+	fn handle_unresponsive_workers(
+		w: Linear<0, { T::HandleUnresponsivePerBlockLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let deadline = current_block.saturating_add(1u32.into());
+		for _ in 0..w {
+			let worker_public = sr25519::Public::generate_pair(WORKER_KEY_TYPE, None);
+			let worker = add_mock_online_worker::<T>(&worker_public, &owner, Some(impl_id.clone()));
+			// Move the worker to the due bucket
+			OffchainComputingInfra::<T>::unschedule_heartbeat(&worker);
+			HeartbeatDeadlines::<T>::insert(deadline, &worker, ());
+			WorkerHeartbeatWindows::<T>::insert(
+				&worker,
+				HeartbeatWindow { next_heartbeat: current_block, deadline },
+			);
+		}
+		for i in 0..10_000u32 {
+			let worker: T::AccountId = account("idle_worker", i, 0);
+			let idle_deadline = deadline.saturating_add((i % 100 + 1).into());
+			HeartbeatDeadlines::<T>::insert(idle_deadline, worker, ());
+		}
+		NextHeartbeatDeadlineToHandle::<T>::put(deadline);
+
+		#[block]
+		{
+			OffchainComputingInfra::<T>::on_initialize(deadline);
+		}
+
+		assert_eq!(HeartbeatDeadlines::<T>::iter_key_prefix(deadline).next().is_none(), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_impl() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let balance = T::RegisterImplDeposit::get()
+			.saturating_add((10 * DOLLARS).saturated_into::<BalanceOf<T>>());
+		T::Currency::set_balance(&owner, balance);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), AttestationMethod::OptOut);
+
+		let impl_info = Impls::<T>::iter_values().last().expect("Should have an impl");
+		assert_eq!(impl_info.owner, owner);
+
+		Ok(())
+	}
+
+	// The impl has metadata, and `b` builds to clear
+	#[benchmark]
+	fn deregister_impl(b: Linear<1, { T::MaxImplBuilds::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		fund_impl_owner::<T>(&owner);
+		set_mock_impl_metadata::<T>(&owner, impl_id.clone());
+		for version in 2..=b {
+			assert_ok!(OffchainComputingInfra::<T>::register_impl_build(
+				RawOrigin::Signed(owner.clone()).into(),
+				impl_id.clone(),
+				version,
+				None
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), impl_id.clone());
+
+		assert_eq!(Impls::<T>::contains_key(&impl_id), false);
+		assert_eq!(ImplBuilds::<T>::iter_key_prefix(&impl_id).next().is_none(), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_impl_metadata(
+		m: Linear<1, { T::ImplMetadataLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		fund_impl_owner::<T>(&owner);
+		let metadata: BoundedVec<u8, T::ImplMetadataLimit> =
+			vec![0u8; m as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), impl_id.clone(), Some(metadata.clone()));
+
+		let metadata_entry = ImplMetadata::<T>::get(&impl_id).expect("Metadata should has value");
+		assert_eq!(metadata_entry.data, metadata);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_impl_build() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), impl_id.clone(), 2u32, Some(Default::default()));
+
+		assert_eq!(ImplBuilds::<T>::contains_key(&impl_id, 2u32), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_impl_build() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), impl_id.clone(), 1u32);
+
+		assert_eq!(ImplBuilds::<T>::contains_key(&impl_id, 1u32), false);
+
+		Ok(())
+	}
+
+	// This is the slow path, a build publisher deprecates the build
+	#[benchmark]
+	fn update_impl_build_status() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let publisher: T::AccountId = account("publisher", 0, 0);
+		assert_ok!(OffchainComputingInfra::<T>::add_impl_build_publisher(
+			RawOrigin::Signed(owner.clone()).into(),
+			impl_id.clone(),
+			T::Lookup::unlookup(publisher.clone())
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(publisher), impl_id.clone(), 1u32, ImplBuildStatus::Deprecated);
+
+		let impl_build_info =
+			ImplBuilds::<T>::get(&impl_id, 1u32).expect("ImplBuildInfo should has value");
+		assert_eq!(impl_build_info.status, ImplBuildStatus::Deprecated);
+
+		Ok(())
+	}
+
+	// This is the slow path, a build publisher starts the canary with the longest allow list
+	#[benchmark]
+	fn start_impl_build_canary() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let publisher: T::AccountId = account("publisher", 0, 0);
+		assert_ok!(OffchainComputingInfra::<T>::add_impl_build_publisher(
+			RawOrigin::Signed(owner.clone()).into(),
+			impl_id.clone(),
+			T::Lookup::unlookup(publisher.clone())
+		));
+		let allowed_workers: BoundedVec<_, _> = (0..32u32)
+			.map(|i| account::<T::AccountId>("worker", i, 0))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let rollout = CanaryRollout::AllowList(allowed_workers);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(publisher), impl_id.clone(), 1u32, rollout);
+
+		let impl_build_info =
+			ImplBuilds::<T>::get(&impl_id, 1u32).expect("ImplBuildInfo should has value");
+		assert_eq!(impl_build_info.status, ImplBuildStatus::Canary);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn promote_impl_build() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		assert_ok!(OffchainComputingInfra::<T>::start_impl_build_canary(
+			RawOrigin::Signed(owner.clone()).into(),
+			impl_id.clone(),
+			1u32,
+			CanaryRollout::MaxWorkers(1)
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), impl_id.clone(), 1u32);
+
+		let impl_build_info =
+			ImplBuilds::<T>::get(&impl_id, 1u32).expect("ImplBuildInfo should has value");
+		assert_eq!(impl_build_info.status, ImplBuildStatus::Released);
+		assert_eq!(ImplBuildCanaryRollouts::<T>::contains_key(&impl_id, 1u32), false);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_impl_worker_requirements() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let worker_deposit_floor = T::RegisterWorkerDeposit::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			impl_id.clone(),
+			worker_deposit_floor,
+			HeartbeatPeriodClass::Slow,
+		);
+
+		let impl_info = Impls::<T>::get(&impl_id).expect("ImplInfo should has value");
+		assert_eq!(impl_info.heartbeat_period_class, HeartbeatPeriodClass::Slow);
+
+		Ok(())
+	}
+
+	// The publisher is appended to the last place
+	#[benchmark]
+	fn add_impl_build_publisher() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let max_publishers = T::MaxImplBuildPublishers::get();
+		for i in 1..max_publishers {
+			let publisher: T::AccountId = account("publisher", i, 0);
+			assert_ok!(OffchainComputingInfra::<T>::add_impl_build_publisher(
+				RawOrigin::Signed(owner.clone()).into(),
+				impl_id.clone(),
+				T::Lookup::unlookup(publisher)
+			));
+		}
+		let publisher: T::AccountId = account("publisher", 0, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			impl_id.clone(),
+			T::Lookup::unlookup(publisher.clone()),
+		);
+
+		assert_eq!(ImplBuildPublishers::<T>::get(&impl_id).contains(&publisher), true);
+
+		Ok(())
+	}
+
+	// The publisher is in the last place
+	#[benchmark]
+	fn remove_impl_build_publisher() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let max_publishers = T::MaxImplBuildPublishers::get();
+		for i in 0..max_publishers {
+			let publisher: T::AccountId = account("publisher", i, 0);
+			assert_ok!(OffchainComputingInfra::<T>::add_impl_build_publisher(
+				RawOrigin::Signed(owner.clone()).into(),
+				impl_id.clone(),
+				T::Lookup::unlookup(publisher)
+			));
+		}
+		let publisher: T::AccountId = account("publisher", max_publishers - 1, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			impl_id.clone(),
+			T::Lookup::unlookup(publisher.clone()),
+		);
+
+		assert_eq!(ImplBuildPublishers::<T>::get(&impl_id).contains(&publisher), false);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_accept_impl_ownership() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		T::Currency::set_balance(&new_owner, T::Currency::minimum_balance());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(new_owner.clone()), Some(impl_id.clone()));
+
+		assert_eq!(ImplOwnershipAcceptance::<T>::get(&new_owner), Some(impl_id));
+
+		Ok(())
+	}
+
+	// The metadata deposit is moved as well
+	#[benchmark]
+	fn transfer_impl_ownership() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		fund_impl_owner::<T>(&owner);
+		set_mock_impl_metadata::<T>(&owner, impl_id.clone());
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		T::Currency::set_balance(&new_owner, T::Currency::minimum_balance());
+		assert_ok!(OffchainComputingInfra::<T>::set_accept_impl_ownership(
+			RawOrigin::Signed(new_owner.clone()).into(),
+			Some(impl_id.clone())
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			impl_id.clone(),
+			T::Lookup::unlookup(new_owner.clone()),
+		);
+
+		let impl_info = Impls::<T>::get(&impl_id).expect("ImplInfo should has value");
+		assert_eq!(impl_info.owner, new_owner);
+
+		Ok(())
+	}
+
 	// This line generates test cases for benchmarking, and could be run by:
 	//   `cargo test --features runtime-benchmarks`
 	impl_benchmark_test_suite! {
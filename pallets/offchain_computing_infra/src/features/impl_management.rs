@@ -17,7 +17,7 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::tokens::Restriction};
 use sp_std::cmp::Ordering;
 
 impl<T: Config> Pallet<T> {
@@ -37,15 +37,40 @@ impl<T: Config> Pallet<T> {
 			owner_deposit: deposit,
 			attestation_method: attestation_method.clone(),
 			workers_count: 0,
+			worker_deposit_floor: T::RegisterWorkerDeposit::get(),
+			heartbeat_period_class: HeartbeatPeriodClass::default(),
 		};
 
 		Impls::<T>::insert(&impl_id, impl_info);
 		AccountOwningImpls::<T>::insert(&owner, &impl_id, ());
 
-		Self::deposit_event(Event::ImplRegistered {
-			owner,
-			attestation_method,
-			impl_id,
+		Self::deposit_event(Event::ImplRegistered { owner, attestation_method, impl_id });
+		Ok(())
+	}
+
+	pub fn do_update_impl_worker_requirements(
+		mut impl_info: ImplInfo<T::ImplId, T::AccountId, BalanceOf<T>>,
+		worker_deposit_floor: BalanceOf<T>,
+		heartbeat_period_class: HeartbeatPeriodClass,
+	) -> DispatchResult {
+		ensure!(
+			worker_deposit_floor >= T::RegisterWorkerDeposit::get(),
+			Error::<T>::WorkerDepositFloorTooLow
+		);
+		ensure!(
+			Self::collecting_heartbeats_duration(heartbeat_period_class) >=
+				T::MinCollectingHeartbeatsDurationInBlocks::get(),
+			Error::<T>::HeartbeatPeriodTooShort
+		);
+
+		impl_info.worker_deposit_floor = worker_deposit_floor;
+		impl_info.heartbeat_period_class = heartbeat_period_class;
+		Impls::<T>::insert(&impl_info.id, impl_info.clone());
+
+		Self::deposit_event(Event::ImplWorkerRequirementsUpdated {
+			impl_id: impl_info.id,
+			worker_deposit_floor,
+			heartbeat_period_class,
 		});
 		Ok(())
 	}
@@ -65,7 +90,10 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let _ = ImplBuilds::<T>::clear_prefix(&impl_id, T::MaxImplBuilds::get(), None);
+		let _ = ImplBuildCanaryRollouts::<T>::clear_prefix(&impl_id, T::MaxImplBuilds::get(), None);
+		let _ = ImplBuildHealths::<T>::clear_prefix(&impl_id, T::MaxImplBuilds::get(), None);
 		CounterForImplBuilds::<T>::remove(impl_info.id);
+		ImplBuildPublishers::<T>::remove(&impl_id);
 
 		Impls::<T>::remove(&impl_id);
 		AccountOwningImpls::<T>::remove(&impl_info.owner, impl_id.clone());
@@ -81,6 +109,122 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfer the implementation to the new owner, who must have accepted the ownership.
+	/// The deposits are moved to the new owner.
+	pub(crate) fn do_transfer_impl_ownership(
+		who: T::AccountId,
+		impl_id: T::ImplId,
+		new_owner: T::AccountId,
+	) -> DispatchResult {
+		let acceptable_impl = ImplOwnershipAcceptance::<T>::get(&new_owner);
+		ensure!(acceptable_impl.as_ref() == Some(&impl_id), Error::<T>::Unaccepted);
+
+		let mut impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
+		Self::ensure_impl_owner(&who, &impl_info)?;
+		if impl_info.owner == new_owner {
+			return Ok(())
+		}
+
+		T::Currency::transfer_on_hold(
+			&HoldReason::ImplRegistrationReserve.into(),
+			&impl_info.owner,
+			&new_owner,
+			impl_info.owner_deposit,
+			Precision::BestEffort,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+
+		ImplMetadata::<T>::try_mutate(&impl_id, |maybe_metadata_entry| -> DispatchResult {
+			let Some(metadata_entry) = maybe_metadata_entry else { return Ok(()) };
+
+			T::Currency::transfer_on_hold(
+				&HoldReason::ImplMetadataStorageReserve.into(),
+				&metadata_entry.depositor,
+				&new_owner,
+				metadata_entry.actual_deposit,
+				Precision::BestEffort,
+				Restriction::OnHold,
+				Fortitude::Polite,
+			)?;
+			metadata_entry.depositor = new_owner.clone();
+
+			Ok(())
+		})?;
+
+		AccountOwningImpls::<T>::remove(&impl_info.owner, &impl_id);
+		AccountOwningImpls::<T>::insert(&new_owner, &impl_id, ());
+
+		impl_info.owner = new_owner.clone();
+		Impls::<T>::insert(&impl_id, impl_info);
+
+		ImplOwnershipAcceptance::<T>::remove(&new_owner);
+		frame_system::Pallet::<T>::dec_consumers(&new_owner);
+
+		Self::deposit_event(Event::ImplOwnerChanged { impl_id, new_owner });
+		Ok(())
+	}
+
+	/// Set or unset the implementation that the account is willing to accept the ownership of.
+	pub(crate) fn do_accept_impl_ownership(
+		who: T::AccountId,
+		maybe_impl_id: Option<T::ImplId>,
+	) -> DispatchResult {
+		let exists = ImplOwnershipAcceptance::<T>::contains_key(&who);
+		match (exists, maybe_impl_id.is_some()) {
+			(false, true) => {
+				frame_system::Pallet::<T>::inc_consumers(&who)?;
+			},
+			(true, false) => {
+				frame_system::Pallet::<T>::dec_consumers(&who);
+			},
+			_ => {},
+		}
+		if let Some(impl_id) = maybe_impl_id.as_ref() {
+			ImplOwnershipAcceptance::<T>::insert(&who, impl_id);
+		} else {
+			ImplOwnershipAcceptance::<T>::remove(&who);
+		}
+
+		Self::deposit_event(Event::ImplOwnershipAcceptanceChanged { who, maybe_impl_id });
+		Ok(())
+	}
+
+	pub(crate) fn do_add_impl_build_publisher(
+		impl_id: T::ImplId,
+		publisher: T::AccountId,
+	) -> DispatchResult {
+		ImplBuildPublishers::<T>::try_mutate(&impl_id, |publishers| -> DispatchResult {
+			ensure!(!publishers.contains(&publisher), Error::<T>::ImplBuildPublisherAlreadyAdded);
+			publishers
+				.try_push(publisher.clone())
+				.map_err(|_| Error::<T>::ImplBuildPublishersLimitExceeded)?;
+
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::ImplBuildPublisherAdded { impl_id, publisher });
+		Ok(())
+	}
+
+	pub(crate) fn do_remove_impl_build_publisher(
+		impl_id: T::ImplId,
+		publisher: T::AccountId,
+	) -> DispatchResult {
+		ImplBuildPublishers::<T>::try_mutate(&impl_id, |publishers| -> DispatchResult {
+			let index = publishers
+				.iter()
+				.position(|p| p == &publisher)
+				.ok_or(Error::<T>::ImplBuildPublisherNotFound)?;
+			publishers.remove(index);
+
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::ImplBuildPublisherRemoved { impl_id, publisher });
+		Ok(())
+	}
+
 	pub(crate) fn do_update_impl_metadata(
 		impl_info: ImplInfo<T::ImplId, T::AccountId, BalanceOf<T>>,
 		new_metadata: BoundedVec<u8, T::ImplMetadataLimit>,
@@ -190,6 +334,8 @@ impl<T: Config> Pallet<T> {
 		ensure!(impl_build_info.workers_count == 0, Error::<T>::ImplBuildStillInUse);
 
 		ImplBuilds::<T>::remove(&impl_id, impl_build_version);
+		ImplBuildCanaryRollouts::<T>::remove(&impl_id, impl_build_version);
+		ImplBuildHealths::<T>::remove(&impl_id, impl_build_version);
 		CounterForImplBuilds::<T>::try_mutate(&impl_id, |counter| -> Result<(), DispatchError> {
 			*counter -= 1;
 			Ok(())
@@ -205,6 +351,8 @@ impl<T: Config> Pallet<T> {
 		impl_build_version: ImplBuildVersion,
 		status: ImplBuildStatus,
 	) -> DispatchResult {
+		ensure!(status != ImplBuildStatus::Canary, Error::<T>::CanaryRolloutRequired);
+
 		ImplBuilds::<T>::try_mutate(
 			&impl_id,
 			impl_build_version,
@@ -218,6 +366,7 @@ impl<T: Config> Pallet<T> {
 				Ok(())
 			},
 		)?;
+		ImplBuildCanaryRollouts::<T>::remove(&impl_id, impl_build_version);
 
 		Self::deposit_event(Event::<T>::ImplBuildStatusUpdated {
 			impl_id,
@@ -227,4 +376,48 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	pub(crate) fn do_start_impl_build_canary(
+		impl_id: T::ImplId,
+		impl_build_version: ImplBuildVersion,
+		rollout: CanaryRollout<T::AccountId>,
+	) -> DispatchResult {
+		ImplBuilds::<T>::try_mutate(
+			&impl_id,
+			impl_build_version,
+			|impl_build_info| -> Result<(), DispatchError> {
+				let Some(info) = impl_build_info.as_mut() else {
+					return Err(Error::<T>::ImplBuildNotFound.into())
+				};
+				ensure!(
+					matches!(info.status, ImplBuildStatus::Released | ImplBuildStatus::Canary),
+					Error::<T>::ImplBuildRestricted
+				);
+
+				info.status = ImplBuildStatus::Canary;
+
+				Ok(())
+			},
+		)?;
+		ImplBuildCanaryRollouts::<T>::insert(&impl_id, impl_build_version, rollout.clone());
+
+		Self::deposit_event(Event::<T>::ImplBuildCanaryStarted {
+			impl_id,
+			impl_build_version,
+			rollout,
+		});
+
+		Ok(())
+	}
+
+	pub(crate) fn do_promote_impl_build(
+		impl_id: T::ImplId,
+		impl_build_version: ImplBuildVersion,
+	) -> DispatchResult {
+		let impl_build_info = ImplBuilds::<T>::get(&impl_id, impl_build_version)
+			.ok_or(Error::<T>::ImplBuildNotFound)?;
+		ensure!(impl_build_info.status == ImplBuildStatus::Canary, Error::<T>::ImplBuildNotCanary);
+
+		Self::do_update_impl_build_status(impl_id, impl_build_version, ImplBuildStatus::Released)
+	}
 }
@@ -17,5 +17,7 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 pub mod impl_management;
+#[cfg(any(feature = "try-runtime", test))]
+pub mod try_state;
 pub mod worker_lifecycle;
 pub mod worker_management;
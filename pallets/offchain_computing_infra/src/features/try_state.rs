@@ -0,0 +1,135 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_runtime::{Saturating, TryRuntimeError};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+/// Add the amount to the account's expected held balance.
+fn accrue<AccountId: Ord, Balance: Saturating + Default>(
+	deposits: &mut BTreeMap<AccountId, Balance>,
+	who: AccountId,
+	amount: Balance,
+) {
+	deposits.entry(who).or_default().saturating_accrue(amount);
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check the invariants of the pallet's storage, it iterates all implementations and workers,
+	/// so only use it in tests and `try-runtime` checks.
+	///
+	/// - The denormalized counters equal to the numbers recomputed from the underlying maps.
+	/// - The held implementations' deposits equal to the recorded ones.
+	/// - The held workers' deposits don't exceed the recorded ones, they may have been slashed.
+	/// - The accounts known to the pallet hold nothing else of the pallet's hold reasons.
+	pub fn do_try_state() -> Result<(), TryRuntimeError> {
+		ensure!(
+			Impls::<T>::iter().count() as u32 == Impls::<T>::count(),
+			"Implementations count mismatch"
+		);
+		ensure!(
+			Workers::<T>::iter().count() as u32 == Workers::<T>::count(),
+			"Workers count mismatch"
+		);
+
+		let mut impl_workers_count = BTreeMap::<T::ImplId, u32>::new();
+		let mut build_workers_count = BTreeMap::<(T::ImplId, ImplBuildVersion), u32>::new();
+		let mut worker_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		for worker_info in Workers::<T>::iter_values() {
+			*impl_workers_count.entry(worker_info.impl_id.clone()).or_default() += 1;
+			if let Some(impl_build_version) = worker_info.impl_build_version {
+				*build_workers_count
+					.entry((worker_info.impl_id.clone(), impl_build_version))
+					.or_default() += 1;
+			}
+
+			let deposit = worker_info.deposit.saturating_add(
+				WorkerDepositUnbondings::<T>::get(&worker_info.account)
+					.map(|unbonding| unbonding.amount)
+					.unwrap_or_default(),
+			);
+			accrue(&mut worker_deposits, worker_info.account, deposit);
+		}
+
+		let mut registration_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		let mut metadata_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		for impl_info in Impls::<T>::iter_values() {
+			ensure!(
+				impl_info.workers_count ==
+					impl_workers_count.get(&impl_info.id).copied().unwrap_or_default(),
+				"Implementation's workers count mismatch"
+			);
+			ensure!(
+				CounterForImplBuilds::<T>::get(&impl_info.id) ==
+					ImplBuilds::<T>::iter_prefix(&impl_info.id).count() as u32,
+				"Implementation's builds count mismatch"
+			);
+
+			accrue(&mut registration_deposits, impl_info.owner, impl_info.owner_deposit);
+			if let Some(metadata_entry) = ImplMetadata::<T>::get(&impl_info.id) {
+				accrue(
+					&mut metadata_deposits,
+					metadata_entry.depositor,
+					metadata_entry.actual_deposit,
+				);
+			}
+		}
+
+		for (impl_id, impl_build_version, impl_build_info) in ImplBuilds::<T>::iter() {
+			ensure!(Impls::<T>::contains_key(&impl_id), "Build of a nonexistent implementation");
+			ensure!(
+				impl_build_info.workers_count ==
+					build_workers_count
+						.get(&(impl_id, impl_build_version))
+						.copied()
+						.unwrap_or_default(),
+				"Implementation build's workers count mismatch"
+			);
+		}
+
+		// Check every known account against every reason, so the accounts hold nothing without
+		// a record, and the held sum of each reason equals to the recorded sum
+		let accounts = [&registration_deposits, &metadata_deposits, &worker_deposits]
+			.into_iter()
+			.flat_map(|deposits| deposits.keys().cloned())
+			.collect::<BTreeSet<_>>();
+		for who in accounts {
+			let recorded = |deposits: &BTreeMap<T::AccountId, BalanceOf<T>>| {
+				deposits.get(&who).copied().unwrap_or_default()
+			};
+			ensure!(
+				T::Currency::balance_on_hold(&HoldReason::ImplRegistrationReserve.into(), &who) ==
+					recorded(&registration_deposits),
+				"Implementations' held deposit mismatch"
+			);
+			ensure!(
+				T::Currency::balance_on_hold(&HoldReason::ImplMetadataStorageReserve.into(), &who) ==
+					recorded(&metadata_deposits),
+				"Implementations' metadata held deposit mismatch"
+			);
+			ensure!(
+				T::Currency::balance_on_hold(&HoldReason::WorkerRegistrationReserve.into(), &who) <=
+					recorded(&worker_deposits),
+				"Worker's held deposit exceeds the recorded"
+			);
+		}
+
+		Ok(())
+	}
+}
@@ -30,7 +30,7 @@ impl<T: Config> Pallet<T> {
 	/// 6 Do `can_online` hook, will pass the payload
 	/// Then
 	/// 2 Update worker's info, persists to storage
-	/// 3 Set flip-flop
+	/// 3 Schedule the next heartbeat
 	pub fn do_online(
 		worker: T::AccountId,
 		payload: OnlinePayload<T::ImplId>,
@@ -66,10 +66,16 @@ impl<T: Config> Pallet<T> {
 		let mut impl_build_info =
 			ImplBuilds::<T>::get(&worker_info.impl_id, payload.impl_build_version)
 				.ok_or(Error::<T>::ImplBuildNotFound)?;
-		ensure!(
-			impl_build_info.status == ImplBuildStatus::Released,
-			Error::<T>::ImplBuildRestricted
-		);
+		match impl_build_info.status {
+			ImplBuildStatus::Released => {},
+			ImplBuildStatus::Canary => Self::ensure_canary_admits(
+				&worker,
+				&worker_info.impl_id,
+				&impl_build_info,
+				current_status != WorkerStatus::Unresponsive,
+			)?,
+			_ => return Err(Error::<T>::ImplBuildRestricted.into()),
+		}
 		if let Some(magic_bytes) = impl_build_info.magic_bytes.clone() {
 			ensure!(
 				magic_bytes == payload.impl_build_magic_bytes,
@@ -77,6 +83,10 @@ impl<T: Config> Pallet<T> {
 			);
 		}
 
+		// The implementation may have raised its deposit floor since the worker registered
+		let impl_info = Impls::<T>::get(&worker_info.impl_id).ok_or(Error::<T>::ImplNotFound)?;
+		worker_info.deposit = worker_info.deposit.max(impl_info.worker_deposit_floor);
+
 		// Check reserved money
 		let deposit =
 			T::Currency::balance_on_hold(&HoldReason::WorkerRegistrationReserve.into(), &worker);
@@ -111,13 +121,15 @@ impl<T: Config> Pallet<T> {
 		}
 		worker_info.status = WorkerStatus::Online;
 		Workers::<T>::insert(&worker, worker_info);
+		WorkerInactiveSince::<T>::remove(&worker);
+		Self::update_worker_encryption_key(&worker, payload.encryption_public_key);
 
 		if current_status != WorkerStatus::Unresponsive {
 			impl_build_info.workers_count += 1;
 			ImplBuilds::<T>::insert(payload.impl_id, payload.impl_build_version, impl_build_info);
 		}
 
-		let next_heartbeat = Self::flip_flop_for_online(&worker);
+		let next_heartbeat = Self::schedule_heartbeat(&worker, impl_info.heartbeat_period_class);
 
 		Self::deposit_event(Event::<T>::WorkerOnline {
 			worker: worker.clone(),
@@ -125,10 +137,11 @@ impl<T: Config> Pallet<T> {
 			impl_build_version: payload.impl_build_version,
 			attestation_method: attestation.method(),
 			attestation_expires_at: verified_attestation.expires_at(),
+			encryption_public_key: payload.encryption_public_key,
 			next_heartbeat,
 		});
 
-		T::OffchainWorkerLifecycleHooks::after_online(&worker);
+		T::OffchainWorkerLifecycleHooks::after_online(&worker, &payload, &verified_attestation);
 
 		Ok(())
 	}
@@ -158,10 +171,87 @@ impl<T: Config> Pallet<T> {
 		worker_info.attestation_expires_at = verified_attestation.expires_at();
 		worker_info.attested_at = Some(T::UnixTime::now().as_secs().saturated_into::<u64>());
 		Workers::<T>::insert(&worker, worker_info.clone());
+		Self::update_worker_encryption_key(&worker, payload.encryption_public_key);
 
 		Self::deposit_event(Event::<T>::WorkerAttestationRefreshed {
 			worker: worker.clone(),
 			expires_at: verified_attestation.expires_at(),
+			encryption_public_key: payload.encryption_public_key,
+		});
+
+		T::OffchainWorkerLifecycleHooks::after_refresh_attestation(
+			&worker,
+			&payload,
+			&verified_attestation,
+		);
+
+		Ok(())
+	}
+
+	/// Switch an online worker to another `Released` build of the same implementation,
+	/// the worker keeps online and its uptime
+	pub(crate) fn do_upgrade_build(
+		worker: T::AccountId,
+		payload: OnlinePayload<T::ImplId>,
+		attestation: Attestation,
+	) -> DispatchResult {
+		let mut worker_info = Workers::<T>::get(&worker).ok_or(Error::<T>::WorkerNotFound)?;
+		Self::ensure_worker(&worker, &worker_info)?;
+		ensure!(worker_info.status == WorkerStatus::Online, Error::<T>::WorkerNotOnline);
+		ensure!(worker_info.impl_id == payload.impl_id, Error::<T>::ImplMismatched);
+
+		let Some(current_impl_build_version) = worker_info.impl_build_version else {
+			return Err(Error::<T>::InternalError.into())
+		};
+		ensure!(
+			current_impl_build_version != payload.impl_build_version,
+			Error::<T>::ImplBuildUnchanged
+		);
+
+		let mut impl_build_info =
+			ImplBuilds::<T>::get(&worker_info.impl_id, payload.impl_build_version)
+				.ok_or(Error::<T>::ImplBuildNotFound)?;
+		ensure!(
+			impl_build_info.status == ImplBuildStatus::Released,
+			Error::<T>::ImplBuildRestricted
+		);
+		if let Some(magic_bytes) = impl_build_info.magic_bytes.clone() {
+			ensure!(
+				magic_bytes == payload.impl_build_magic_bytes,
+				Error::<T>::ImplBuildMagicBytesMismatched
+			);
+		}
+
+		Self::ensure_attestation_method(&attestation, &worker_info)?;
+		let verified_attestation = Self::verify_attestation(&attestation)?;
+		Self::verify_online_payload(&worker, &payload, &verified_attestation)?;
+		T::OffchainWorkerLifecycleHooks::can_upgrade_build(
+			&worker,
+			&payload,
+			&verified_attestation,
+		)?;
+
+		ImplBuilds::<T>::mutate(&worker_info.impl_id, current_impl_build_version, |info| {
+			if let Some(info) = info.as_mut() {
+				info.workers_count = info.workers_count.saturating_sub(1);
+			}
+		});
+		impl_build_info.workers_count += 1;
+		ImplBuilds::<T>::insert(&payload.impl_id, payload.impl_build_version, impl_build_info);
+
+		worker_info.impl_spec_version = Some(payload.impl_spec_version);
+		worker_info.impl_build_version = Some(payload.impl_build_version);
+		worker_info.attestation_expires_at = verified_attestation.expires_at();
+		worker_info.attested_at = Some(T::UnixTime::now().as_secs().saturated_into::<u64>());
+		Workers::<T>::insert(&worker, worker_info);
+		Self::update_worker_encryption_key(&worker, payload.encryption_public_key);
+
+		Self::deposit_event(Event::<T>::WorkerBuildUpgraded {
+			worker: worker.clone(),
+			impl_spec_version: payload.impl_spec_version,
+			impl_build_version: payload.impl_build_version,
+			attestation_expires_at: verified_attestation.expires_at(),
+			encryption_public_key: payload.encryption_public_key,
 		});
 
 		T::OffchainWorkerLifecycleHooks::after_refresh_attestation(
@@ -268,9 +358,11 @@ impl<T: Config> Pallet<T> {
 			return Ok(())
 		}
 
+		let impl_info = Impls::<T>::get(&worker_info.impl_id).ok_or(Error::<T>::InternalError)?;
+
 		// Check the worker's reserved money
 		if T::Currency::balance_on_hold(&HoldReason::WorkerRegistrationReserve.into(), &worker) <
-			T::RegisterWorkerDeposit::get()
+			impl_info.worker_deposit_floor.max(T::RegisterWorkerDeposit::get())
 		{
 			Self::set_worker_offline(&worker, OfflineReason::InsufficientDepositFunds);
 
@@ -284,7 +376,7 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::InternalError)?;
 		let valid_impl_build = matches!(
 			impl_build_info.status,
-			ImplBuildStatus::Released | ImplBuildStatus::Deprecated
+			ImplBuildStatus::Released | ImplBuildStatus::Deprecated | ImplBuildStatus::Canary
 		);
 
 		if !valid_impl_build {
@@ -293,29 +385,11 @@ impl<T: Config> Pallet<T> {
 			return Ok(())
 		}
 
-		let next_heartbeat = Self::generate_next_heartbeat_block();
-		let stage = FlipOrFlop::<T>::get();
-		match stage {
-			FlipFlopStage::Flip => {
-				let Some(flip) = FlipSet::<T>::get(&worker) else {
-					return Err(Error::<T>::HeartbeatAlreadySent.into())
-				};
-				ensure!(flip <= current_block, Error::<T>::TooEarly);
-
-				FlipSet::<T>::remove(&worker);
-				FlopSet::<T>::insert(&worker, next_heartbeat);
-			},
-			FlipFlopStage::Flop => {
-				let Some(flop) = FlopSet::<T>::get(&worker) else {
-					return Err(Error::<T>::HeartbeatAlreadySent.into())
-				};
-				ensure!(flop <= current_block, Error::<T>::TooEarly);
-
-				FlopSet::<T>::remove(&worker);
-				FlipSet::<T>::insert(&worker, next_heartbeat);
-			},
-			_ => return Err(Error::<T>::TooEarly.into()),
-		}
+		let Some(window) = WorkerHeartbeatWindows::<T>::get(&worker) else {
+			return Err(Error::<T>::InternalError.into())
+		};
+		ensure!(window.next_heartbeat <= current_block, Error::<T>::TooEarly);
+		let next_heartbeat = Self::schedule_heartbeat(&worker, impl_info.heartbeat_period_class);
 
 		let Some(last_sent_heartbeat_at) = worker_info.last_sent_heartbeat_at else {
 			return Err(Error::<T>::InternalError.into())
@@ -17,7 +17,7 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::tokens::Restriction};
 
 impl<T: Config> Pallet<T> {
 	pub(crate) fn do_register_worker(
@@ -28,7 +28,8 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		ensure!(owner != worker, Error::<T>::InvalidOwner);
 
-		let deposit = T::RegisterWorkerDeposit::get();
+		let mut impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
+		let deposit = impl_info.worker_deposit_floor.max(T::RegisterWorkerDeposit::get());
 		ensure!(
 			initial_balance.saturating_add(T::Currency::reducible_balance(
 				&worker,
@@ -40,7 +41,6 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(!Workers::<T>::contains_key(&worker), Error::<T>::AlreadyRegistered);
 
-		let mut impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
 		impl_info.workers_count += 1;
 		Impls::<T>::insert(&impl_id, impl_info);
 
@@ -66,6 +66,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		Workers::<T>::insert(&worker, worker_info);
+		WorkerInactiveSince::<T>::insert(&worker, frame_system::Pallet::<T>::block_number());
 		AccountOwningWorkers::<T>::insert(&owner, &worker, ());
 
 		Self::deposit_event(Event::<T>::WorkerRegistered { worker, owner, impl_id });
@@ -88,7 +89,62 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::DeregisterBlocked
 		);
 
-		let deposit = worker_info.deposit;
+		Self::remove_worker(worker_info, None)?;
+
+		Self::deposit_event(Event::<T>::WorkerDeregistered { worker, force: false });
+		Ok(())
+	}
+
+	/// Deregister the worker by `WorkerForceOrigin`, or by the reaper if the worker has been
+	/// inactive long enough.
+	pub(crate) fn do_force_deregister_worker(
+		worker: T::AccountId,
+		maybe_reaper: Option<T::AccountId>,
+	) -> DispatchResult {
+		let worker_info = Workers::<T>::get(&worker).ok_or(Error::<T>::WorkerNotFound)?;
+
+		if maybe_reaper.is_some() {
+			let inactive_since =
+				WorkerInactiveSince::<T>::get(&worker).ok_or(Error::<T>::WorkerNotReapable)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >=
+					inactive_since
+						.saturating_add(T::ReapWorkerAfterInactiveBlocks::get().into()),
+				Error::<T>::WorkerNotReapable
+			);
+		}
+
+		if matches!(
+			worker_info.status,
+			WorkerStatus::Online | WorkerStatus::RequestingOffline | WorkerStatus::Unresponsive
+		) {
+			Self::set_worker_offline(&worker, OfflineReason::Forced);
+		}
+
+		let reaper_fee = maybe_reaper.map(|reaper| (reaper, T::WorkerReaperFee::get()));
+		Self::remove_worker(worker_info, reaper_fee)?;
+
+		Self::deposit_event(Event::<T>::WorkerDeregistered { worker, force: true });
+		Ok(())
+	}
+
+	/// Remove the worker, its deposit and balance are returned to its owner,
+	/// the reaper takes the fee from them first.
+	fn remove_worker(
+		worker_info: WorkerInfo<T::AccountId, BalanceOf<T>, T::ImplId>,
+		reaper_fee: Option<(T::AccountId, BalanceOf<T>)>,
+	) -> DispatchResult {
+		let worker = worker_info.account.clone();
+		let owner = worker_info.owner.clone();
+
+		T::OffchainWorkerLifecycleHooks::before_deregister(&worker);
+
+		// The unbonding deposit is still held, so release it as well
+		let deposit = worker_info.deposit.saturating_add(
+			WorkerDepositUnbondings::<T>::take(&worker)
+				.map(|unbonding| unbonding.amount)
+				.unwrap_or_default(),
+		);
 		if !deposit.is_zero() {
 			// The upper limit is the actual reserved, so it is OK
 			T::Currency::release(
@@ -98,6 +154,16 @@ impl<T: Config> Pallet<T> {
 				Precision::BestEffort,
 			)?;
 		}
+		if let Some((reaper, fee)) = reaper_fee {
+			let fee = fee.min(T::Currency::reducible_balance(
+				&worker,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			));
+			if !fee.is_zero() {
+				T::Currency::transfer(&worker, &reaper, fee, Preservation::Expendable)?;
+			}
+		}
 		T::Currency::transfer(
 			&worker,
 			&owner,
@@ -111,9 +177,88 @@ impl<T: Config> Pallet<T> {
 		Impls::<T>::insert(&worker_info.impl_id, impl_info);
 
 		Workers::<T>::remove(&worker);
+		WorkerInactiveSince::<T>::remove(&worker);
+		WorkerEncryptionKeys::<T>::remove(&worker);
 		AccountOwningWorkers::<T>::remove(&owner, &worker);
 
-		Self::deposit_event(Event::<T>::WorkerDeregistered { worker, force: false });
+		Ok(())
+	}
+
+	pub(crate) fn do_increase_worker_deposit(
+		mut worker_info: WorkerInfo<T::AccountId, BalanceOf<T>, T::ImplId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let worker = worker_info.account.clone();
+		T::Currency::transfer(&worker_info.owner, &worker, amount, Preservation::Preserve)?;
+		T::Currency::hold(&HoldReason::WorkerRegistrationReserve.into(), &worker, amount)?;
+
+		worker_info.deposit = worker_info.deposit.saturating_add(amount);
+		let deposit = worker_info.deposit;
+		Workers::<T>::insert(&worker, worker_info);
+
+		Self::deposit_event(Event::<T>::WorkerDepositIncreased { worker, amount, deposit });
+		Ok(())
+	}
+
+	pub(crate) fn do_decrease_worker_deposit(
+		mut worker_info: WorkerInfo<T::AccountId, BalanceOf<T>, T::ImplId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let impl_info = Impls::<T>::get(&worker_info.impl_id).ok_or(Error::<T>::ImplNotFound)?;
+		let min_deposit = impl_info.worker_deposit_floor.max(T::RegisterWorkerDeposit::get());
+		let deposit = worker_info.deposit.saturating_sub(amount);
+		ensure!(deposit >= min_deposit, Error::<T>::WorkerDepositBelowMinimum);
+
+		let worker = worker_info.account.clone();
+		worker_info.deposit = deposit;
+		Workers::<T>::insert(&worker, worker_info);
+		T::OffchainWorkerLifecycleHooks::can_decrease_deposit(&worker)?;
+
+		// A new decreasing restarts the unbonding of the pending amount
+		let unlock_at = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::WorkerDepositUnbondingDurationInBlocks::get().into());
+		WorkerDepositUnbondings::<T>::mutate(&worker, |unbonding| {
+			let pending = unbonding.as_ref().map(|it| it.amount).unwrap_or_default();
+			*unbonding =
+				Some(WorkerDepositUnbonding { amount: pending.saturating_add(amount), unlock_at });
+		});
+
+		Self::deposit_event(Event::<T>::WorkerDepositDecreased {
+			worker,
+			amount,
+			deposit,
+			unlock_at,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_withdraw_unbonded_worker_deposit(
+		worker_info: WorkerInfo<T::AccountId, BalanceOf<T>, T::ImplId>,
+	) -> DispatchResult {
+		let worker = worker_info.account;
+		let unbonding = WorkerDepositUnbondings::<T>::get(&worker)
+			.ok_or(Error::<T>::NoUnbondingWorkerDeposit)?;
+		ensure!(
+			unbonding.unlock_at <= frame_system::Pallet::<T>::block_number(),
+			Error::<T>::WorkerDepositStillUnbonding
+		);
+
+		// The deposit may be slashed, the worker's deposit is kept first
+		let held =
+			T::Currency::balance_on_hold(&HoldReason::WorkerRegistrationReserve.into(), &worker);
+		let amount = unbonding.amount.min(held.saturating_sub(worker_info.deposit));
+		let amount = T::Currency::transfer_on_hold(
+			&HoldReason::WorkerRegistrationReserve.into(),
+			&worker,
+			&worker_info.owner,
+			amount,
+			Precision::BestEffort,
+			Restriction::Free,
+			Fortitude::Polite,
+		)?;
+		WorkerDepositUnbondings::<T>::remove(&worker);
+
+		Self::deposit_event(Event::<T>::WorkerDepositWithdrawn { worker, amount });
 		Ok(())
 	}
 }
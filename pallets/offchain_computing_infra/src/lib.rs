@@ -19,6 +19,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod features;
+pub mod migrations;
 mod traits;
 pub mod weights;
 
@@ -61,7 +62,7 @@ use sp_core::{sr25519, H256};
 use sp_io::crypto::sr25519_verify;
 use sp_runtime::{
 	DispatchError,
-	traits::{StaticLookup, Zero},
+	traits::{One, StaticLookup, Zero},
 	SaturatedConversion, Saturating,
 };
 use sp_std::prelude::*;
@@ -89,7 +90,7 @@ mod pallet {
 	use sp_std::fmt::Display;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -123,6 +124,7 @@ mod pallet {
 		type ImplId: Member
 			+ Parameter
 			+ MaxEncodedLen
+			+ MaybeSerializeDeserialize
 			+ Display
 			+ AtLeast32BitUnsigned
 			+ Incrementable;
@@ -130,6 +132,9 @@ mod pallet {
 		/// Who can register implementation
 		type RegisterImplOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+		/// Who can forcibly deregister workers
+		type WorkerForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// The minimum amount required to keep a worker registration.
 		#[pallet::constant]
 		type RegisterWorkerDeposit: Get<BalanceOf<Self>>;
@@ -155,6 +160,11 @@ mod pallet {
 		#[pallet::constant]
 		type MaxImplBuilds: Get<u32>;
 
+		/// The maximum number of accounts that could publish builds on behalf of the
+		/// implementation's owner.
+		#[pallet::constant]
+		type MaxImplBuildPublishers: Get<u32>;
+
 		/// Max number of moving unresponsive workers to pending offline workers queue
 		#[pallet::constant]
 		type HandleUnresponsivePerBlockLimit: Get<u32>;
@@ -163,6 +173,23 @@ mod pallet {
 		#[pallet::constant]
 		type CollectingHeartbeatsDurationInBlocks: Get<u32>;
 
+		/// The minimum duration (blocks) of collecting workers' heartbeats that an implementation's
+		/// heartbeat period class could derive
+		#[pallet::constant]
+		type MinCollectingHeartbeatsDurationInBlocks: Get<u32>;
+
+		/// The duration (blocks) that a decreased worker deposit keeps held before withdrawing
+		#[pallet::constant]
+		type WorkerDepositUnbondingDurationInBlocks: Get<u32>;
+
+		/// The duration (blocks) that a worker stays inactive before anyone could reap it
+		#[pallet::constant]
+		type ReapWorkerAfterInactiveBlocks: Get<u32>;
+
+		/// The amount taken from the reaped worker's balance as the reaper's reward
+		#[pallet::constant]
+		type WorkerReaperFee: Get<BalanceOf<Self>>;
+
 		/// Allow Opt out attestation
 		#[pallet::constant]
 		type DisallowOptOutAttestation: Get<bool>;
@@ -227,24 +254,78 @@ mod pallet {
 	pub type CounterForImplBuilds<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::ImplId, u32, ValueQuery>;
 
-	/// Storage for flip set, this is for online checking
+	/// The rollouts of the builds in `Canary` status.
+	#[pallet::storage]
+	pub type ImplBuildCanaryRollouts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ImplId,
+		Blake2_128Concat,
+		ImplBuildVersion,
+		CanaryRollout<T::AccountId>,
+		OptionQuery,
+	>;
+
+	/// The results of jobs processed by workers running the build, reported by downstream pallets.
+	#[pallet::storage]
+	pub type ImplBuildHealths<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ImplId,
+		Blake2_128Concat,
+		ImplBuildVersion,
+		ImplBuildHealth,
+		ValueQuery,
+	>;
+
+	/// The accounts delegated by the implementation's owner to publish builds.
+	#[pallet::storage]
+	pub type ImplBuildPublishers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ImplId,
+		BoundedVec<T::AccountId, T::MaxImplBuildPublishers>,
+		ValueQuery,
+	>;
+
+	/// The implementation that an account is willing to accept the ownership of.
 	#[pallet::storage]
-	pub(crate) type FlipSet<T: Config> =
-		CountedStorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+	pub type ImplOwnershipAcceptance<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::ImplId, OptionQuery>;
 
-	/// Storage for flop set, this is for online checking
+	/// The encryption public keys published by workers, they are bound to workers' attestation.
 	#[pallet::storage]
-	pub(crate) type FlopSet<T: Config> =
-		CountedStorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+	pub type WorkerEncryptionKeys<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, EncryptionPublicKey>;
 
-	/// Storage for stage of flip-flop, this is used for online checking
+	/// The worker's deposit that is unbonding, it's still held until withdrawn.
 	#[pallet::storage]
-	pub(crate) type FlipOrFlop<T: Config> = StorageValue<_, FlipFlopStage, ValueQuery>;
+	pub type WorkerDepositUnbondings<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		WorkerDepositUnbonding<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
 
-	/// Storage for stage of flip-flop, this is used for online checking
+	/// The block that the worker became inactive (not online), used for reaping the worker.
 	#[pallet::storage]
-	pub(crate) type CurrentFlipFlopStartedAt<T: Config> =
-		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+	pub type WorkerInactiveSince<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// Online workers bucketed by their heartbeat deadline, workers still in the bucket when the
+	/// deadline comes are unresponsive.
+	#[pallet::storage]
+	pub(crate) type HeartbeatDeadlines<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The window that the online worker should send its next heartbeat.
+	#[pallet::storage]
+	pub(crate) type WorkerHeartbeatWindows<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, HeartbeatWindow<BlockNumberFor<T>>>;
+
+	/// The earliest heartbeat deadline bucket that isn't fully handled yet.
+	#[pallet::storage]
+	pub(crate) type NextHeartbeatDeadlineToHandle<T: Config> = StorageValue<_, BlockNumberFor<T>>;
 
 	/// Stores the `ImplId` that is going to be used for the next implementation.
 	/// This gets incremented whenever a new impl is created.
@@ -273,6 +354,74 @@ mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The implementations to register, as `(impl_id, owner, attestation_method)`.
+		///
+		/// The owners should be endowed to hold the registration and the metadata deposits.
+		pub impls: Vec<(T::ImplId, T::AccountId, AttestationMethod)>,
+		/// The metadata of the implementations, as `(impl_id, metadata)`.
+		pub impl_metadata: Vec<(T::ImplId, Vec<u8>)>,
+		/// The released builds to register, as `(impl_id, version, magic_bytes)`.
+		pub impl_builds: Vec<(T::ImplId, ImplBuildVersion, Option<Vec<u8>>)>,
+		/// The workers to register, as `(owner, worker, impl_id)`.
+		///
+		/// The workers should be endowed to hold the registration deposit and keep alive.
+		pub workers: Vec<(T::AccountId, T::AccountId, T::ImplId)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let mut next_impl_id = NextImplId::<T>::get().unwrap_or(101u32.into());
+			for (impl_id, owner, attestation_method) in &self.impls {
+				Pallet::<T>::do_register_impl(
+					impl_id.clone(),
+					owner.clone(),
+					attestation_method.clone(),
+				)
+				.expect("Genesis implementation should be registered");
+
+				if *impl_id >= next_impl_id {
+					next_impl_id =
+						impl_id.increment().expect("Genesis implementation id is too large");
+				}
+			}
+			NextImplId::<T>::put(next_impl_id);
+
+			for (impl_id, metadata) in &self.impl_metadata {
+				let impl_info = Impls::<T>::get(impl_id)
+					.expect("Genesis metadata should belong to a genesis implementation");
+				let metadata = BoundedVec::try_from(metadata.clone())
+					.expect("Genesis implementation metadata is too long");
+				Pallet::<T>::do_update_impl_metadata(impl_info, metadata)
+					.expect("Genesis implementation metadata should be set");
+			}
+
+			for (impl_id, version, magic_bytes) in &self.impl_builds {
+				let impl_info = Impls::<T>::get(impl_id)
+					.expect("Genesis build should belong to a genesis implementation");
+				let magic_bytes = magic_bytes.clone().map(|magic_bytes| {
+					ImplBuildMagicBytes::try_from(magic_bytes)
+						.expect("Genesis build magic bytes are too long")
+				});
+				Pallet::<T>::do_register_impl_build(impl_info, *version, magic_bytes)
+					.expect("Genesis build should be registered");
+			}
+
+			for (owner, worker, impl_id) in &self.workers {
+				Pallet::<T>::do_register_worker(
+					owner.clone(),
+					worker.clone(),
+					impl_id.clone(),
+					Zero::zero(),
+				)
+				.expect("Genesis worker should be registered");
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -287,6 +436,21 @@ mod pallet {
 			worker: T::AccountId,
 			force: bool,
 		},
+		WorkerDepositIncreased {
+			worker: T::AccountId,
+			amount: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+		},
+		WorkerDepositDecreased {
+			worker: T::AccountId,
+			amount: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+			unlock_at: BlockNumberFor<T>,
+		},
+		WorkerDepositWithdrawn {
+			worker: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 		/// The worker is online
 		WorkerOnline {
 			worker: T::AccountId,
@@ -294,6 +458,7 @@ mod pallet {
 			impl_build_version: ImplBuildVersion,
 			attestation_method: AttestationMethod,
 			attestation_expires_at: Option<u64>,
+			encryption_public_key: Option<EncryptionPublicKey>,
 			next_heartbeat: BlockNumberFor<T>,
 		},
 		WorkerUnresponsive {
@@ -318,6 +483,15 @@ mod pallet {
 		WorkerAttestationRefreshed {
 			worker: T::AccountId,
 			expires_at: Option<u64>,
+			encryption_public_key: Option<EncryptionPublicKey>,
+		},
+		/// The worker upgraded its build without going offline
+		WorkerBuildUpgraded {
+			worker: T::AccountId,
+			impl_spec_version: ImplSpecVersion,
+			impl_build_version: ImplBuildVersion,
+			attestation_expires_at: Option<u64>,
+			encryption_public_key: Option<EncryptionPublicKey>,
 		},
 		ImplRegistered {
 			impl_id: T::ImplId,
@@ -350,6 +524,32 @@ mod pallet {
 			impl_build_version: ImplBuildVersion,
 			status: ImplBuildStatus,
 		},
+		ImplBuildCanaryStarted {
+			impl_id: T::ImplId,
+			impl_build_version: ImplBuildVersion,
+			rollout: CanaryRollout<T::AccountId>,
+		},
+		ImplWorkerRequirementsUpdated {
+			impl_id: T::ImplId,
+			worker_deposit_floor: BalanceOf<T>,
+			heartbeat_period_class: HeartbeatPeriodClass,
+		},
+		ImplBuildPublisherAdded {
+			impl_id: T::ImplId,
+			publisher: T::AccountId,
+		},
+		ImplBuildPublisherRemoved {
+			impl_id: T::ImplId,
+			publisher: T::AccountId,
+		},
+		ImplOwnershipAcceptanceChanged {
+			who: T::AccountId,
+			maybe_impl_id: Option<T::ImplId>,
+		},
+		ImplOwnerChanged {
+			impl_id: T::ImplId,
+			new_owner: T::AccountId,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -367,6 +567,13 @@ mod pallet {
 		DeregisterBlocked,
 		/// Worker's wallet reserved money smaller than should be reserved
 		InsufficientDeposit,
+		/// The worker's deposit can't be lower than the implementation's worker deposit floor
+		WorkerDepositBelowMinimum,
+		NoUnbondingWorkerDeposit,
+		/// The unbonding worker deposit is not unlocked yet
+		WorkerDepositStillUnbonding,
+		/// The worker isn't inactive long enough to be reaped
+		WorkerNotReapable,
 		/// The extrinsic origin isn't the worker's owner
 		NotTheOwner,
 		/// The extrinsic origin isn't the worker
@@ -392,6 +599,8 @@ mod pallet {
 		PayloadSignatureMismatched,
 		ImplMismatched,
 		ImplBuildChanged,
+		/// The worker is already running the build
+		ImplBuildUnchanged,
 		/// Worker's software is blocked or deprecated.
 		ImplBuildRestricted,
 		/// worker implementation's hash mismatch
@@ -411,80 +620,63 @@ mod pallet {
 		ImplBuildNotFound,
 		ImplBuildsLimitExceeded,
 		ImplBuildStillInUse,
+		/// Use `start_impl_build_canary` to set a build to `Canary`
+		CanaryRolloutRequired,
+		ImplBuildNotCanary,
+		/// The canary build reached its max workers
+		ImplBuildCanaryFull,
+		/// The worker isn't in the canary build's allow-list
+		WorkerNotInCanary,
+		/// The worker deposit floor is lower than the runtime's minimum worker deposit
+		WorkerDepositFloorTooLow,
+		/// The heartbeat period class is shorter than the runtime's minimum duration
+		HeartbeatPeriodTooShort,
+		/// The account is already a build publisher of the implementation.
+		ImplBuildPublisherAlreadyAdded,
+		ImplBuildPublisherNotFound,
+		ImplBuildPublishersLimitExceeded,
+		/// The new owner hasn't accepted the implementation's ownership.
+		Unaccepted,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-			let mut reads: u64 = 2; // Read FlipOrFlop and CurrentFlipFlopStartedAt
-			let mut writes: u64 = 0;
-
-			let mut flip_or_flop = FlipOrFlop::<T>::get();
-			let current_flip_flop_started_at = CurrentFlipFlopStartedAt::<T>::get();
-			if n == current_flip_flop_started_at +
-				T::CollectingHeartbeatsDurationInBlocks::get().into()
-			{
-				match flip_or_flop {
-					FlipFlopStage::Flip => {
-						flip_or_flop = FlipFlopStage::FlipToFlop;
-						FlipOrFlop::<T>::set(flip_or_flop);
-						writes += 1;
-					},
-					FlipFlopStage::Flop => {
-						flip_or_flop = FlipFlopStage::FlopToFlip;
-						FlipOrFlop::<T>::set(flip_or_flop);
-						writes += 1;
-					},
-					_ => {},
+			// Only the workers due in the handled buckets are touched, every bucket checked also
+			// counts in the limit, so the weight is bounded regardless of the number of workers.
+			let mut remaining = T::HandleUnresponsivePerBlockLimit::get();
+			let mut handled: u32 = 0;
+			let mut checked_buckets: u64 = 0;
+
+			let mut deadline = NextHeartbeatDeadlineToHandle::<T>::get().unwrap_or(n);
+			while deadline <= n && remaining > 0 {
+				checked_buckets += 1;
+				let workers: Vec<T::AccountId> = HeartbeatDeadlines::<T>::iter_key_prefix(deadline)
+					.take(remaining as usize)
+					.collect();
+				if workers.is_empty() {
+					deadline = deadline.saturating_add(One::one());
+					remaining -= 1;
+					continue
 				}
+
+				let workers_count = workers.len() as u32;
+				for worker in workers {
+					Self::handle_worker_unresponsive(&worker);
+				}
+				handled = handled.saturating_add(workers_count);
+				remaining = remaining.saturating_sub(workers_count);
 			}
-			match flip_or_flop {
-				FlipFlopStage::FlipToFlop => {
-					let iter = FlipSet::<T>::iter_keys()
-						.take(T::HandleUnresponsivePerBlockLimit::get() as usize);
-					let total_count = FlipSet::<T>::count();
-
-					let mut i: u64 = 0;
-					for worker in iter {
-						FlipSet::<T>::remove(&worker);
-						Self::handle_worker_unresponsive(&worker);
-						i += 1;
-					}
-
-					reads += i;
-					writes += i.saturating_mul(3);
-
-					if i >= total_count as u64 {
-						FlipOrFlop::<T>::set(FlipFlopStage::Flop);
-						CurrentFlipFlopStartedAt::<T>::set(n);
-						writes += 2;
-					}
-				},
-				FlipFlopStage::FlopToFlip => {
-					let iter = FlopSet::<T>::iter_keys()
-						.take(T::HandleUnresponsivePerBlockLimit::get() as usize);
-					let total_count = FlopSet::<T>::count();
-
-					let mut i: u64 = 0;
-					for worker in iter {
-						FlopSet::<T>::remove(&worker);
-						Self::handle_worker_unresponsive(&worker);
-						i += 1;
-					}
-
-					reads += i;
-					writes += i.saturating_mul(3);
-
-					if i >= total_count as u64 {
-						FlipOrFlop::<T>::set(FlipFlopStage::Flip);
-						CurrentFlipFlopStartedAt::<T>::set(n);
-						writes += 2;
-					}
-				},
-				_ => {},
-			}
+			NextHeartbeatDeadlineToHandle::<T>::put(deadline);
+
+			// Read and write `NextHeartbeatDeadlineToHandle`, and read the checked buckets
+			let db_weight = T::DbWeight::get().reads_writes(checked_buckets.saturating_add(1), 1);
+			T::WeightInfo::handle_unresponsive_workers(handled).saturating_add(db_weight)
+		}
 
-			T::DbWeight::get().reads_writes(reads, writes)
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
 		}
 	}
 
@@ -531,6 +723,36 @@ mod pallet {
 			Self::do_deregister_worker(who, worker)
 		}
 
+		/// Forcibly deregister a worker, the worker will be offline first if it's online.
+		/// The worker's deposit and balance will be returned to its owner.
+		///
+		/// Origin must be `WorkerForceOrigin`.
+		#[transactional]
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::force_deregister_worker())]
+		pub fn force_deregister_worker(
+			origin: OriginFor<T>,
+			worker: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::WorkerForceOrigin::ensure_origin(origin)?;
+			let worker = T::Lookup::lookup(worker)?;
+			Self::do_force_deregister_worker(worker, None)
+		}
+
+		/// Deregister a worker that has been inactive for `ReapWorkerAfterInactiveBlocks`,
+		/// the sender takes `WorkerReaperFee` from the worker's balance,
+		/// the rest will be returned to the worker's owner.
+		///
+		/// Origin must be Signed.
+		#[transactional]
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::reap_worker())]
+		pub fn reap_worker(origin: OriginFor<T>, worker: AccountIdLookupOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let worker = T::Lookup::lookup(worker)?;
+			Self::do_force_deregister_worker(worker, Some(who))
+		}
+
 		/// The same with balances.transfer_keep_alive(owner, worker, balance)
 		#[transactional]
 		#[pallet::call_index(2)]
@@ -567,6 +789,63 @@ mod pallet {
 			Ok(())
 		}
 
+		/// Hold more of the worker's deposit, the amount is transferred from the owner.
+		///
+		/// Origin must be Signed and the sender should be the worker's owner.
+		#[transactional]
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::increase_worker_deposit())]
+		pub fn increase_worker_deposit(
+			origin: OriginFor<T>,
+			worker: AccountIdLookupOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let worker = T::Lookup::lookup(worker)?;
+			let worker_info = Workers::<T>::get(&worker).ok_or(Error::<T>::WorkerNotFound)?;
+			Self::ensure_owner(&who, &worker_info)?;
+
+			Self::do_increase_worker_deposit(worker_info, amount)
+		}
+
+		/// Decrease the worker's deposit, the amount keeps held until
+		/// `WorkerDepositUnbondingDurationInBlocks` passed, then the owner could withdraw it.
+		///
+		/// Origin must be Signed and the sender should be the worker's owner.
+		#[transactional]
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::decrease_worker_deposit())]
+		pub fn decrease_worker_deposit(
+			origin: OriginFor<T>,
+			worker: AccountIdLookupOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let worker = T::Lookup::lookup(worker)?;
+			let worker_info = Workers::<T>::get(&worker).ok_or(Error::<T>::WorkerNotFound)?;
+			Self::ensure_owner(&who, &worker_info)?;
+
+			Self::do_decrease_worker_deposit(worker_info, amount)
+		}
+
+		/// Withdraw the unlocked worker's deposit to the owner.
+		///
+		/// Origin must be Signed and the sender should be the worker's owner.
+		#[transactional]
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded_worker_deposit())]
+		pub fn withdraw_unbonded_worker_deposit(
+			origin: OriginFor<T>,
+			worker: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let worker = T::Lookup::lookup(worker)?;
+			let worker_info = Workers::<T>::get(&worker).ok_or(Error::<T>::WorkerNotFound)?;
+			Self::ensure_owner(&who, &worker_info)?;
+
+			Self::do_withdraw_unbonded_worker_deposit(worker_info)
+		}
+
 		/// The worker claim for online
 		#[transactional]
 		#[pallet::call_index(4)]
@@ -636,6 +915,20 @@ mod pallet {
 			Self::do_refresh_attestation(who, payload, attestation)
 		}
 
+		/// The worker upgrade to another released build of the implementation in-place,
+		/// it keeps online and its uptime
+		#[transactional]
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::upgrade_build())]
+		pub fn upgrade_build(
+			origin: OriginFor<T>,
+			payload: OnlinePayload<T::ImplId>,
+			attestation: Attestation,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_upgrade_build(who, payload, attestation)
+		}
+
 		/// Worker report it is still online, must called by the worker
 		#[transactional]
 		#[pallet::call_index(10)]
@@ -647,7 +940,7 @@ mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(11)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::register_impl())]
 		pub fn register_impl(
 			origin: OriginFor<T>,
 			attestation_method: AttestationMethod,
@@ -669,7 +962,7 @@ mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(12)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::deregister_impl(T::MaxImplBuilds::get()))]
 		pub fn deregister_impl(origin: OriginFor<T>, impl_id: T::ImplId) -> DispatchResult {
 			let who = T::RegisterImplOrigin::ensure_origin(origin)?;
 
@@ -678,7 +971,7 @@ mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(13)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::update_impl_metadata(T::ImplMetadataLimit::get()))]
 		pub fn update_impl_metadata(
 			origin: OriginFor<T>,
 			impl_id: T::ImplId,
@@ -700,7 +993,7 @@ mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(14)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::register_impl_build())]
 		pub fn register_impl_build(
 			origin: OriginFor<T>,
 			impl_id: T::ImplId,
@@ -710,14 +1003,14 @@ mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let impl_info = Impls::<T>::get(impl_id).ok_or(Error::<T>::ImplNotFound)?;
-			Self::ensure_impl_owner(&who, &impl_info)?;
+			Self::ensure_impl_owner_or_build_publisher(&who, &impl_info)?;
 
 			Self::do_register_impl_build(impl_info, version, magic_bytes)
 		}
 
 		#[transactional]
 		#[pallet::call_index(15)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::deregister_impl_build())]
 		pub fn deregister_impl_build(
 			origin: OriginFor<T>,
 			impl_id: T::ImplId,
@@ -733,7 +1026,7 @@ mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(16)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::update_impl_build_status())]
 		pub fn update_impl_build_status(
 			origin: OriginFor<T>,
 			impl_id: T::ImplId,
@@ -743,17 +1036,163 @@ mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
-			Self::ensure_impl_owner(&who, &impl_info)?;
+			if who != impl_info.owner {
+				// Build publishers could only deprecate builds
+				Self::ensure_impl_build_publisher(&who, &impl_id)?;
+				ensure!(status == ImplBuildStatus::Deprecated, Error::<T>::NoPermission);
+				let impl_build_info =
+					ImplBuilds::<T>::get(&impl_id, version).ok_or(Error::<T>::ImplBuildNotFound)?;
+				ensure!(
+					impl_build_info.status != ImplBuildStatus::Retired,
+					Error::<T>::NoPermission
+				);
+			}
 
 			Self::do_update_impl_build_status(impl_id, version, status)
 		}
+
+		/// Roll out a released build to a limited set of workers,
+		/// it could also be used to change the rollout of a canary build.
+		///
+		/// Origin must be Signed and the sender should be the implementation's owner or a build
+		/// publisher.
+		#[transactional]
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::start_impl_build_canary())]
+		pub fn start_impl_build_canary(
+			origin: OriginFor<T>,
+			impl_id: T::ImplId,
+			version: ImplBuildVersion,
+			rollout: CanaryRollout<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
+			Self::ensure_impl_owner_or_build_publisher(&who, &impl_info)?;
+
+			Self::do_start_impl_build_canary(impl_id, version, rollout)
+		}
+
+		/// Release the canary build to all workers.
+		///
+		/// Origin must be Signed and the sender should be the implementation's owner.
+		#[transactional]
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::promote_impl_build())]
+		pub fn promote_impl_build(
+			origin: OriginFor<T>,
+			impl_id: T::ImplId,
+			version: ImplBuildVersion,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
+			Self::ensure_impl_owner(&who, &impl_info)?;
+
+			Self::do_promote_impl_build(impl_id, version)
+		}
+
+		/// Update the deposit floor and the heartbeat period class of the implementation's
+		/// workers, online workers follow the new heartbeat period class from their next
+		/// heartbeat.
+		///
+		/// Origin must be Signed and the sender should be the implementation's owner.
+		#[transactional]
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::update_impl_worker_requirements())]
+		pub fn update_impl_worker_requirements(
+			origin: OriginFor<T>,
+			impl_id: T::ImplId,
+			worker_deposit_floor: BalanceOf<T>,
+			heartbeat_period_class: HeartbeatPeriodClass,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
+			Self::ensure_impl_owner(&who, &impl_info)?;
+
+			Self::do_update_impl_worker_requirements(
+				impl_info,
+				worker_deposit_floor,
+				heartbeat_period_class,
+			)
+		}
+
+		/// Delegate an account to register builds and deprecate builds of the implementation.
+		///
+		/// Origin must be Signed and the sender should be the implementation's owner.
+		#[transactional]
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::add_impl_build_publisher())]
+		pub fn add_impl_build_publisher(
+			origin: OriginFor<T>,
+			impl_id: T::ImplId,
+			publisher: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let publisher = T::Lookup::lookup(publisher)?;
+
+			let impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
+			Self::ensure_impl_owner(&who, &impl_info)?;
+
+			Self::do_add_impl_build_publisher(impl_id, publisher)
+		}
+
+		#[transactional]
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::remove_impl_build_publisher())]
+		pub fn remove_impl_build_publisher(
+			origin: OriginFor<T>,
+			impl_id: T::ImplId,
+			publisher: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let publisher = T::Lookup::lookup(publisher)?;
+
+			let impl_info = Impls::<T>::get(&impl_id).ok_or(Error::<T>::ImplNotFound)?;
+			Self::ensure_impl_owner(&who, &impl_info)?;
+
+			Self::do_remove_impl_build_publisher(impl_id, publisher)
+		}
+
+		/// Set (or reset) the acceptance of ownership for a particular account.
+		///
+		/// - `maybe_impl_id`: The implementation whose ownership the sender is willing to accept,
+		///   `None` to accept none.
+		#[transactional]
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_accept_impl_ownership())]
+		pub fn set_accept_impl_ownership(
+			origin: OriginFor<T>,
+			maybe_impl_id: Option<T::ImplId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_accept_impl_ownership(who, maybe_impl_id)
+		}
+
+		/// Change the owner of the implementation, the new owner must have accepted the ownership
+		/// by `set_accept_impl_ownership`.
+		#[transactional]
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::transfer_impl_ownership())]
+		pub fn transfer_impl_ownership(
+			origin: OriginFor<T>,
+			impl_id: T::ImplId,
+			new_owner: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+
+			Self::do_transfer_impl_ownership(who, impl_id, new_owner)
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
 	pub(crate) fn set_worker_unresponsive(worker: &T::AccountId) {
-		FlipSet::<T>::remove(worker);
-		FlopSet::<T>::remove(worker);
+		Self::unschedule_heartbeat(worker);
+		WorkerInactiveSince::<T>::insert(worker, frame_system::Pallet::<T>::block_number());
 		Workers::<T>::mutate(worker, |worker_info| {
 			if let Some(info) = worker_info.as_mut() {
 				info.status = WorkerStatus::Unresponsive;
@@ -766,8 +1205,12 @@ impl<T: Config> Pallet<T> {
 	pub(crate) fn set_worker_offline(worker: &T::AccountId, reason: OfflineReason) {
 		T::OffchainWorkerLifecycleHooks::before_offline(worker, reason.clone());
 
-		FlipSet::<T>::remove(worker);
-		FlopSet::<T>::remove(worker);
+		Self::unschedule_heartbeat(worker);
+		WorkerEncryptionKeys::<T>::remove(worker);
+		// Unresponsive workers keep the block they became inactive
+		if !WorkerInactiveSince::<T>::contains_key(worker) {
+			WorkerInactiveSince::<T>::insert(worker, frame_system::Pallet::<T>::block_number());
+		}
 		Workers::<T>::mutate(worker, |worker_info| {
 			if let Some(info) = worker_info.as_mut() {
 				if let Some(impl_build_version) = info.impl_build_version {
@@ -793,19 +1236,28 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::<T>::WorkerOffline { worker: worker.clone(), reason });
 	}
 
-	pub(crate) fn flip_flop_for_online(worker: &T::AccountId) -> BlockNumberFor<T> {
-		let next_heartbeat = Self::generate_next_heartbeat_block();
-		let stage = FlipOrFlop::<T>::get();
-		match stage {
-			FlipFlopStage::Flip | FlipFlopStage::FlopToFlip => {
-				FlopSet::<T>::insert(worker, next_heartbeat);
-			},
-			FlipFlopStage::Flop | FlipFlopStage::FlipToFlop => {
-				FlipSet::<T>::insert(worker, next_heartbeat);
-			},
+	/// Schedule the worker's next heartbeat, it replaces the previous schedule.
+	pub(crate) fn schedule_heartbeat(
+		worker: &T::AccountId,
+		class: HeartbeatPeriodClass,
+	) -> BlockNumberFor<T> {
+		Self::unschedule_heartbeat(worker);
+
+		let window = Self::generate_next_heartbeat_window(class);
+		HeartbeatDeadlines::<T>::insert(window.deadline, worker, ());
+		WorkerHeartbeatWindows::<T>::insert(worker, window.clone());
+
+		window.next_heartbeat
+	}
+
+	pub(crate) fn unschedule_heartbeat(worker: &T::AccountId) {
+		if let Some(window) = WorkerHeartbeatWindows::<T>::take(worker) {
+			HeartbeatDeadlines::<T>::remove(window.deadline, worker);
 		}
+	}
 
-		next_heartbeat
+	pub(crate) fn collecting_heartbeats_duration(class: HeartbeatPeriodClass) -> u32 {
+		class.collecting_duration(T::CollectingHeartbeatsDurationInBlocks::get())
 	}
 
 	pub(crate) fn handle_worker_unresponsive(worker: &T::AccountId) {
@@ -875,6 +1327,51 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn ensure_impl_build_publisher(
+		who: &T::AccountId,
+		impl_id: &T::ImplId,
+	) -> DispatchResult {
+		ensure!(ImplBuildPublishers::<T>::get(impl_id).contains(who), Error::<T>::NoPermission);
+
+		Ok(())
+	}
+
+	pub(crate) fn ensure_impl_owner_or_build_publisher(
+		who: &T::AccountId,
+		impl_info: &ImplInfo<T::ImplId, T::AccountId, BalanceOf<T>>,
+	) -> DispatchResult {
+		if who == &impl_info.owner {
+			return Ok(())
+		}
+
+		Self::ensure_impl_build_publisher(who, &impl_info.id)
+	}
+
+	/// Check the canary build admits the worker,
+	/// `counting` means the worker isn't counted in the build's `workers_count` yet.
+	pub(crate) fn ensure_canary_admits(
+		worker: &T::AccountId,
+		impl_id: &T::ImplId,
+		impl_build_info: &ImplBuildInfo,
+		counting: bool,
+	) -> DispatchResult {
+		let rollout = ImplBuildCanaryRollouts::<T>::get(impl_id, impl_build_info.version)
+			.ok_or(Error::<T>::InternalError)?;
+		match rollout {
+			CanaryRollout::MaxWorkers(max_workers) => {
+				ensure!(
+					!counting || impl_build_info.workers_count < max_workers,
+					Error::<T>::ImplBuildCanaryFull
+				);
+			},
+			CanaryRollout::AllowList(allowed_workers) => {
+				ensure!(allowed_workers.contains(worker), Error::<T>::WorkerNotInCanary);
+			},
+		}
+
+		Ok(())
+	}
+
 	pub(crate) fn ensure_attestation_method(
 		attestation: &Attestation,
 		worker_info: &WorkerInfo<T::AccountId, BalanceOf<T>, T::ImplId>,
@@ -908,12 +1405,17 @@ impl<T: Config> Pallet<T> {
 		random_number
 	}
 
-	pub(crate) fn generate_next_heartbeat_block() -> BlockNumberFor<T> {
-		let current_flip_flop_started_at = CurrentFlipFlopStartedAt::<T>::get();
-		let duration = T::CollectingHeartbeatsDurationInBlocks::get();
+	pub(crate) fn generate_next_heartbeat_window(
+		class: HeartbeatPeriodClass,
+	) -> HeartbeatWindow<BlockNumberFor<T>> {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let duration = Self::collecting_heartbeats_duration(class);
 		let random_delay = Self::generate_random_number(0) % (duration * 4 / 5); // Give ~20% room
 
-		current_flip_flop_started_at + (duration + random_delay).into()
+		HeartbeatWindow {
+			next_heartbeat: current_block + (duration + random_delay).into(),
+			deadline: current_block + duration.saturating_mul(2).into(),
+		}
 	}
 
 	pub fn impl_info(
@@ -936,6 +1438,40 @@ impl<T: Config> Pallet<T> {
 		Workers::<T>::contains_key(worker)
 	}
 
+	pub fn worker_encryption_key(worker: &T::AccountId) -> Option<EncryptionPublicKey> {
+		WorkerEncryptionKeys::<T>::get(worker)
+	}
+
+	pub(crate) fn update_worker_encryption_key(
+		worker: &T::AccountId,
+		encryption_public_key: Option<EncryptionPublicKey>,
+	) {
+		if let Some(encryption_public_key) = encryption_public_key {
+			WorkerEncryptionKeys::<T>::insert(worker, encryption_public_key);
+		} else {
+			WorkerEncryptionKeys::<T>::remove(worker);
+		}
+	}
+
+	/// Count a job processed by a worker running the build, used for watching canary builds.
+	pub fn record_impl_build_job_result(
+		impl_id: &T::ImplId,
+		impl_build_version: ImplBuildVersion,
+		succeeded: bool,
+	) {
+		if !ImplBuilds::<T>::contains_key(impl_id, impl_build_version) {
+			return
+		}
+
+		ImplBuildHealths::<T>::mutate(impl_id, impl_build_version, |health| {
+			if succeeded {
+				health.jobs_succeeded.saturating_inc();
+			} else {
+				health.jobs_failed.saturating_inc();
+			}
+		});
+	}
+
 	pub fn reward_worker(
 		worker: &T::AccountId,
 		source: &T::AccountId,
@@ -961,8 +1497,8 @@ impl<T: Config> Pallet<T> {
 		worker_info.status = WorkerStatus::Offline;
 		Workers::<T>::insert(worker, worker_info);
 
-		FlipSet::<T>::remove(worker);
-		FlopSet::<T>::remove(worker);
+		Self::unschedule_heartbeat(worker);
+		WorkerInactiveSince::<T>::insert(worker, frame_system::Pallet::<T>::block_number());
 
 		Self::deposit_event(Event::<T>::WorkerOffline { worker: worker.clone(), reason });
 
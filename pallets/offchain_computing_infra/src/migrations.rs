@@ -0,0 +1,218 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations for the off-chain computing infra pallet.
+//!
+//! The migrations run in a single block. They touch each implementation and worker a few
+//! times, and the removed flip-flop sets hold at most one entry per worker, so their weight
+//! grows with the registered workers only.
+
+use crate::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade,
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Migrate the storage from v1 to v2.
+///
+/// - Implementations get the minimum worker deposit floor `RegisterWorkerDeposit` and the standard
+///   heartbeat period class.
+/// - The flip-flop online checking is replaced by heartbeat deadlines, so online workers are
+///   scheduled a new heartbeat window, and inactive workers start to count down for reaping.
+pub mod v2 {
+	use super::*;
+
+	/// The storage layouts before v2.
+	pub(crate) mod v1 {
+		use super::*;
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+		pub struct ImplInfo<ImplId, AccountId, Balance> {
+			pub id: ImplId,
+			pub owner: AccountId,
+			pub owner_deposit: Balance,
+			pub attestation_method: AttestationMethod,
+			pub workers_count: u32,
+		}
+
+		#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
+		pub enum FlipFlopStage {
+			#[default]
+			Flip,
+			Flop,
+			FlipToFlop,
+			FlopToFlip,
+		}
+
+		#[storage_alias]
+		pub type Impls<T: Config> = CountedStorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as Config>::ImplId,
+			ImplInfo<<T as Config>::ImplId, <T as frame_system::Config>::AccountId, BalanceOf<T>>,
+		>;
+
+		#[storage_alias]
+		pub type FlipSet<T: Config> = CountedStorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			BlockNumberFor<T>,
+		>;
+
+		#[storage_alias]
+		pub type FlopSet<T: Config> = CountedStorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			BlockNumberFor<T>,
+		>;
+
+		#[storage_alias]
+		pub type FlipOrFlop<T: Config> = StorageValue<Pallet<T>, FlipFlopStage, ValueQuery>;
+
+		#[storage_alias]
+		pub type CurrentFlipFlopStartedAt<T: Config> =
+			StorageValue<Pallet<T>, BlockNumberFor<T>, ValueQuery>;
+	}
+
+	/// The migration without checking the storage version, use [`MigrateV1ToV2`] instead.
+	pub struct VersionUncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+
+			Impls::<T>::translate::<v1::ImplInfo<T::ImplId, T::AccountId, BalanceOf<T>>, _>(
+				|_, old| {
+					reads.saturating_inc();
+					writes.saturating_inc();
+
+					Some(ImplInfo {
+						id: old.id,
+						owner: old.owner,
+						owner_deposit: old.owner_deposit,
+						attestation_method: old.attestation_method,
+						workers_count: old.workers_count,
+						worker_deposit_floor: T::RegisterWorkerDeposit::get(),
+						heartbeat_period_class: HeartbeatPeriodClass::Standard,
+					})
+				},
+			);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let mut rescheduled: u32 = 0;
+			for (worker, worker_info) in Workers::<T>::iter() {
+				reads.saturating_inc();
+				match worker_info.status {
+					WorkerStatus::Online | WorkerStatus::RequestingOffline => {
+						let class = Impls::<T>::get(&worker_info.impl_id)
+							.map(|impl_info| impl_info.heartbeat_period_class)
+							.unwrap_or_default();
+						Pallet::<T>::schedule_heartbeat(&worker, class);
+						rescheduled.saturating_inc();
+						reads.saturating_accrue(3);
+						writes.saturating_accrue(2);
+					},
+					_ => {
+						reads.saturating_inc();
+						if !WorkerInactiveSince::<T>::contains_key(&worker) {
+							WorkerInactiveSince::<T>::insert(&worker, current_block);
+							writes.saturating_inc();
+						}
+					},
+				}
+			}
+
+			// Every entry is a worker, so the sets are bounded by the registered workers
+			let flip_removed = v1::FlipSet::<T>::clear(v1::FlipSet::<T>::count(), None).unique;
+			let flop_removed = v1::FlopSet::<T>::clear(v1::FlopSet::<T>::count(), None).unique;
+			v1::FlipOrFlop::<T>::kill();
+			v1::CurrentFlipFlopStartedAt::<T>::kill();
+			writes.saturating_accrue((flip_removed as u64).saturating_add(flop_removed as u64));
+			writes.saturating_accrue(4);
+
+			log!(info, "Migrated to v2, {} online workers rescheduled", rescheduled);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let impls_count = v1::Impls::<T>::count();
+			ensure!(
+				v1::Impls::<T>::iter().count() as u32 == impls_count,
+				"v1 implementations are undecodable"
+			);
+
+			let workers_count = Workers::<T>::count();
+			let online_workers_count = Workers::<T>::iter_values()
+				.filter(|info| {
+					matches!(info.status, WorkerStatus::Online | WorkerStatus::RequestingOffline)
+				})
+				.count() as u32;
+
+			Ok((impls_count, workers_count, online_workers_count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (impls_count, workers_count, online_workers_count): (u32, u32, u32) =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "the state of pre-upgrade is undecodable")?;
+
+			ensure!(Impls::<T>::count() == impls_count, "implementations count mismatch");
+			ensure!(
+				Impls::<T>::iter().count() as u32 == impls_count,
+				"v2 implementations are undecodable"
+			);
+			ensure!(Workers::<T>::count() == workers_count, "workers count mismatch");
+			ensure!(
+				WorkerHeartbeatWindows::<T>::iter().count() as u32 == online_workers_count,
+				"online workers aren't all scheduled"
+			);
+			ensure!(
+				WorkerInactiveSince::<T>::iter().count() as u32 ==
+					workers_count.saturating_sub(online_workers_count),
+				"inactive workers aren't all tracked"
+			);
+			ensure!(
+				v1::FlipSet::<T>::count() == 0 && v1::FlopSet::<T>::count() == 0,
+				"flip-flop sets aren't cleared"
+			);
+			ensure!(
+				!v1::FlipOrFlop::<T>::exists() && !v1::CurrentFlipFlopStartedAt::<T>::exists(),
+				"flip-flop stage isn't cleared"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Migrate v1 to v2, it only runs when the on-chain storage version is 1.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		VersionUncheckedMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -22,12 +22,9 @@ use frame_support::{
 	derive_impl,
 	traits::{fungible::Mutate, OnFinalize, OnInitialize},
 };
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::{ConstBool, ConstU128, ConstU16, ConstU32, ConstU64};
-use sp_runtime::{
-	traits::IdentityLookup,
-	BuildStorage,
-};
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -97,14 +94,20 @@ impl pallet_offchain_computing_infra::Config for Test {
 	type Randomness = RandomnessCollectiveFlip;
 	type ImplId = u32;
 	type RegisterImplOrigin = EnsureSigned<Self::AccountId>;
+	type WorkerForceOrigin = EnsureRoot<Self::AccountId>;
 	type RegisterWorkerDeposit = ConstU128<{ 100 * DOLLARS }>;
 	type RegisterImplDeposit = ConstU128<{ DOLLARS }>;
 	type ImplMetadataDepositBase = ConstU128<{ DOLLARS }>;
 	type ImplMetadataDepositPerByte = ConstU128<{ CENTS }>;
 	type ImplMetadataLimit = ConstU32<50>;
 	type MaxImplBuilds = ConstU32<4>;
+	type MaxImplBuildPublishers = ConstU32<2>;
 	type HandleUnresponsivePerBlockLimit = ConstU32<3>;
 	type CollectingHeartbeatsDurationInBlocks = ConstU32<6>;
+	type MinCollectingHeartbeatsDurationInBlocks = ConstU32<4>;
+	type WorkerDepositUnbondingDurationInBlocks = ConstU32<10>;
+	type ReapWorkerAfterInactiveBlocks = ConstU32<20>;
+	type WorkerReaperFee = ConstU128<{ DOLLARS }>;
 	type DisallowOptOutAttestation = ConstBool<false>;
 	type WeightInfo = ();
 	type OffchainWorkerLifecycleHooks = ();
@@ -118,6 +121,15 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	t.into()
 }
 
+/// Execute the test, then check the pallet's storage invariants.
+#[allow(unused)]
+pub(crate) fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		OffchainComputingInfra::do_try_state().expect("storage invariants should hold");
+	});
+}
+
 #[allow(unused)]
 pub(crate) fn run_to_block(n: u64) {
 	// NOTE that this function only simulates modules of interest. Depending on new pallet may
@@ -19,7 +19,7 @@
 #[allow(unused)]
 use crate::{mock::*, BalanceOf, Config, Error, Event as OffchainComputingInfraEvent, Workers};
 #[allow(unused)]
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, traits::Hooks};
 #[allow(unused)]
 use frame_system::Account;
 #[allow(unused)]
@@ -101,7 +101,7 @@ fn register_worker_for(
 
 #[test]
 fn register_worker_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		set_balance(ALICE, 201 * DOLLARS);
 
 		run_to_block(1);
@@ -136,7 +136,7 @@ fn register_worker_works() {
 
 #[test]
 fn deregister_worker_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		set_balance(ALICE, 201 * DOLLARS);
 
 		run_to_block(1);
@@ -155,3 +155,740 @@ fn deregister_worker_works() {
 		assert!(!Account::<Test>::contains_key(ALICE_WORKER));
 	});
 }
+
+#[test]
+fn impl_build_publishers_and_ownership_transfer_work() {
+	build_and_execute(|| {
+		set_balance(ALICE, 201 * DOLLARS);
+		set_balance(BOB, 10 * DOLLARS);
+
+		run_to_block(1);
+
+		let (impl_id, _impl_spec_version) = mock_impl_and_build(ALICE);
+		let publisher = BOB_WORKER;
+
+		assert_noop!(
+			OffchainComputingInfra::register_impl_build(
+				RuntimeOrigin::signed(publisher),
+				impl_id,
+				1,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			OffchainComputingInfra::add_impl_build_publisher(
+				RuntimeOrigin::signed(BOB),
+				impl_id,
+				publisher,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(OffchainComputingInfra::add_impl_build_publisher(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			publisher,
+		));
+
+		// Publishers could register builds and deprecate them, but not retire them
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(publisher),
+			impl_id,
+			1,
+			None,
+		));
+		assert_noop!(
+			OffchainComputingInfra::update_impl_build_status(
+				RuntimeOrigin::signed(publisher),
+				impl_id,
+				1,
+				ImplBuildStatus::Retired,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(OffchainComputingInfra::update_impl_build_status(
+			RuntimeOrigin::signed(publisher),
+			impl_id,
+			1,
+			ImplBuildStatus::Deprecated,
+		));
+		assert_noop!(
+			OffchainComputingInfra::deregister_impl_build(
+				RuntimeOrigin::signed(publisher),
+				impl_id,
+				1,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			OffchainComputingInfra::deregister_impl(RuntimeOrigin::signed(publisher), impl_id),
+			Error::<Test>::NoPermission
+		);
+
+		// Transfer the ownership
+		assert_noop!(
+			OffchainComputingInfra::transfer_impl_ownership(
+				RuntimeOrigin::signed(ALICE),
+				impl_id,
+				BOB,
+			),
+			Error::<Test>::Unaccepted
+		);
+		assert_ok!(OffchainComputingInfra::set_accept_impl_ownership(
+			RuntimeOrigin::signed(BOB),
+			Some(impl_id),
+		));
+		assert_ok!(OffchainComputingInfra::transfer_impl_ownership(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			BOB,
+		));
+		assert_eq!(
+			last_event(),
+			OffchainComputingInfraEvent::ImplOwnerChanged { impl_id, new_owner: BOB }
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), DOLLARS);
+
+		// The publisher is still delegated
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(publisher),
+			impl_id,
+			2,
+			None,
+		));
+		assert_ok!(OffchainComputingInfra::remove_impl_build_publisher(
+			RuntimeOrigin::signed(BOB),
+			impl_id,
+			publisher,
+		));
+		assert_noop!(
+			OffchainComputingInfra::register_impl_build(
+				RuntimeOrigin::signed(publisher),
+				impl_id,
+				3,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(OffchainComputingInfra::deregister_impl(RuntimeOrigin::signed(BOB), impl_id));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+fn online_payload(impl_id: ImplId, impl_build_version: ImplBuildVersion) -> OnlinePayload<ImplId> {
+	OnlinePayload {
+		impl_id,
+		impl_spec_version: 1,
+		impl_build_version,
+		impl_build_magic_bytes: Default::default(),
+		encryption_public_key: None,
+		capabilities: Default::default(),
+		job_slots: None,
+	}
+}
+
+#[test]
+fn upgrade_build_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 201 * DOLLARS);
+
+		run_to_block(1);
+
+		let (impl_id, _impl_spec_version) = mock_impl_and_build(ALICE);
+		for version in 1..=3 {
+			assert_ok!(OffchainComputingInfra::register_impl_build(
+				RuntimeOrigin::signed(ALICE),
+				impl_id,
+				version,
+				None,
+			));
+		}
+		assert_ok!(OffchainComputingInfra::update_impl_build_status(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			3,
+			ImplBuildStatus::Deprecated,
+		));
+		register_worker_for(ALICE, ALICE_WORKER, impl_id, 101 * DOLLARS);
+
+		assert_noop!(
+			OffchainComputingInfra::upgrade_build(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				online_payload(impl_id, 2),
+				Attestation::OptOut,
+			),
+			Error::<Test>::WorkerNotOnline
+		);
+		assert_ok!(OffchainComputingInfra::online(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			online_payload(impl_id, 1),
+			Attestation::OptOut,
+		));
+		let online_worker_info = Workers::<Test>::get(ALICE_WORKER).unwrap();
+
+		run_to_block(2);
+
+		assert_noop!(
+			OffchainComputingInfra::upgrade_build(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				online_payload(impl_id, 1),
+				Attestation::OptOut,
+			),
+			Error::<Test>::ImplBuildUnchanged
+		);
+		assert_noop!(
+			OffchainComputingInfra::upgrade_build(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				online_payload(impl_id, 3),
+				Attestation::OptOut,
+			),
+			Error::<Test>::ImplBuildRestricted
+		);
+		assert_ok!(OffchainComputingInfra::upgrade_build(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			online_payload(impl_id, 2),
+			Attestation::OptOut,
+		));
+
+		let worker_info = Workers::<Test>::get(ALICE_WORKER).unwrap();
+		assert_eq!(worker_info.status, WorkerStatus::Online);
+		assert_eq!(worker_info.impl_build_version, Some(2));
+		assert_eq!(worker_info.uptime_started_at, online_worker_info.uptime_started_at);
+		assert_eq!(worker_info.uptime, online_worker_info.uptime);
+		assert_eq!(crate::ImplBuilds::<Test>::get(impl_id, 1).unwrap().workers_count, 0);
+		assert_eq!(crate::ImplBuilds::<Test>::get(impl_id, 2).unwrap().workers_count, 1);
+	});
+}
+
+#[test]
+fn impl_build_canary_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS);
+
+		run_to_block(1);
+
+		let (impl_id, _impl_spec_version) = mock_impl_and_build(ALICE);
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			1,
+			None,
+		));
+		assert_noop!(
+			OffchainComputingInfra::update_impl_build_status(
+				RuntimeOrigin::signed(ALICE),
+				impl_id,
+				1,
+				ImplBuildStatus::Canary,
+			),
+			Error::<Test>::CanaryRolloutRequired
+		);
+		assert_ok!(OffchainComputingInfra::start_impl_build_canary(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			1,
+			CanaryRollout::MaxWorkers(1),
+		));
+		register_worker_for(ALICE, ALICE_WORKER, impl_id, 101 * DOLLARS);
+		register_worker_for(ALICE, BOB_WORKER, impl_id, 101 * DOLLARS);
+
+		// Only one worker could run the canary build
+		assert_ok!(OffchainComputingInfra::online(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			online_payload(impl_id, 1),
+			Attestation::OptOut,
+		));
+		assert_noop!(
+			OffchainComputingInfra::online(
+				RuntimeOrigin::signed(BOB_WORKER),
+				online_payload(impl_id, 1),
+				Attestation::OptOut,
+			),
+			Error::<Test>::ImplBuildCanaryFull
+		);
+
+		// Switch to an allow-list
+		assert_ok!(OffchainComputingInfra::start_impl_build_canary(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			1,
+			CanaryRollout::AllowList(vec![ALICE_WORKER].try_into().unwrap()),
+		));
+		assert_noop!(
+			OffchainComputingInfra::online(
+				RuntimeOrigin::signed(BOB_WORKER),
+				online_payload(impl_id, 1),
+				Attestation::OptOut,
+			),
+			Error::<Test>::WorkerNotInCanary
+		);
+
+		OffchainComputingInfra::record_impl_build_job_result(&impl_id, 1, true);
+		OffchainComputingInfra::record_impl_build_job_result(&impl_id, 1, false);
+		assert_eq!(
+			crate::ImplBuildHealths::<Test>::get(impl_id, 1),
+			ImplBuildHealth { jobs_succeeded: 1, jobs_failed: 1 }
+		);
+
+		// Promote to all workers
+		assert_noop!(
+			OffchainComputingInfra::promote_impl_build(RuntimeOrigin::signed(BOB), impl_id, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(OffchainComputingInfra::promote_impl_build(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			1
+		));
+		assert!(!crate::ImplBuildCanaryRollouts::<Test>::contains_key(impl_id, 1));
+		assert_noop!(
+			OffchainComputingInfra::promote_impl_build(RuntimeOrigin::signed(ALICE), impl_id, 1),
+			Error::<Test>::ImplBuildNotCanary
+		);
+		assert_ok!(OffchainComputingInfra::online(
+			RuntimeOrigin::signed(BOB_WORKER),
+			online_payload(impl_id, 1),
+			Attestation::OptOut,
+		));
+		assert_eq!(crate::ImplBuilds::<Test>::get(impl_id, 1).unwrap().workers_count, 2);
+	});
+}
+
+#[test]
+fn impl_worker_requirements_work() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS);
+
+		run_to_block(1);
+
+		let (impl_id, _impl_spec_version) = mock_impl_and_build(ALICE);
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			1,
+			None,
+		));
+
+		assert_noop!(
+			OffchainComputingInfra::update_impl_worker_requirements(
+				RuntimeOrigin::signed(BOB),
+				impl_id,
+				150 * DOLLARS,
+				HeartbeatPeriodClass::Slow,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			OffchainComputingInfra::update_impl_worker_requirements(
+				RuntimeOrigin::signed(ALICE),
+				impl_id,
+				50 * DOLLARS,
+				HeartbeatPeriodClass::Slow,
+			),
+			Error::<Test>::WorkerDepositFloorTooLow
+		);
+		assert_noop!(
+			OffchainComputingInfra::update_impl_worker_requirements(
+				RuntimeOrigin::signed(ALICE),
+				impl_id,
+				150 * DOLLARS,
+				HeartbeatPeriodClass::Fast,
+			),
+			Error::<Test>::HeartbeatPeriodTooShort
+		);
+		assert_ok!(OffchainComputingInfra::update_impl_worker_requirements(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			150 * DOLLARS,
+			HeartbeatPeriodClass::Slow,
+		));
+		assert_eq!(
+			last_event(),
+			OffchainComputingInfraEvent::ImplWorkerRequirementsUpdated {
+				impl_id,
+				worker_deposit_floor: 150 * DOLLARS,
+				heartbeat_period_class: HeartbeatPeriodClass::Slow,
+			}
+		);
+
+		// Workers have to hold the implementation's deposit floor
+		assert_noop!(
+			OffchainComputingInfra::register_worker(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_WORKER,
+				impl_id,
+				101 * DOLLARS
+			),
+			Error::<Test>::InitialBalanceTooLow
+		);
+		let worker_info = register_worker_for(ALICE, ALICE_WORKER, impl_id, 151 * DOLLARS);
+		assert_eq!(worker_info.deposit, 150 * DOLLARS);
+
+		// Workers follow the implementation's heartbeat period class
+		assert_ok!(OffchainComputingInfra::online(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			online_payload(impl_id, 1),
+			Attestation::OptOut,
+		));
+		let window = crate::WorkerHeartbeatWindows::<Test>::get(ALICE_WORKER).unwrap();
+		assert!((13..22).contains(&window.next_heartbeat));
+		assert_eq!(window.deadline, 25);
+		assert!(crate::HeartbeatDeadlines::<Test>::contains_key(25, ALICE_WORKER));
+
+		// Online workers switch to the new heartbeat period class from the next heartbeat
+		assert_ok!(OffchainComputingInfra::update_impl_worker_requirements(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			120 * DOLLARS,
+			HeartbeatPeriodClass::Standard,
+		));
+		run_to_block(window.next_heartbeat);
+		assert_ok!(OffchainComputingInfra::heartbeat(RuntimeOrigin::signed(ALICE_WORKER)));
+		assert!(!crate::HeartbeatDeadlines::<Test>::contains_key(25, ALICE_WORKER));
+		let new_window = crate::WorkerHeartbeatWindows::<Test>::get(ALICE_WORKER).unwrap();
+		assert_eq!(new_window.deadline, window.next_heartbeat + 12);
+
+		assert_ok!(OffchainComputingInfra::request_offline(RuntimeOrigin::signed(ALICE_WORKER)));
+		assert!(!crate::WorkerHeartbeatWindows::<Test>::contains_key(ALICE_WORKER));
+		assert!(!crate::HeartbeatDeadlines::<Test>::contains_key(
+			new_window.deadline,
+			ALICE_WORKER
+		));
+	});
+}
+
+#[test]
+fn heartbeat_deadline_marks_worker_unresponsive() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS);
+
+		run_to_block(1);
+
+		let (impl_id, _impl_spec_version) = mock_impl_and_build(ALICE);
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			1,
+			None,
+		));
+		for worker in [ALICE_WORKER, BOB_WORKER] {
+			register_worker_for(ALICE, worker, impl_id, 101 * DOLLARS);
+			assert_ok!(OffchainComputingInfra::online(
+				RuntimeOrigin::signed(worker),
+				online_payload(impl_id, 1),
+				Attestation::OptOut,
+			));
+		}
+
+		let window = crate::WorkerHeartbeatWindows::<Test>::get(ALICE_WORKER).unwrap();
+		assert_eq!(window.deadline, 13);
+		assert_noop!(
+			OffchainComputingInfra::heartbeat(RuntimeOrigin::signed(ALICE_WORKER)),
+			Error::<Test>::TooEarly
+		);
+		run_to_block(window.next_heartbeat);
+		assert_ok!(OffchainComputingInfra::heartbeat(RuntimeOrigin::signed(ALICE_WORKER)));
+
+		// Only the workers due in the block are handled
+		run_to_block(13);
+		OffchainComputingInfra::on_initialize(13);
+		assert_eq!(Workers::<Test>::get(ALICE_WORKER).unwrap().status, WorkerStatus::Online);
+		assert_eq!(Workers::<Test>::get(BOB_WORKER).unwrap().status, WorkerStatus::Unresponsive);
+		assert!(!crate::WorkerHeartbeatWindows::<Test>::contains_key(BOB_WORKER));
+		assert_eq!(crate::NextHeartbeatDeadlineToHandle::<Test>::get(), Some(14));
+	});
+}
+
+#[test]
+fn worker_deposit_increase_and_decrease_work() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS);
+
+		run_to_block(1);
+
+		let (impl_id, _impl_spec_version) = mock_impl_and_build(ALICE);
+		register_worker_for(ALICE, ALICE_WORKER, impl_id, 101 * DOLLARS);
+
+		assert_noop!(
+			OffchainComputingInfra::decrease_worker_deposit(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_WORKER,
+				10 * DOLLARS
+			),
+			Error::<Test>::WorkerDepositBelowMinimum
+		);
+		assert_noop!(
+			OffchainComputingInfra::increase_worker_deposit(
+				RuntimeOrigin::signed(BOB),
+				ALICE_WORKER,
+				50 * DOLLARS
+			),
+			Error::<Test>::NotTheOwner
+		);
+
+		assert_ok!(OffchainComputingInfra::increase_worker_deposit(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_WORKER,
+			50 * DOLLARS
+		));
+		assert_eq!(Workers::<Test>::get(ALICE_WORKER).unwrap().deposit, 150 * DOLLARS);
+		assert_eq!(Balances::reserved_balance(ALICE_WORKER), 150 * DOLLARS);
+
+		// The decreased deposit keeps held while unbonding
+		assert_ok!(OffchainComputingInfra::decrease_worker_deposit(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_WORKER,
+			30 * DOLLARS
+		));
+		assert_eq!(
+			last_event(),
+			OffchainComputingInfraEvent::WorkerDepositDecreased {
+				worker: ALICE_WORKER,
+				amount: 30 * DOLLARS,
+				deposit: 120 * DOLLARS,
+				unlock_at: 11,
+			}
+		);
+		assert_eq!(Workers::<Test>::get(ALICE_WORKER).unwrap().deposit, 120 * DOLLARS);
+		assert_eq!(Balances::reserved_balance(ALICE_WORKER), 150 * DOLLARS);
+		assert_noop!(
+			OffchainComputingInfra::withdraw_unbonded_worker_deposit(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_WORKER
+			),
+			Error::<Test>::WorkerDepositStillUnbonding
+		);
+
+		run_to_block(11);
+
+		let owner_balance = Balances::free_balance(ALICE);
+		assert_ok!(OffchainComputingInfra::withdraw_unbonded_worker_deposit(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_WORKER
+		));
+		assert_eq!(Balances::reserved_balance(ALICE_WORKER), 120 * DOLLARS);
+		assert_eq!(Balances::free_balance(ALICE), owner_balance + 30 * DOLLARS);
+		assert_noop!(
+			OffchainComputingInfra::withdraw_unbonded_worker_deposit(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_WORKER
+			),
+			Error::<Test>::NoUnbondingWorkerDeposit
+		);
+	});
+}
+
+#[test]
+fn worker_force_deregister_and_reap_work() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS);
+		set_balance(BOB, 10 * DOLLARS);
+
+		run_to_block(1);
+
+		let (impl_id, _impl_spec_version) = mock_impl_and_build(ALICE);
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(ALICE),
+			impl_id,
+			1,
+			None,
+		));
+		register_worker_for(ALICE, ALICE_WORKER, impl_id, 101 * DOLLARS);
+		register_worker_for(ALICE, BOB_WORKER, impl_id, 101 * DOLLARS);
+		assert_ok!(OffchainComputingInfra::online(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			online_payload(impl_id, 1),
+			Attestation::OptOut,
+		));
+
+		// Governance could deregister an online worker
+		assert_noop!(
+			OffchainComputingInfra::force_deregister_worker(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_WORKER
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let owner_balance = Balances::free_balance(ALICE);
+		assert_ok!(OffchainComputingInfra::force_deregister_worker(
+			RuntimeOrigin::root(),
+			ALICE_WORKER
+		));
+		assert_eq!(
+			last_event(),
+			OffchainComputingInfraEvent::WorkerDeregistered { worker: ALICE_WORKER, force: true }
+		);
+		assert!(Workers::<Test>::get(ALICE_WORKER).is_none());
+		assert_eq!(crate::ImplBuilds::<Test>::get(impl_id, 1).unwrap().workers_count, 0);
+		assert_eq!(Balances::free_balance(ALICE), owner_balance + 101 * DOLLARS);
+
+		// Anyone could reap the worker after it has been inactive long enough
+		assert_noop!(
+			OffchainComputingInfra::reap_worker(RuntimeOrigin::signed(BOB), BOB_WORKER),
+			Error::<Test>::WorkerNotReapable
+		);
+
+		run_to_block(21);
+
+		let owner_balance = Balances::free_balance(ALICE);
+		assert_ok!(OffchainComputingInfra::reap_worker(RuntimeOrigin::signed(BOB), BOB_WORKER));
+		assert_eq!(
+			last_event(),
+			OffchainComputingInfraEvent::WorkerDeregistered { worker: BOB_WORKER, force: true }
+		);
+		assert_eq!(Balances::free_balance(BOB), 11 * DOLLARS);
+		assert_eq!(Balances::free_balance(ALICE), owner_balance + 100 * DOLLARS);
+		assert!(!crate::WorkerInactiveSince::<Test>::contains_key(BOB_WORKER));
+		assert_eq!(crate::Impls::<Test>::get(impl_id).unwrap().workers_count, 0);
+	});
+}
+
+#[test]
+fn try_state_detects_broken_invariants() {
+	new_test_ext().execute_with(|| {
+		set_balance(ALICE, 201 * DOLLARS);
+
+		let (impl_id, _impl_build_version) = mock_impl_and_build(ALICE);
+		register_worker_for(ALICE, ALICE_WORKER, impl_id, 101 * DOLLARS);
+		assert_ok!(OffchainComputingInfra::do_try_state());
+
+		crate::Impls::<Test>::mutate(impl_id, |impl_info| {
+			impl_info.as_mut().unwrap().workers_count = 2;
+		});
+		assert!(OffchainComputingInfra::do_try_state().is_err());
+
+		crate::Impls::<Test>::mutate(impl_id, |impl_info| {
+			let impl_info = impl_info.as_mut().unwrap();
+			impl_info.workers_count = 1;
+			impl_info.owner_deposit = 2 * DOLLARS;
+		});
+		assert!(OffchainComputingInfra::do_try_state().is_err());
+	});
+}
+
+fn mock_v1_worker(owner: AccountId, worker: AccountId, status: WorkerStatus) -> WorkerInfo {
+	WorkerInfo {
+		account: worker,
+		owner,
+		deposit: 100 * DOLLARS,
+		status,
+		impl_id: 101,
+		impl_spec_version: Some(1),
+		impl_build_version: Some(1),
+		attestation_method: Some(AttestationMethod::OptOut),
+		attestation_expires_at: None,
+		attested_at: None,
+		last_sent_heartbeat_at: None,
+		uptime_started_at: None,
+		uptime: None,
+	}
+}
+
+#[test]
+fn migrate_v1_to_v2_works() {
+	#[cfg(feature = "try-runtime")]
+	use crate::migrations::v2::VersionUncheckedMigrateV1ToV2;
+	use crate::{
+		migrations::v2::{v1, MigrateV1ToV2},
+		Impls, WorkerHeartbeatWindows, WorkerInactiveSince,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		run_to_block(5);
+
+		StorageVersion::new(1).put::<OffchainComputingInfra>();
+		v1::Impls::<Test>::insert(
+			101,
+			v1::ImplInfo {
+				id: 101,
+				owner: ALICE,
+				owner_deposit: DOLLARS,
+				attestation_method: AttestationMethod::OptOut,
+				workers_count: 2,
+			},
+		);
+		let online_worker = mock_v1_worker(ALICE, ALICE_WORKER, WorkerStatus::Online);
+		Workers::<Test>::insert(ALICE_WORKER, online_worker);
+		let offline_worker = mock_v1_worker(BOB, BOB_WORKER, WorkerStatus::Offline);
+		Workers::<Test>::insert(BOB_WORKER, offline_worker);
+		v1::FlipSet::<Test>::insert(ALICE_WORKER, 3);
+		v1::FlipOrFlop::<Test>::put(v1::FlipFlopStage::Flip);
+		v1::CurrentFlipFlopStartedAt::<Test>::put(3);
+
+		#[cfg(feature = "try-runtime")]
+		let state =
+			VersionUncheckedMigrateV1ToV2::<Test>::pre_upgrade().expect("pre-upgrade should pass");
+
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(VersionUncheckedMigrateV1ToV2::<Test>::post_upgrade(state));
+
+		assert_eq!(OffchainComputingInfra::on_chain_storage_version(), 2);
+
+		let impl_info = Impls::<Test>::get(101).unwrap();
+		assert_eq!(impl_info.workers_count, 2);
+		assert_eq!(impl_info.worker_deposit_floor, 100 * DOLLARS);
+		assert_eq!(impl_info.heartbeat_period_class, HeartbeatPeriodClass::Standard);
+
+		let window = WorkerHeartbeatWindows::<Test>::get(ALICE_WORKER).unwrap();
+		assert!(window.next_heartbeat > 5 && window.next_heartbeat < window.deadline);
+		assert_eq!(WorkerInactiveSince::<Test>::get(ALICE_WORKER), None);
+		assert_eq!(WorkerHeartbeatWindows::<Test>::get(BOB_WORKER), None);
+		assert_eq!(WorkerInactiveSince::<Test>::get(BOB_WORKER), Some(5));
+
+		assert_eq!(v1::FlipSet::<Test>::count(), 0);
+		assert_eq!(v1::FlipOrFlop::<Test>::exists(), false);
+		assert_eq!(v1::CurrentFlipFlopStartedAt::<Test>::exists(), false);
+
+		// The migration only runs once
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(OffchainComputingInfra::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn genesis_config_works() {
+	use crate::{GenesisConfig, ImplBuilds, ImplMetadata, Impls, NextImplId};
+	use frame_support::traits::BuildGenesisConfig;
+
+	build_and_execute(|| {
+		set_balance(ALICE, 1000 * DOLLARS);
+		set_balance(BOB, 1000 * DOLLARS);
+		set_balance(ALICE_WORKER, 1000 * DOLLARS);
+		set_balance(BOB_WORKER, 1000 * DOLLARS);
+
+		GenesisConfig::<Test> {
+			impls: vec![(200, ALICE, AttestationMethod::OptOut)],
+			impl_metadata: vec![(200, b"metadata".to_vec())],
+			impl_builds: vec![(200, 1, None), (200, 2, Some(b"magic".to_vec()))],
+			workers: vec![(ALICE, ALICE_WORKER, 200), (BOB, BOB_WORKER, 200)],
+		}
+		.build();
+
+		let impl_info = Impls::<Test>::get(200).unwrap();
+		assert_eq!(impl_info.owner, ALICE);
+		assert_eq!(impl_info.workers_count, 2);
+		assert_eq!(ImplMetadata::<Test>::get(200).unwrap().data.to_vec(), b"metadata".to_vec());
+		assert_eq!(NextImplId::<Test>::get(), Some(201));
+
+		assert_eq!(ImplBuilds::<Test>::get(200, 1).unwrap().status, ImplBuildStatus::Released);
+		assert_eq!(
+			ImplBuilds::<Test>::get(200, 2).unwrap().magic_bytes.unwrap().to_vec(),
+			b"magic".to_vec()
+		);
+
+		let worker_info = Workers::<Test>::get(ALICE_WORKER).unwrap();
+		assert_eq!(worker_info.status, WorkerStatus::Registered);
+		assert_eq!(Balances::reserved_balance(ALICE_WORKER), worker_info.deposit);
+		assert_eq!(Workers::<Test>::get(BOB_WORKER).unwrap().owner, BOB);
+
+		// Implementations registered later won't collide with the genesis ones
+		run_to_block(1);
+		let (impl_id, _) = mock_impl_and_build(ALICE);
+		assert_eq!(impl_id, 201);
+	});
+}
@@ -32,7 +32,11 @@ pub trait OffchainWorkerLifecycleHooks<AccountId, ImplId> {
 
 	/// A hook after the worker transited to online status,
 	/// can use for add additional business logic, e.g. assign job, reserve more money
-	fn after_online(worker: &AccountId);
+	fn after_online(
+		worker: &AccountId,
+		payload: &OnlinePayload<ImplId>,
+		verified_attestation: &VerifiedAttestation,
+	);
 
 	/// A hook for checking the worker whether can offline,
 	/// can use for add extra conditions check,
@@ -47,7 +51,7 @@ pub trait OffchainWorkerLifecycleHooks<AccountId, ImplId> {
 	/// can use for add additional business logic, e.g. un-reserve money
 	fn before_offline(worker: &AccountId, reason: OfflineReason);
 
-	/// A hook after the worker update its attestation,
+	/// A hook after the worker update its attestation or upgrade its build,
 	/// Can use for if interest in payload's custom field
 	fn after_refresh_attestation(
 		worker: &AccountId,
@@ -55,6 +59,20 @@ pub trait OffchainWorkerLifecycleHooks<AccountId, ImplId> {
 		verified_attestation: &VerifiedAttestation,
 	);
 
+	/// A hook for checking the online worker whether can upgrade to the payload's build,
+	/// can use for add extra conditions check (e.g. the spec version still acceptable),
+	/// if returns error, the worker will keep running the current build
+	fn can_upgrade_build(
+		worker: &AccountId,
+		payload: &OnlinePayload<ImplId>,
+		verified_attestation: &VerifiedAttestation,
+	) -> DispatchResult;
+
+	/// A hook for checking the worker whether can decrease its deposit, it's called after the
+	/// worker's deposit updated, can use for add extra conditions check (e.g. the minimum
+	/// deposit required by the downstream), if returns error, the deposit will not be decreased
+	fn can_decrease_deposit(worker: &AccountId) -> DispatchResult;
+
 	/// A hook after the worker transited to requesting offline status,
 	/// can use for add additional business logic, e.g. stop assigning job
 	fn after_requesting_offline(worker: &AccountId);
@@ -77,7 +95,7 @@ impl<AccountId, ImplId> OffchainWorkerLifecycleHooks<AccountId, ImplId> for () {
 		Ok(())
 	}
 
-	fn after_online(_: &AccountId) {
+	fn after_online(_: &AccountId, _: &OnlinePayload<ImplId>, _: &VerifiedAttestation) {
 		// Do nothing
 	}
 
@@ -101,6 +119,18 @@ impl<AccountId, ImplId> OffchainWorkerLifecycleHooks<AccountId, ImplId> for () {
 		// Do nothing
 	}
 
+	fn can_upgrade_build(
+		_: &AccountId,
+		_: &OnlinePayload<ImplId>,
+		_: &VerifiedAttestation,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn can_decrease_deposit(_: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
 	fn after_requesting_offline(_: &AccountId) {
 		// Do nothing
 	}
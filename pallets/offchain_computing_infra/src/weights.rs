@@ -23,6 +23,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! CPU: `<UNKNOWN>`
 //! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The weights of the extrinsics added or changed since the run above are hand-written
+//! estimates following their storage accesses, regenerate this file by running the command below.
 
 // Executed Command:
 //    ./target/production/cybros-node
@@ -55,62 +58,94 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
     fn register_worker() -> Weight;
     fn deregister_worker() -> Weight;
+    fn force_deregister_worker() -> Weight;
+    fn reap_worker() -> Weight;
     fn transfer_to_worker() -> Weight;
     fn withdraw_from_worker() -> Weight;
+    fn increase_worker_deposit() -> Weight;
+    fn decrease_worker_deposit() -> Weight;
+    fn withdraw_unbonded_worker_deposit() -> Weight;
     fn online() -> Weight;
     fn refresh_attestation() -> Weight;
+    fn upgrade_build() -> Weight;
     fn request_offline() -> Weight;
     fn request_offline_for() -> Weight;
     fn force_offline() -> Weight;
     fn force_offline_for() -> Weight;
     fn heartbeat() -> Weight;
+    fn handle_unresponsive_workers(w: u32, ) -> Weight;
+    fn register_impl() -> Weight;
+    fn deregister_impl(b: u32, ) -> Weight;
+    fn update_impl_metadata(m: u32, ) -> Weight;
+    fn register_impl_build() -> Weight;
+    fn deregister_impl_build() -> Weight;
+    fn update_impl_build_status() -> Weight;
+    fn start_impl_build_canary() -> Weight;
+    fn promote_impl_build() -> Weight;
+    fn update_impl_worker_requirements() -> Weight;
+    fn add_impl_build_publisher() -> Weight;
+    fn remove_impl_build_publisher() -> Weight;
+    fn set_accept_impl_ownership() -> Weight;
+    fn transfer_impl_ownership() -> Weight;
 }
 
 /// Weights for pallet_offchain_computing_infra using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: `System::Account` (r:1 w:1)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Impls` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForWorkers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
-    /// Proof: `OffchainComputingInfra::AccountOwningWorkers` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
     fn register_worker() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `286`
-        //   Estimated: `3655`
-        // Minimum execution time: 44_000_000 picoseconds.
         Weight::from_parts(46_000_000, 3655)
             .saturating_add(T::DbWeight::get().reads(5_u64))
             .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:1 w:1)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Impls` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForWorkers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
-    /// Proof: `OffchainComputingInfra::AccountOwningWorkers` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
     fn deregister_worker() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `652`
-        //   Estimated: `3655`
-        // Minimum execution time: 44_000_000 picoseconds.
         Weight::from_parts(48_000_000, 3655)
             .saturating_add(T::DbWeight::get().reads(5_u64))
             .saturating_add(T::DbWeight::get().writes(6_u64))
     }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
+    fn force_deregister_worker() -> Weight {
+        Weight::from_parts(68_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(13_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    /// Storage: `System::Account` (r:3 w:3)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
+    fn reap_worker() -> Weight {
+        Weight::from_parts(71_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
     /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:1 w:1)
@@ -138,31 +173,44 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn increase_worker_deposit() -> Weight {
+        Weight::from_parts(45_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    fn decrease_worker_deposit() -> Weight {
+        Weight::from_parts(21_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    fn withdraw_unbonded_worker_deposit() -> Weight {
+        Weight::from_parts(47_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
     /// Storage: `Balances::Holds` (r:1 w:0)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
-    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     /// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-    /// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipOrFlop` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::FlipOrFlop` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:0 w:1)
     fn online() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `543`
-        //   Estimated: `4079`
-        // Minimum execution time: 16_000_000 picoseconds.
-        Weight::from_parts(18_000_000, 4079)
-            .saturating_add(T::DbWeight::get().reads(9_u64))
-            .saturating_add(T::DbWeight::get().writes(4_u64))
+        Weight::from_parts(19_000_000, 4079)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
     /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
@@ -178,166 +226,274 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:2 w:2)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::WorkerDeclaredCapabilities` (r:0 w:1)
+    fn upgrade_build() -> Weight {
+        Weight::from_parts(18_000_000, 6246)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn request_offline() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 16_000_000 picoseconds.
         Weight::from_parts(17_000_000, 3655)
-            .saturating_add(T::DbWeight::get().reads(6_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn request_offline_for() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 16_000_000 picoseconds.
         Weight::from_parts(17_000_000, 3655)
-            .saturating_add(T::DbWeight::get().reads(6_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn force_offline() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 15_000_000 picoseconds.
         Weight::from_parts(16_000_000, 3655)
-            .saturating_add(T::DbWeight::get().reads(6_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn force_offline_for() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 16_000_000 picoseconds.
         Weight::from_parts(17_000_000, 3655)
-            .saturating_add(T::DbWeight::get().reads(6_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
-    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
     /// Storage: `Balances::Holds` (r:1 w:0)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
     /// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-    /// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipOrFlop` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::FlipOrFlop` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlipSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:2)
     fn heartbeat() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `755`
-        //   Estimated: `4079`
-        // Minimum execution time: 21_000_000 picoseconds.
-        Weight::from_parts(23_000_000, 4079)
-            .saturating_add(T::DbWeight::get().reads(11_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+        Weight::from_parts(22_000_000, 4079)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::NextHeartbeatDeadlineToHandle` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:101 w:100)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:100 w:100)
+    /// Storage: `OffchainComputingInfra::Workers` (r:100 w:100)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:0 w:100)
+    /// The range of component `w` is `[0, 100]`.
+    fn handle_unresponsive_workers(w: u32, ) -> Weight {
+        Weight::from_parts(7_000_000, 3525)
+            .saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(w.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(w.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(w.into())))
+            .saturating_add(Weight::from_parts(0, 2665).saturating_mul(w.into()))
+    }
+    /// Storage: `OffchainComputingInfra::NextImplId` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImpls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningImpls` (r:0 w:1)
+    fn register_impl() -> Weight {
+        Weight::from_parts(36_000_000, 3604)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:0 w:8)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:8)
+    /// Storage: `OffchainComputingInfra::ImplBuildHealths` (r:0 w:8)
+    /// Storage: `OffchainComputingInfra::CounterForImplBuilds` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImpls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningImpls` (r:0 w:1)
+    /// The range of component `b` is `[1, 8]`.
+    fn deregister_impl(b: u32, ) -> Weight {
+        Weight::from_parts(52_000_000, 5599)
+            .saturating_add(Weight::from_parts(1_900_000, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `m` is `[1, 2048]`.
+    fn update_impl_metadata(m: u32, ) -> Weight {
+        Weight::from_parts(39_000_000, 5599)
+            .saturating_add(Weight::from_parts(2_100, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImplBuilds` (r:1 w:1)
+    fn register_impl_build() -> Weight {
+        Weight::from_parts(14_000_000, 3581)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildHealths` (r:0 w:1)
+    fn deregister_impl_build() -> Weight {
+        Weight::from_parts(17_000_000, 3581)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    fn update_impl_build_status() -> Weight {
+        Weight::from_parts(16_000_000, 3742)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    fn start_impl_build_canary() -> Weight {
+        Weight::from_parts(19_000_000, 3742)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    fn promote_impl_build() -> Weight {
+        Weight::from_parts(15_000_000, 3581)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    fn update_impl_worker_requirements() -> Weight {
+        Weight::from_parts(11_000_000, 3559)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:1)
+    fn add_impl_build_publisher() -> Weight {
+        Weight::from_parts(14_000_000, 3742)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:1)
+    fn remove_impl_build_publisher() -> Weight {
+        Weight::from_parts(14_000_000, 3742)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingInfra::ImplOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn set_accept_impl_ownership() -> Weight {
+        Weight::from_parts(12_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::ImplOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `OffchainComputingInfra::ImplMetadata` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningImpls` (r:0 w:2)
+    fn transfer_impl_ownership() -> Weight {
+        Weight::from_parts(86_000_000, 5599)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
     }
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
     /// Storage: `System::Account` (r:1 w:1)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Impls` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForWorkers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
-    /// Proof: `OffchainComputingInfra::AccountOwningWorkers` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
     fn register_worker() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `286`
-        //   Estimated: `3655`
-        // Minimum execution time: 44_000_000 picoseconds.
         Weight::from_parts(46_000_000, 3655)
             .saturating_add(RocksDbWeight::get().reads(5_u64))
             .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:1 w:1)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Impls` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForWorkers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
-    /// Proof: `OffchainComputingInfra::AccountOwningWorkers` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
     fn deregister_worker() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `652`
-        //   Estimated: `3655`
-        // Minimum execution time: 44_000_000 picoseconds.
         Weight::from_parts(48_000_000, 3655)
             .saturating_add(RocksDbWeight::get().reads(5_u64))
             .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
+    fn force_deregister_worker() -> Weight {
+        Weight::from_parts(68_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(13_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    /// Storage: `System::Account` (r:3 w:3)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningWorkers` (r:0 w:1)
+    fn reap_worker() -> Weight {
+        Weight::from_parts(71_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
     /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:1 w:1)
@@ -365,31 +521,44 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn increase_worker_deposit() -> Weight {
+        Weight::from_parts(45_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    fn decrease_worker_deposit() -> Weight {
+        Weight::from_parts(21_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerDepositUnbondings` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
+    fn withdraw_unbonded_worker_deposit() -> Weight {
+        Weight::from_parts(47_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
     /// Storage: `Balances::Holds` (r:1 w:0)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
-    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     /// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-    /// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipOrFlop` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::FlipOrFlop` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:0 w:1)
     fn online() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `543`
-        //   Estimated: `4079`
-        // Minimum execution time: 16_000_000 picoseconds.
-        Weight::from_parts(18_000_000, 4079)
-            .saturating_add(RocksDbWeight::get().reads(9_u64))
-            .saturating_add(RocksDbWeight::get().writes(4_u64))
+        Weight::from_parts(19_000_000, 4079)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
     /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
@@ -405,118 +574,214 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:2 w:2)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::WorkerDeclaredCapabilities` (r:0 w:1)
+    fn upgrade_build() -> Weight {
+        Weight::from_parts(18_000_000, 6246)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn request_offline() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 16_000_000 picoseconds.
         Weight::from_parts(17_000_000, 3655)
-            .saturating_add(RocksDbWeight::get().reads(6_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn request_offline_for() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 16_000_000 picoseconds.
         Weight::from_parts(17_000_000, 3655)
-            .saturating_add(RocksDbWeight::get().reads(6_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn force_offline() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 15_000_000 picoseconds.
         Weight::from_parts(16_000_000, 3655)
-            .saturating_add(RocksDbWeight::get().reads(6_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:0)
-    /// Proof: `OffchainComputingPool::CounterForWorkerAssignedJobs` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerEncryptionKeys` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:1 w:1)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
     fn force_offline_for() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `573`
-        //   Estimated: `3655`
-        // Minimum execution time: 16_000_000 picoseconds.
         Weight::from_parts(17_000_000, 3655)
-            .saturating_add(RocksDbWeight::get().reads(6_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     /// Storage: `OffchainComputingInfra::Workers` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::Workers` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
-    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
     /// Storage: `Balances::Holds` (r:1 w:0)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::ImplBuilds` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::CurrentFlipFlopStartedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:1 w:1)
     /// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-    /// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipOrFlop` (r:1 w:0)
-    /// Proof: `OffchainComputingInfra::FlipOrFlop` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlopSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlopSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlopSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::FlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::FlipSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-    /// Storage: `OffchainComputingInfra::CounterForFlipSet` (r:1 w:1)
-    /// Proof: `OffchainComputingInfra::CounterForFlipSet` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:0 w:2)
     fn heartbeat() -> Weight {
-        // Proof Size summary in bytes:
-        //   Measured:  `755`
-        //   Estimated: `4079`
-        // Minimum execution time: 21_000_000 picoseconds.
-        Weight::from_parts(23_000_000, 4079)
-            .saturating_add(RocksDbWeight::get().reads(11_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+        Weight::from_parts(22_000_000, 4079)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::NextHeartbeatDeadlineToHandle` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::HeartbeatDeadlines` (r:101 w:100)
+    /// Storage: `OffchainComputingInfra::WorkerHeartbeatWindows` (r:100 w:100)
+    /// Storage: `OffchainComputingInfra::Workers` (r:100 w:100)
+    /// Storage: `OffchainComputingInfra::WorkerInactiveSince` (r:0 w:100)
+    /// The range of component `w` is `[0, 100]`.
+    fn handle_unresponsive_workers(w: u32, ) -> Weight {
+        Weight::from_parts(7_000_000, 3525)
+            .saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(w.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(w.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(w.into())))
+            .saturating_add(Weight::from_parts(0, 2665).saturating_mul(w.into()))
+    }
+    /// Storage: `OffchainComputingInfra::NextImplId` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImpls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningImpls` (r:0 w:1)
+    fn register_impl() -> Weight {
+        Weight::from_parts(36_000_000, 3604)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:0 w:8)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:8)
+    /// Storage: `OffchainComputingInfra::ImplBuildHealths` (r:0 w:8)
+    /// Storage: `OffchainComputingInfra::CounterForImplBuilds` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImpls` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningImpls` (r:0 w:1)
+    /// The range of component `b` is `[1, 8]`.
+    fn deregister_impl(b: u32, ) -> Weight {
+        Weight::from_parts(52_000_000, 5599)
+            .saturating_add(Weight::from_parts(1_900_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `m` is `[1, 2048]`.
+    fn update_impl_metadata(m: u32, ) -> Weight {
+        Weight::from_parts(39_000_000, 5599)
+            .saturating_add(Weight::from_parts(2_100, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImplBuilds` (r:1 w:1)
+    fn register_impl_build() -> Weight {
+        Weight::from_parts(14_000_000, 3581)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::CounterForImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildHealths` (r:0 w:1)
+    fn deregister_impl_build() -> Weight {
+        Weight::from_parts(17_000_000, 3581)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    fn update_impl_build_status() -> Weight {
+        Weight::from_parts(16_000_000, 3742)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    fn start_impl_build_canary() -> Weight {
+        Weight::from_parts(19_000_000, 3742)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuilds` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::ImplBuildCanaryRollouts` (r:0 w:1)
+    fn promote_impl_build() -> Weight {
+        Weight::from_parts(15_000_000, 3581)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    fn update_impl_worker_requirements() -> Weight {
+        Weight::from_parts(11_000_000, 3559)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:1)
+    fn add_impl_build_publisher() -> Weight {
+        Weight::from_parts(14_000_000, 3742)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::ImplBuildPublishers` (r:1 w:1)
+    fn remove_impl_build_publisher() -> Weight {
+        Weight::from_parts(14_000_000, 3742)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingInfra::ImplOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn set_accept_impl_ownership() -> Weight {
+        Weight::from_parts(12_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingInfra::ImplOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `OffchainComputingInfra::ImplMetadata` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::AccountOwningImpls` (r:0 w:2)
+    fn transfer_impl_ownership() -> Weight {
+        Weight::from_parts(86_000_000, 5599)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
     }
 }
@@ -0,0 +1,36 @@
+;; Forwards the call data to the chain extension and returns its output.
+;; The first 4 bytes of the call data is the function id, the rest is the input.
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "call_chain_extension"
+		(func $call_chain_extension (param i32 i32 i32 i32 i32) (result i32))
+	)
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) length of the input buffer
+	(data (i32.const 0) "\00\01")
+
+	;; [4, 8) length of the output buffer
+	(data (i32.const 4) "\00\01")
+
+	;; [8, 264) input buffer
+
+	;; [264, 520) output buffer
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_input (i32.const 8) (i32.const 0))
+		(drop
+			(call $call_chain_extension
+				(i32.load (i32.const 8)) ;; func_id
+				(i32.const 12) ;; input_ptr
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4)) ;; input_len
+				(i32.const 264) ;; output_ptr
+				(i32.const 4) ;; output_len_ptr
+			)
+		)
+		(call $seal_return (i32.const 0) (i32.const 264) (i32.load (i32.const 4)))
+	)
+)
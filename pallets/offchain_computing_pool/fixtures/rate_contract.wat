@@ -0,0 +1,29 @@
+;; A rate contract charges 10 DOLLARS when creating a job, and 4 DOLLARS when settling it.
+;; The call data is the selector followed by the encoded `JobFeeStage` and the job's info,
+;; the output is the encoded `Result<u128, LangError>`.
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) length of the input buffer
+	(data (i32.const 0) "\00\01")
+
+	;; [4, 21) `Ok(10 * DOLLARS)`
+	(data (i32.const 4) "\00\00\10\a5\d4\e8\00\00\00\00\00\00\00\00\00\00\00")
+
+	;; [21, 38) `Ok(4 * DOLLARS)`
+	(data (i32.const 21) "\00\00\a0\db\21\5d\00\00\00\00\00\00\00\00\00\00\00")
+
+	;; [38, 294) input buffer
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_input (i32.const 38) (i32.const 0))
+		(if (i32.load8_u (i32.const 42)) ;; the stage follows the 4 bytes selector
+			(then (call $seal_return (i32.const 0) (i32.const 21) (i32.const 17)))
+			(else (call $seal_return (i32.const 0) (i32.const 4) (i32.const 17)))
+		)
+	)
+)
@@ -20,13 +20,34 @@
 
 use scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_core::{bounded::BoundedVec, ConstU32, RuntimeDebug};
+use serde::{Deserialize, Serialize};
+use sp_core::{
+	bounded::{BoundedVec, ConstU32, Get},
+	hashing::blake2_256,
+	RuntimeDebug,
+};
 
 pub use base_primitives::*;
 
 pub type UniqueTrackId = BoundedVec<u8, ConstU32<16>>;
+/// The URI of the data stored off-chain, e.g. IPFS CID, Arweave tx, or HTTPS URL
+pub type DataUri = BoundedVec<u8, ConstU32<256>>;
+/// The blake2-256 hash of the data
+pub type DataHash = [u8; 32];
 
-#[derive(Clone, Decode, Encode, MaxEncodedLen, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
+#[derive(
+	Clone,
+	Decode,
+	Encode,
+	MaxEncodedLen,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	Default,
+	Serialize,
+	Deserialize,
+)]
 pub enum ApplicableScope {
 	/// Only the owner could use the implementations.
 	#[default]
@@ -37,15 +58,51 @@ pub enum ApplicableScope {
 	AllowList,
 }
 
+/// How to charge for jobs
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct JobPolicy<PoolId, BlockNumber> {
+pub enum JobRateStrategy<AccountId> {
+	/// No fee
+	Free,
+	/// The fee is calculated by a whitelisted contract
+	Contract(AccountId),
+}
+
+/// When the rate contract being queried
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum JobFeeStage {
+	/// Creating the job, the fee will be held from the depositor
+	Creating,
+	/// The job processed, the fee will be paid to the worker, it can't exceed the held fee
+	Settling,
+}
+
+/// What the worker gets from the held fee when the depositor cancels a processing job,
+/// the rest will be refunded to the depositor
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum JobCancellationCompensation {
+	/// The worker gets nothing
+	Nothing,
+	/// The worker gets a fixed percent (at most 100) of the fee
+	Percent(u8),
+	/// The worker gets the fee pro rata by the elapsed time of processing to the expiring time
+	ProRata,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct JobPolicy<PoolId, AccountId, BlockNumber> {
 	/// Policy's id
 	pub id: PoolId,
 	/// This policy is available to use
 	pub enabled: bool,
 	/// Who can applicable with the policy
 	pub applicable_scope: ApplicableScope,
-	// TODO：rates strategy
+	/// How to charge for jobs
+	pub rate_strategy: JobRateStrategy<AccountId>,
+	/// What the worker gets when the job cancelled while processing
+	pub cancellation_compensation: JobCancellationCompensation,
+	/// How many times a job could be attempted, the job discarded or panicked will be retried by
+	/// other workers until attempts exhausted
+	pub max_attempts: u32,
 	// TODO: allow create scheduled job and rule
 	/// When the policy starts.
 	pub start_block: Option<BlockNumber>,
@@ -58,7 +115,28 @@ pub struct JobPolicy<PoolId, BlockNumber> {
 // for each job, pay to worker or the owner TODO: WorkerPolicy: How to slashing, max processing
 // duration, and etc.
 
+/// A budget that a sponsor funded for paying deposits of jobs created with a policy.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct JobSponsorship<Balance> {
+	/// The remaining budget, it is held from the sponsor
+	pub budget: Balance,
+	/// The maximum amount that an account could spend from the budget,
+	/// the spending returns when the sponsored jobs destroyed
+	pub allowance_per_account: Balance,
+}
+
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
 pub enum JobScheduler {
 	/// DemoOnly
 	DemoOnly
@@ -72,6 +150,14 @@ pub enum JobDestroyReason {
 	Expired,
 }
 
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PoolStatus {
+	/// The pool is live
+	Live,
+	/// The pool is being destroyed, jobs and workers are removing in batches
+	Destroying,
+}
+
 /// Information about a pool.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct PoolInfo<PoolId, AccountId, Balance, ImplId> {
@@ -100,6 +186,8 @@ pub struct PoolInfo<PoolId, AccountId, Balance, ImplId> {
 	pub jobs_count: u32,
 	/// The total number of outstanding workers of this pool.
 	pub workers_count: u32,
+	/// The pool's status
+	pub status: PoolStatus,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -112,6 +200,8 @@ pub enum JobStatus {
 	Processed,
 	/// Ending status, the worker can't process the job (e.g. force offline)
 	Discarded,
+	/// Ending status, the depositor cancelled the job while processing
+	Cancelled,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -128,6 +218,163 @@ pub enum JobResult {
 	Panic,
 }
 
+/// How the job's input or output encrypted
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EncryptionScheme<AccountId> {
+	/// The data is plain
+	Plain,
+	/// The data is encrypted by NaCl box with the worker's published encryption key,
+	/// only the worker can take the job
+	NaclBoxToWorker(AccountId),
+	/// The data is sealed to the beneficiary of the job, only the beneficiary can decrypt it
+	SealedToBeneficiary,
+}
+
+/// A reference to the data stored off-chain
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DataReference {
+	/// Where to fetch the data
+	pub uri: DataUri,
+	/// The size in bytes of the data
+	pub size: u64,
+	/// The blake2-256 hash of the data, consumers can verify the fetched data with it
+	pub hash: DataHash,
+}
+
+/// The job's input or output, either stored on-chain or referenced to off-chain
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(DataLimit))]
+#[codec(mel_bound())]
+pub enum JobPayload<DataLimit: Get<u32>> {
+	/// The data stored on-chain
+	Inline(BoundedVec<u8, DataLimit>),
+	/// The data stored off-chain
+	Reference(DataReference),
+}
+
+impl<DataLimit: Get<u32>> JobPayload<DataLimit> {
+	/// The length in bytes that stored on-chain, the storage deposit is charged on it
+	pub fn storage_len(&self) -> usize {
+		match self {
+			Self::Inline(data) => data.len(),
+			Self::Reference(reference) => reference.encoded_size(),
+		}
+	}
+
+	/// The blake2-256 hash of the data
+	pub fn hash(&self) -> DataHash {
+		match self {
+			Self::Inline(data) => blake2_256(data),
+			Self::Reference(reference) => reference.hash,
+		}
+	}
+}
+
+// Implement manually to avoid the unnecessary bounds on `DataLimit`
+impl<DataLimit: Get<u32>> Clone for JobPayload<DataLimit> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Inline(data) => Self::Inline(data.clone()),
+			Self::Reference(reference) => Self::Reference(reference.clone()),
+		}
+	}
+}
+
+impl<DataLimit: Get<u32>> PartialEq for JobPayload<DataLimit> {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Inline(a), Self::Inline(b)) => a == b,
+			(Self::Reference(a), Self::Reference(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
+impl<DataLimit: Get<u32>> Eq for JobPayload<DataLimit> {}
+
+impl<DataLimit: Get<u32>> core::fmt::Debug for JobPayload<DataLimit> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Inline(data) => f.debug_tuple("Inline").field(data).finish(),
+			Self::Reference(reference) => f.debug_tuple("Reference").field(reference).finish(),
+		}
+	}
+}
+
+/// The job's input or output stored on-chain
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(DataLimit))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+pub struct JobData<AccountId, Balance, DataLimit: Get<u32>> {
+	/// The depositor of the data
+	pub depositor: AccountId,
+	/// The actual deposit of the data
+	pub actual_deposit: Balance,
+	/// The surplus deposit of the data, it will be returned back when destroying
+	pub surplus_deposit: Balance,
+	/// How the data encrypted
+	pub encryption: EncryptionScheme<AccountId>,
+	/// The data or the reference to the off-chain data
+	pub data: JobPayload<DataLimit>,
+}
+
+/// The contract message to be called when the job processed
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct JobCallback<AccountId, Balance> {
+	/// The contract address
+	pub contract: AccountId,
+	/// The selector of the message
+	pub selector: [u8; 4],
+	/// The `ref_time` part of the gas limit
+	pub ref_time_limit: u64,
+	/// The `proof_size` part of the gas limit
+	pub proof_size_limit: u64,
+	/// The fee of the gas limit prepaid by the depositor, it is held until the callback executed
+	pub prepaid_fee: Balance,
+	/// The storage deposit limit of the call, it is held from the depositor until the callback
+	/// executed
+	pub storage_deposit_limit: Balance,
+}
+
+/// The capabilities that a worker declared when online or refreshing attestation
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct WorkerCapabilitySet {
+	pub capabilities: WorkerCapabilities,
+	/// The capabilities are covered by the worker's attestation
+	pub attested: bool,
+	/// How many jobs the worker could process concurrently
+	pub job_slots: u32,
+}
+
+/// Require the worker has the capability, and its value is at least `min_value`
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CapabilityRequirement {
+	pub key: WorkerCapabilityKey,
+	pub min_value: u64,
+}
+
+/// The requirements that the worker must meet to take the job
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct JobRequirements {
+	pub capabilities: BoundedVec<CapabilityRequirement, ConstU32<8>>,
+	/// Only accept capabilities covered by the worker's attestation
+	pub attested_only: bool,
+}
+
+impl JobRequirements {
+	pub fn is_satisfied_by(&self, capability_set: &WorkerCapabilitySet) -> bool {
+		if self.attested_only && !capability_set.attested {
+			return false
+		}
+
+		self.capabilities.iter().all(|requirement| {
+			capability_set.capabilities.iter().any(|capability| {
+				capability.key == requirement.key && capability.value >= requirement.min_value
+			})
+		})
+	}
+}
+
 // TODO: Idea: JobType: info will copy to Job, advanceable, creatable, minimum_deposit (more than
 // actual will save to surplus_deposit)
 
@@ -153,4 +400,10 @@ pub struct JobInfo<JobId, PolicyId, AccountId, Balance> {
 	pub assigned_at: Option<u64>,
 	pub processing_at: Option<u64>,
 	pub ended_at: Option<u64>,
+	/// How many attempts failed, e.g. discarded or panicked
+	pub attempts: u32,
+	/// The fee held from the depositor, it will be settled when the job processed
+	pub fee: Balance,
+	/// The contract to be called when the job processed
+	pub callback: Option<JobCallback<AccountId, Balance>>,
 }
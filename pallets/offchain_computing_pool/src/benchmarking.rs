@@ -0,0 +1,1215 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for pallet-offchain_computing_pool
+
+// Only enable this module for benchmarking.
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, impl_benchmark_test_suite, v2::*, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{fungible::Mutate, EnsureOrigin, Get},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_contracts::{Code, CollectEvents, DebugInfo};
+use sp_runtime::{
+	app_crypto::{sr25519, KeyTypeId, RuntimePublic},
+	traits::{IdentifyAccount, Zero},
+	AccountId32, MultiSignature, MultiSigner, SaturatedConversion, Saturating,
+};
+use sp_std::prelude::*;
+
+use super::*;
+use crate::Pallet as OffchainComputingPool;
+
+const DOLLARS: u128 = 1_000_000_000_000;
+const PRE_SIGNED_JOB_KEY_TYPE: KeyTypeId = KeyTypeId(*b"psjb");
+const CONTRACT_GAS_LIMIT: Weight = Weight::from_parts(100_000_000_000, 3 * 1024 * 1024);
+
+/// A contract does nothing, it's used as the callback or the rate contract.
+///
+/// `(module (import "env" "memory" (memory 1 1)) (func (export "deploy")) (func (export "call")))`
+const DUMMY_CONTRACT: &[u8] = &[
+	0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic and version
+	0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+	0x02, 0x10, 0x01, 0x03, 0x65, 0x6e, 0x76, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x01,
+	0x01, 0x01, // import section
+	0x03, 0x03, 0x02, 0x00, 0x00, // function section
+	0x07, 0x11, 0x02, 0x06, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x00, 0x00, 0x04, 0x63, 0x61, 0x6c,
+	0x6c, 0x00, 0x01, // export section
+	0x0a, 0x07, 0x02, 0x02, 0x00, 0x0b, 0x02, 0x00, 0x0b, // code section
+];
+
+/// Helper to create the signer of pre-signed jobs in benchmarks.
+pub trait BenchmarkHelper<Public, AccountId, Signature> {
+	/// Generate a key pair, returns its public key and the account.
+	fn signer() -> (Public, AccountId);
+	/// Sign the message with the key pair of the public key.
+	fn sign(signer: &Public, message: &[u8]) -> Signature;
+}
+
+impl BenchmarkHelper<MultiSigner, AccountId32, MultiSignature> for () {
+	fn signer() -> (MultiSigner, AccountId32) {
+		let public = sr25519::Public::generate_pair(PRE_SIGNED_JOB_KEY_TYPE, None);
+		let signer = MultiSigner::Sr25519(public);
+		(signer.clone(), signer.into_account())
+	}
+
+	fn sign(signer: &MultiSigner, message: &[u8]) -> MultiSignature {
+		let MultiSigner::Sr25519(public) = signer else { panic!("The signer should be sr25519") };
+		let signature = public
+			.sign(PRE_SIGNED_JOB_KEY_TYPE, &message)
+			.expect("The key pair should exist");
+		MultiSignature::Sr25519(signature)
+	}
+}
+
+/// Fund the account in both the pool's and the infra's currency, they could be different.
+fn fund_account<T: Config>(who: &T::AccountId) {
+	<T as Config>::Currency::set_balance(
+		who,
+		(1_000_000 * DOLLARS).saturated_into::<BalanceOf<T>>(),
+	);
+	<T as pallet_offchain_computing_infra::Config>::Currency::set_balance(
+		who,
+		(1_000_000 * DOLLARS).saturated_into::<pallet_offchain_computing_infra::BalanceOf<T>>(),
+	);
+}
+
+fn add_mock_impl<T: Config>(owner: &T::AccountId) -> T::ImplId {
+	fund_account::<T>(owner);
+
+	let impl_id = pallet_offchain_computing_infra::NextImplId::<T>::get().unwrap_or(101u32.into());
+	assert_ok!(PalletInfra::<T>::register_impl(
+		RawOrigin::Signed(owner.clone()).into(),
+		AttestationMethod::OptOut,
+	));
+	assert_ok!(PalletInfra::<T>::register_impl_build(
+		RawOrigin::Signed(owner.clone()).into(),
+		impl_id.clone(),
+		1u32,
+		None
+	));
+
+	impl_id
+}
+
+fn add_mock_pool<T: Config>(owner: &T::AccountId) -> T::PoolId {
+	let impl_id = add_mock_impl::<T>(owner);
+
+	let pool_id = NextPoolId::<T>::get().unwrap_or(101u32.into());
+	assert_ok!(OffchainComputingPool::<T>::create_pool(
+		RawOrigin::Signed(owner.clone()).into(),
+		impl_id,
+		JobScheduler::DemoOnly,
+		true,
+		false
+	));
+
+	pool_id
+}
+
+fn add_mock_policy<T: Config>(
+	owner: &T::AccountId,
+	pool_id: &T::PoolId,
+	applicable_scope: ApplicableScope,
+) -> T::PolicyId {
+	let policy_id = NextJobPolicyId::<T>::get(pool_id).unwrap_or(1u32.into());
+	assert_ok!(OffchainComputingPool::<T>::create_job_policy(
+		RawOrigin::Signed(owner.clone()).into(),
+		pool_id.clone(),
+		applicable_scope,
+		None,
+		None
+	));
+
+	policy_id
+}
+
+fn set_mock_pool_metadata<T: Config>(owner: &T::AccountId, pool_id: &T::PoolId) {
+	let metadata: BoundedVec<u8, T::PoolMetadataLimit> =
+		vec![0u8; T::PoolMetadataLimit::get() as usize].try_into().unwrap();
+	assert_ok!(OffchainComputingPool::<T>::update_pool_metadata(
+		RawOrigin::Signed(owner.clone()).into(),
+		pool_id.clone(),
+		Some(metadata)
+	));
+}
+
+fn set_mock_pool_team<T: Config>(owner: &T::AccountId, pool_id: &T::PoolId) {
+	let team = (0..4).map(|i| account::<T::AccountId>("team", i, 0)).collect::<Vec<_>>();
+	assert_ok!(OffchainComputingPool::<T>::set_pool_team(
+		RawOrigin::Signed(owner.clone()).into(),
+		pool_id.clone(),
+		Some(T::Lookup::unlookup(team[0].clone())),
+		Some(T::Lookup::unlookup(team[1].clone())),
+		Some(T::Lookup::unlookup(team[2].clone())),
+		Some(T::Lookup::unlookup(team[3].clone())),
+	));
+}
+
+/// Register a worker of the pool's impl, then online it.
+fn add_mock_worker<T: Config>(
+	owner: &T::AccountId,
+	pool_id: &T::PoolId,
+	index: u32,
+) -> T::AccountId {
+	let impl_id = Pools::<T>::get(pool_id).expect("PoolInfo should has value").impl_id;
+	let worker: T::AccountId = account("worker", index, 0);
+	fund_account::<T>(owner);
+
+	let initial_deposit =
+		<T as pallet_offchain_computing_infra::Config>::RegisterWorkerDeposit::get()
+			.saturating_add((11 * DOLLARS).saturated_into());
+	assert_ok!(PalletInfra::<T>::register_worker(
+		RawOrigin::Signed(owner.clone()).into(),
+		T::Lookup::unlookup(worker.clone()),
+		impl_id.clone(),
+		initial_deposit
+	));
+
+	let payload = OnlinePayload::<T::ImplId> {
+		impl_id,
+		impl_spec_version: 1,
+		impl_build_version: 1,
+		impl_build_magic_bytes: Default::default(),
+		encryption_public_key: None,
+		capabilities: Default::default(),
+		job_slots: None,
+	};
+	assert_ok!(PalletInfra::<T>::online(
+		RawOrigin::Signed(worker.clone()).into(),
+		payload,
+		Attestation::OptOut
+	));
+	fund_account::<T>(&worker);
+
+	worker
+}
+
+fn add_mock_subscribed_worker<T: Config>(
+	owner: &T::AccountId,
+	pool_id: &T::PoolId,
+	index: u32,
+) -> T::AccountId {
+	let worker = add_mock_worker::<T>(owner, pool_id, index);
+	assert_ok!(OffchainComputingPool::<T>::authorize_worker(
+		RawOrigin::Signed(owner.clone()).into(),
+		pool_id.clone(),
+		T::Lookup::unlookup(worker.clone())
+	));
+	assert_ok!(OffchainComputingPool::<T>::subscribe_pool(
+		RawOrigin::Signed(worker.clone()).into(),
+		pool_id.clone()
+	));
+
+	worker
+}
+
+fn mock_payload<DataLimit: Get<u32>>(len: u32) -> JobPayload<DataLimit> {
+	JobPayload::Inline(vec![0u8; len as usize].try_into().unwrap())
+}
+
+fn mock_unique_track_id() -> UniqueTrackId {
+	vec![0u8; 16].try_into().unwrap()
+}
+
+fn mock_requirements() -> JobRequirements {
+	let capabilities = (0..8u8)
+		.map(|i| CapabilityRequirement { key: vec![i; 32].try_into().unwrap(), min_value: 1 })
+		.collect::<Vec<_>>();
+
+	JobRequirements { capabilities: capabilities.try_into().unwrap(), attested_only: true }
+}
+
+fn add_mock_job<T: Config>(
+	depositor: &T::AccountId,
+	pool_id: &T::PoolId,
+	policy_id: &T::PolicyId,
+	input_len: u32,
+) -> T::JobId {
+	let job_id = NextJobId::<T>::get(pool_id).unwrap_or(1u32.into());
+	assert_ok!(OffchainComputingPool::<T>::create_job(
+		RawOrigin::Signed(depositor.clone()).into(),
+		pool_id.clone(),
+		policy_id.clone(),
+		None,
+		None,
+		1,
+		Some(mock_payload::<T::InputLimit>(input_len)),
+		EncryptionScheme::Plain,
+		None,
+		None,
+		None
+	));
+
+	job_id
+}
+
+/// Create a job with the largest input and a callback to the dummy contract.
+fn add_mock_job_with_callback<T: Config>(
+	depositor: &T::AccountId,
+	pool_id: &T::PoolId,
+	policy_id: &T::PolicyId,
+) -> T::JobId {
+	let contract = instantiate_mock_contract::<T>(depositor);
+	let callback = JobCallbackRequest {
+		contract,
+		selector: [0u8; 4],
+		gas_limit: T::MaxJobCallbackGasLimit::get(),
+		storage_deposit_limit: DOLLARS.saturated_into(),
+	};
+
+	let job_id = NextJobId::<T>::get(pool_id).unwrap_or(1u32.into());
+	assert_ok!(OffchainComputingPool::<T>::create_job(
+		RawOrigin::Signed(depositor.clone()).into(),
+		pool_id.clone(),
+		policy_id.clone(),
+		None,
+		None,
+		1,
+		Some(mock_payload::<T::InputLimit>(T::InputLimit::get())),
+		EncryptionScheme::Plain,
+		None,
+		None,
+		Some(callback)
+	));
+
+	job_id
+}
+
+fn take_mock_job<T: Config>(
+	worker: &T::AccountId,
+	pool_id: &T::PoolId,
+	job_id: &T::JobId,
+	processing: bool,
+) {
+	assert_ok!(OffchainComputingPool::<T>::take_job(
+		RawOrigin::Signed(worker.clone()).into(),
+		pool_id.clone(),
+		Some(job_id.clone()),
+		processing,
+		None
+	));
+}
+
+fn instantiate_mock_contract<T: Config>(owner: &T::AccountId) -> T::AccountId {
+	let balance = OffchainComputingPool::<T>::calculate_gas_fee(T::MaxJobCallbackGasLimit::get())
+		.saturating_add((1_000_000 * DOLLARS).saturated_into::<BalanceOf<T>>());
+	<T as Config>::Currency::set_balance(owner, balance);
+
+	pallet_contracts::Pallet::<T>::bare_instantiate(
+		owner.clone(),
+		Zero::zero(),
+		CONTRACT_GAS_LIMIT,
+		None,
+		Code::Upload(DUMMY_CONTRACT.to_vec()),
+		vec![],
+		vec![],
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+	)
+	.result
+	.expect("The contract should be instantiated")
+	.account_id
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_pool() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let impl_id = add_mock_impl::<T>(&owner);
+		let pool_id = NextPoolId::<T>::get().unwrap_or(101u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), impl_id, JobScheduler::DemoOnly, true, false);
+
+		let pool_info = Pools::<T>::get(&pool_id).expect("PoolInfo should has value");
+		assert_eq!(pool_info.owner, owner);
+
+		Ok(())
+	}
+
+	// The pool has metadata, a team and `p` policies to clear
+	#[benchmark]
+	fn destroy_pool(p: Linear<0, { T::MaxPoliciesPerPool::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		set_mock_pool_metadata::<T>(&owner, &pool_id);
+		set_mock_pool_team::<T>(&owner, &pool_id);
+		for _ in 0..p {
+			add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone());
+
+		assert_eq!(Pools::<T>::contains_key(&pool_id), false);
+		assert_eq!(JobPolicies::<T>::iter_key_prefix(&pool_id).next().is_none(), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_pool_metadata(
+		m: Linear<1, { T::PoolMetadataLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let metadata: BoundedVec<u8, T::PoolMetadataLimit> =
+			vec![0u8; m as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), Some(metadata.clone()));
+
+		let metadata_entry = PoolMetadata::<T>::get(&pool_id).expect("Metadata should has value");
+		assert_eq!(metadata_entry.data, metadata);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_pool_settings() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			1,
+			2,
+			JobScheduler::DemoOnly,
+			true,
+			true,
+		);
+
+		let pool_info = Pools::<T>::get(&pool_id).expect("PoolInfo should has value");
+		assert_eq!(pool_info.max_impl_spec_version, 2);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_job_policy() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = NextJobPolicyId::<T>::get(&pool_id).unwrap_or(1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), ApplicableScope::Public, None, None);
+
+		assert_eq!(JobPolicies::<T>::contains_key(&pool_id, &policy_id), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn authorize_account() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::AllowList);
+		let user: T::AccountId = account("user", 0, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			policy_id.clone(),
+			T::Lookup::unlookup(user.clone()),
+		);
+
+		assert_eq!(
+			JobPolicyAuthorizedAccounts::<T>::contains_key((pool_id, policy_id, user)),
+			true
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_account() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::AllowList);
+		let user: T::AccountId = account("user", 0, 0);
+		assert_ok!(OffchainComputingPool::<T>::authorize_account(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone(),
+			policy_id.clone(),
+			T::Lookup::unlookup(user.clone())
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			policy_id.clone(),
+			T::Lookup::unlookup(user.clone()),
+		);
+
+		assert_eq!(
+			JobPolicyAuthorizedAccounts::<T>::contains_key((pool_id, policy_id, user)),
+			false
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn destroy_job_policy() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), policy_id.clone());
+
+		assert_eq!(JobPolicies::<T>::contains_key(&pool_id, &policy_id), false);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_job_policy_enablement() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), policy_id.clone(), false);
+
+		let policy = JobPolicies::<T>::get(&pool_id, &policy_id).expect("Policy should has value");
+		assert_eq!(policy.enabled, false);
+
+		Ok(())
+	}
+
+	// The pool requires a minimum worker deposit
+	#[benchmark]
+	fn authorize_worker() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		PoolMinWorkerDeposits::<T>::insert(
+			&pool_id,
+			<T as pallet_offchain_computing_infra::Config>::RegisterWorkerDeposit::get(),
+		);
+		let worker = add_mock_worker::<T>(&owner, &pool_id, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), T::Lookup::unlookup(worker.clone()));
+
+		assert_eq!(PoolAuthorizedWorkers::<T>::contains_key(&worker, &pool_id), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_worker() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let worker = add_mock_subscribed_worker::<T>(&owner, &pool_id, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), T::Lookup::unlookup(worker.clone()));
+
+		assert_eq!(PoolAuthorizedWorkers::<T>::contains_key(&worker, &pool_id), false);
+		assert_eq!(WorkerSubscribedPools::<T>::contains_key(&worker, &pool_id), false);
+
+		Ok(())
+	}
+
+	// The pool requires a minimum worker deposit
+	#[benchmark]
+	fn subscribe_pool() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		PoolMinWorkerDeposits::<T>::insert(
+			&pool_id,
+			<T as pallet_offchain_computing_infra::Config>::RegisterWorkerDeposit::get(),
+		);
+		let worker = add_mock_worker::<T>(&owner, &pool_id, 0);
+		assert_ok!(OffchainComputingPool::<T>::authorize_worker(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone(),
+			T::Lookup::unlookup(worker.clone())
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(worker.clone()), pool_id.clone());
+
+		assert_eq!(WorkerSubscribedPools::<T>::contains_key(&worker, &pool_id), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unsubscribe_pool() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let worker = add_mock_subscribed_worker::<T>(&owner, &pool_id, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(worker.clone()), pool_id.clone());
+
+		assert_eq!(WorkerSubscribedPools::<T>::contains_key(&worker, &pool_id), false);
+
+		Ok(())
+	}
+
+	// The policy is an allow list, the job has a unique track id, requirements and a callback,
+	// the fee of the rate contract is weighed by `RateContractGasLimit` separately
+	#[benchmark]
+	fn create_job(i: Linear<0, { T::InputLimit::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::AllowList);
+		assert_ok!(OffchainComputingPool::<T>::authorize_account(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone(),
+			policy_id.clone(),
+			T::Lookup::unlookup(owner.clone())
+		));
+		let contract = instantiate_mock_contract::<T>(&owner);
+		let callback = JobCallbackRequest {
+			contract,
+			selector: [0u8; 4],
+			gas_limit: T::MaxJobCallbackGasLimit::get(),
+			storage_deposit_limit: DOLLARS.saturated_into(),
+		};
+		let job_id = NextJobId::<T>::get(&pool_id).unwrap_or(1u32.into());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			policy_id,
+			Some(mock_unique_track_id()),
+			None,
+			1,
+			Some(mock_payload::<T::InputLimit>(i)),
+			EncryptionScheme::Plain,
+			None,
+			Some(mock_requirements()),
+			Some(callback),
+		);
+
+		assert_eq!(Jobs::<T>::contains_key(&pool_id, &job_id), true);
+		assert_eq!(JobWorkerRequirements::<T>::contains_key(&pool_id, &job_id), true);
+
+		Ok(())
+	}
+
+	// The job is processed, its input, output and proof are the largest
+	#[benchmark]
+	fn destroy_job() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let worker = add_mock_subscribed_worker::<T>(&owner, &pool_id, 0);
+		let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, T::InputLimit::get());
+		take_mock_job::<T>(&worker, &pool_id, &job_id, true);
+		assert_ok!(OffchainComputingPool::<T>::submit_job_result(
+			RawOrigin::Signed(worker.clone()).into(),
+			pool_id.clone(),
+			job_id.clone(),
+			JobResult::Success,
+			Some(mock_payload::<T::OutputLimit>(T::OutputLimit::get())),
+			EncryptionScheme::Plain,
+			Some(vec![0u8; T::ProofLimit::get() as usize].try_into().unwrap()),
+			None
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), job_id.clone());
+
+		assert_eq!(Jobs::<T>::contains_key(&pool_id, &job_id), false);
+		assert_eq!(JobOutputs::<T>::contains_key(&pool_id, &job_id), false);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	// Fill both scan windows with jobs the worker failed before, so the worker takes the last
+	// job scanned
+	#[benchmark]
+	fn take_job(n: Linear<1, { T::TakeJobScanLimit::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let worker = add_mock_subscribed_worker::<T>(&owner, &pool_id, 0);
+		for i in 0..(2 * n - 1) {
+			let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, 0);
+			if i < n {
+				AssignableJobs::<T>::remove((pool_id.clone(), 1, job_id.clone()));
+				DesignatedJobs::<T>::insert((pool_id.clone(), worker.clone(), job_id.clone()), ());
+			}
+			JobFailedWorkers::<T>::insert((pool_id.clone(), job_id, worker.clone()), ());
+		}
+		let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, T::InputLimit::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(worker.clone()), pool_id.clone(), None, true, None);
+
+		let job = Jobs::<T>::get(&pool_id, &job_id).expect("JobInfo should has value");
+		assert_eq!(job.assignee, Some(worker));
+		assert_eq!(job.status, JobStatus::Processing);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resign_job() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let worker = add_mock_subscribed_worker::<T>(&owner, &pool_id, 0);
+		let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, T::InputLimit::get());
+		take_mock_job::<T>(&worker, &pool_id, &job_id, false);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(worker.clone()), pool_id.clone(), job_id.clone());
+
+		let job = Jobs::<T>::get(&pool_id, &job_id).expect("JobInfo should has value");
+		assert_eq!(job.assignee, None);
+
+		Ok(())
+	}
+
+	// This is the slow path, the job has a callback and will be destroyed once processed,
+	// the callback and the rate contract are weighed by their gas limits separately
+	#[benchmark]
+	fn submit_job_result(
+		o: Linear<0, { T::OutputLimit::get() }>,
+		p: Linear<0, { T::ProofLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		assert_ok!(OffchainComputingPool::<T>::update_pool_settings(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone(),
+			1,
+			1,
+			JobScheduler::DemoOnly,
+			true,
+			true
+		));
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let worker = add_mock_subscribed_worker::<T>(&owner, &pool_id, 0);
+		let job_id = add_mock_job_with_callback::<T>(&owner, &pool_id, &policy_id);
+		take_mock_job::<T>(&worker, &pool_id, &job_id, true);
+		let proof: BoundedVec<u8, T::ProofLimit> = vec![0u8; p as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(worker.clone()),
+			pool_id.clone(),
+			job_id.clone(),
+			JobResult::Success,
+			Some(mock_payload::<T::OutputLimit>(o)),
+			EncryptionScheme::Plain,
+			Some(proof),
+			None,
+		);
+
+		assert_eq!(Jobs::<T>::contains_key(&pool_id, &job_id), false);
+		assert_eq!(CounterForWorkerAssignedJobs::<T>::get(&worker), 0);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_job_pre_signed(i: Linear<0, { T::InputLimit::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let (signer_public, signer) = T::BenchmarkHelper::signer();
+		fund_account::<T>(&signer);
+		let relayer: T::AccountId = account("relayer", 0, 0);
+
+		let job_data = PreSignedJobOf::<T> {
+			pool_id: pool_id.clone(),
+			policy_id,
+			unique_track_id: Some(mock_unique_track_id()),
+			beneficiary: None,
+			impl_spec_version: 1,
+			input: Some(vec![0u8; i as usize]),
+			input_encryption: EncryptionScheme::Plain,
+			soft_expires_in: None,
+			only_relayer: Some(relayer.clone()),
+			deadline: frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()),
+			nonce: 0,
+		};
+		let message = OffchainComputingPool::<T>::pre_signed_job_message(&job_data);
+		let signature = T::BenchmarkHelper::sign(&signer_public, &message);
+		let job_id = NextJobId::<T>::get(&pool_id).unwrap_or(1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer), Box::new(job_data), signature, signer.clone(), false);
+
+		let job = Jobs::<T>::get(&pool_id, &job_id).expect("JobInfo should has value");
+		assert_eq!(job.depositor, signer);
+		assert_eq!(PreSignedJobNonces::<T>::get(&signer), 1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fund_job_sponsorship() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		fund_account::<T>(&sponsor);
+		let amount = (100 * DOLLARS).saturated_into::<BalanceOf<T>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sponsor.clone()), pool_id.clone(), policy_id.clone(), amount, amount);
+
+		let sponsorship = JobSponsorships::<T>::get((pool_id, policy_id, sponsor))
+			.expect("Sponsorship should has value");
+		assert_eq!(sponsorship.budget, amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_job_sponsorship() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		fund_account::<T>(&sponsor);
+		let amount = (100 * DOLLARS).saturated_into::<BalanceOf<T>>();
+		assert_ok!(OffchainComputingPool::<T>::fund_job_sponsorship(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			pool_id.clone(),
+			policy_id.clone(),
+			amount,
+			amount
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sponsor.clone()), pool_id.clone(), policy_id.clone(), None);
+
+		assert_eq!(JobSponsorships::<T>::contains_key((pool_id, policy_id, sponsor)), false);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_sponsored_job(i: Linear<0, { T::InputLimit::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		fund_account::<T>(&sponsor);
+		let amount = (1_000 * DOLLARS).saturated_into::<BalanceOf<T>>();
+		assert_ok!(OffchainComputingPool::<T>::fund_job_sponsorship(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			pool_id.clone(),
+			policy_id.clone(),
+			amount,
+			amount
+		));
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let job_id = NextJobId::<T>::get(&pool_id).unwrap_or(1u32.into());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(beneficiary.clone()),
+			pool_id.clone(),
+			policy_id,
+			T::Lookup::unlookup(sponsor.clone()),
+			Some(mock_unique_track_id()),
+			1,
+			Some(mock_payload::<T::InputLimit>(i)),
+			EncryptionScheme::Plain,
+			None,
+		);
+
+		let job = Jobs::<T>::get(&pool_id, &job_id).expect("JobInfo should has value");
+		assert_eq!(job.depositor, sponsor);
+		assert_eq!(job.beneficiary, beneficiary);
+
+		Ok(())
+	}
+
+	// The new input requires more deposit
+	#[benchmark]
+	fn update_job_input(i: Linear<1, { T::InputLimit::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, 0);
+		let input = mock_payload::<T::InputLimit>(i);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			job_id.clone(),
+			Some(input.clone()),
+			EncryptionScheme::Plain,
+		);
+
+		let input_entry = JobInputs::<T>::get(&pool_id, &job_id).expect("Input should has value");
+		assert_eq!(input_entry.data, input);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reclaim_job_surplus_deposit() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, T::InputLimit::get());
+		assert_ok!(OffchainComputingPool::<T>::update_job_input(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone(),
+			job_id.clone(),
+			Some(mock_payload::<T::InputLimit>(0)),
+			EncryptionScheme::Plain
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), job_id.clone());
+
+		let input_entry = JobInputs::<T>::get(&pool_id, &job_id).expect("Input should has value");
+		assert_eq!(input_entry.surplus_deposit.is_zero(), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn top_up_job_deposit() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, 0);
+		let deposit = Jobs::<T>::get(&pool_id, &job_id).expect("JobInfo should has value").deposit;
+		let amount = (10 * DOLLARS).saturated_into::<BalanceOf<T>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), job_id.clone(), amount);
+
+		let job = Jobs::<T>::get(&pool_id, &job_id).expect("JobInfo should has value");
+		assert_eq!(job.deposit, deposit.saturating_add(amount));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_rate_contract() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = instantiate_mock_contract::<T>(&owner);
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, contract.clone());
+
+		assert_eq!(RateContracts::<T>::contains_key(&contract), true);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_rate_contract() -> Result<(), BenchmarkError> {
+		let contract: T::AccountId = account("contract", 0, 0);
+		RateContracts::<T>::insert(&contract, ());
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, contract.clone());
+
+		assert_eq!(RateContracts::<T>::contains_key(&contract), false);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_job_policy_rate_strategy() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let contract: T::AccountId = account("contract", 0, 0);
+		RateContracts::<T>::insert(&contract, ());
+		let rate_strategy = JobRateStrategy::Contract(contract);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			policy_id.clone(),
+			rate_strategy.clone(),
+		);
+
+		let policy = JobPolicies::<T>::get(&pool_id, &policy_id).expect("Policy should has value");
+		assert_eq!(policy.rate_strategy, rate_strategy);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_job() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		assert_ok!(OffchainComputingPool::<T>::update_job_policy_cancellation_compensation(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone(),
+			policy_id.clone(),
+			JobCancellationCompensation::ProRata
+		));
+		let worker = add_mock_subscribed_worker::<T>(&owner, &pool_id, 0);
+		let job_id = add_mock_job::<T>(&owner, &pool_id, &policy_id, 0);
+		take_mock_job::<T>(&worker, &pool_id, &job_id, true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), job_id.clone());
+
+		let job = Jobs::<T>::get(&pool_id, &job_id).expect("JobInfo should has value");
+		assert_eq!(job.status, JobStatus::Cancelled);
+		assert_eq!(CounterForWorkerAssignedJobs::<T>::get(&worker), 0);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_job_policy_cancellation_compensation() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			policy_id.clone(),
+			JobCancellationCompensation::Percent(50),
+		);
+
+		let policy = JobPolicies::<T>::get(&pool_id, &policy_id).expect("Policy should has value");
+		assert_eq!(policy.cancellation_compensation, JobCancellationCompensation::Percent(50));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_job_policy_max_attempts() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		let max_attempts = T::MaxJobAttempts::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), policy_id.clone(), max_attempts);
+
+		let policy = JobPolicies::<T>::get(&pool_id, &policy_id).expect("Policy should has value");
+		assert_eq!(policy.max_attempts, max_attempts);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn start_destroy_pool() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone());
+
+		let pool_info = Pools::<T>::get(&pool_id).expect("PoolInfo should has value");
+		assert_eq!(pool_info.status, PoolStatus::Destroying);
+
+		Ok(())
+	}
+
+	// Each job has the largest input
+	#[benchmark]
+	fn destroy_pool_jobs(
+		n: Linear<0, { T::RemoveItemsLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let policy_id = add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		for _ in 0..n {
+			add_mock_job::<T>(&owner, &pool_id, &policy_id, T::InputLimit::get());
+		}
+		assert_ok!(OffchainComputingPool::<T>::start_destroy_pool(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone()
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone());
+
+		let pool_info = Pools::<T>::get(&pool_id).expect("PoolInfo should has value");
+		assert_eq!(pool_info.jobs_count, 0);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_pool_workers(
+		n: Linear<0, { T::RemoveItemsLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		for i in 0..n {
+			add_mock_subscribed_worker::<T>(&owner, &pool_id, i);
+		}
+		assert_ok!(OffchainComputingPool::<T>::start_destroy_pool(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone()
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone());
+
+		let pool_info = Pools::<T>::get(&pool_id).expect("PoolInfo should has value");
+		assert_eq!(pool_info.workers_count, 0);
+
+		Ok(())
+	}
+
+	// The pool has metadata, a team and `p` policies to clear
+	#[benchmark]
+	fn finish_destroy_pool(
+		p: Linear<0, { T::MaxPoliciesPerPool::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		set_mock_pool_metadata::<T>(&owner, &pool_id);
+		set_mock_pool_team::<T>(&owner, &pool_id);
+		for _ in 0..p {
+			add_mock_policy::<T>(&owner, &pool_id, ApplicableScope::Public);
+		}
+		assert_ok!(OffchainComputingPool::<T>::start_destroy_pool(
+			RawOrigin::Signed(owner.clone()).into(),
+			pool_id.clone()
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone());
+
+		assert_eq!(Pools::<T>::contains_key(&pool_id), false);
+		assert_eq!(PoolMetadata::<T>::contains_key(&pool_id), false);
+
+		Ok(())
+	}
+
+	// The previous team will be cleared
+	#[benchmark]
+	fn set_pool_team() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		set_mock_pool_team::<T>(&owner, &pool_id);
+		let team = (0..4).map(|i| account::<T::AccountId>("new_team", i, 0)).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			Some(T::Lookup::unlookup(team[0].clone())),
+			Some(T::Lookup::unlookup(team[1].clone())),
+			Some(T::Lookup::unlookup(team[2].clone())),
+			Some(T::Lookup::unlookup(team[3].clone())),
+		);
+
+		assert_eq!(
+			OffchainComputingPool::<T>::has_pool_role(&pool_id, &team[0], PoolRole::Admin),
+			true
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_accept_pool_ownership() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		fund_account::<T>(&new_owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(new_owner.clone()), Some(pool_id.clone()));
+
+		assert_eq!(PoolOwnershipAcceptance::<T>::get(&new_owner), Some(pool_id));
+
+		Ok(())
+	}
+
+	// The pool has metadata, its deposit will be transferred too
+	#[benchmark]
+	fn transfer_pool_ownership() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		set_mock_pool_metadata::<T>(&owner, &pool_id);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		fund_account::<T>(&new_owner);
+		assert_ok!(OffchainComputingPool::<T>::set_accept_pool_ownership(
+			RawOrigin::Signed(new_owner.clone()).into(),
+			Some(pool_id.clone())
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool_id.clone(),
+			T::Lookup::unlookup(new_owner.clone()),
+		);
+
+		let pool_info = Pools::<T>::get(&pool_id).expect("PoolInfo should has value");
+		assert_eq!(pool_info.owner, new_owner);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_pool_min_worker_deposit() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let pool_id = add_mock_pool::<T>(&owner);
+		let min_worker_deposit =
+			<T as pallet_offchain_computing_infra::Config>::RegisterWorkerDeposit::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool_id.clone(), Some(min_worker_deposit));
+
+		assert_eq!(PoolMinWorkerDeposits::<T>::get(&pool_id), Some(min_worker_deposit));
+
+		Ok(())
+	}
+
+	// This line generates test cases for benchmarking, and could be run by:
+	//   `cargo test --features runtime-benchmarks`
+	impl_benchmark_test_suite! {
+		OffchainComputingPool,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	}
+}
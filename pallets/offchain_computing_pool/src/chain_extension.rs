@@ -0,0 +1,133 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The chain extension which allows ink! contracts to create jobs and read their results,
+//! so a contract could act as an application that orchestrates off-chain computing.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, Result as ExtensionResult, RetVal,
+};
+use scale_codec::Encode;
+
+/// Create a job, the contract will be the creator, the depositor and the beneficiary of the job,
+/// so the deposits will be paid by the contract's balance.
+///
+/// Input: `(PoolId, PolicyId, Option<UniqueTrackId>, ImplSpecVersion,
+/// Option<JobPayload<InputLimit>>, EncryptionScheme<AccountId>, Option<u64>,
+/// Option<JobCallbackRequest<AccountId, Balance>>)`
+/// Output: `JobId`
+pub const CREATE_JOB_FUNC_ID: u16 = 1;
+/// Query the status and the result of a job.
+///
+/// Input: `(PoolId, JobId)`
+/// Output: `Option<(JobStatus, Option<JobResult>)>`
+pub const JOB_STATUS_FUNC_ID: u16 = 2;
+/// Query the output of a job.
+///
+/// Input: `(PoolId, JobId)`
+/// Output: `Option<JobPayload<OutputLimit>>`
+pub const JOB_OUTPUT_FUNC_ID: u16 = 3;
+
+type CreateJobInput<T> = (
+	<T as Config>::PoolId,
+	<T as Config>::PolicyId,
+	Option<UniqueTrackId>,
+	ImplSpecVersion,
+	Option<JobPayload<<T as Config>::InputLimit>>,
+	EncryptionScheme<<T as frame_system::Config>::AccountId>,
+	Option<u64>,
+	Option<JobCallbackRequest<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
+);
+
+/// The chain extension of the pool pallet, set it to `pallet_contracts::Config::ChainExtension`.
+#[derive(Default)]
+pub struct OffchainComputingPoolExtension;
+
+impl<T: Config> ChainExtension<T> for OffchainComputingPoolExtension {
+	fn call<E: Ext<T = T>>(&mut self, env: Environment<E, InitState>) -> ExtensionResult<RetVal> {
+		let func_id = env.func_id();
+		let mut env = env.buf_in_buf_out();
+		match func_id {
+			CREATE_JOB_FUNC_ID => {
+				let in_len = env.in_len();
+				// The input length bounds the job's input, and the job's fee may be queried from
+				// the policy's rate contract.
+				env.charge_weight(
+					<T as Config>::WeightInfo::create_job(in_len)
+						.saturating_add(T::RateContractGasLimit::get()),
+				)?;
+
+				let (
+					pool_id,
+					policy_id,
+					unique_track_id,
+					impl_spec_version,
+					input,
+					input_encryption,
+					soft_expires_in,
+					callback,
+				): CreateJobInput<T> = env.read_as_unbounded(in_len)?;
+
+				let contract = env.ext().address().clone();
+				let now = T::UnixTime::now().as_secs().saturated_into::<u64>();
+				let job_id = Pallet::<T>::do_create_next_job(
+					contract.clone(),
+					contract.clone(),
+					pool_id,
+					policy_id,
+					unique_track_id,
+					contract,
+					impl_spec_version,
+					input,
+					input_encryption,
+					now,
+					soft_expires_in,
+					None,
+					callback,
+					None,
+				)?;
+
+				env.write(&job_id.encode(), false, None)?;
+			},
+			JOB_STATUS_FUNC_ID => {
+				env.charge_weight(T::DbWeight::get().reads(1))?;
+
+				let (pool_id, job_id): (T::PoolId, T::JobId) = env.read_as()?;
+				let status = Jobs::<T>::get(&pool_id, &job_id).map(|job| (job.status, job.result));
+
+				env.write(&status.encode(), false, None)?;
+			},
+			JOB_OUTPUT_FUNC_ID => {
+				env.charge_weight(T::DbWeight::get().reads(1))?;
+
+				let (pool_id, job_id): (T::PoolId, T::JobId) = env.read_as()?;
+				let output = JobOutputs::<T>::get(&pool_id, &job_id).map(|entry| entry.data);
+
+				env.write(&output.encode(), false, None)?;
+			},
+			_ => {
+				log!(error, "Called an unregistered `func_id`: {:?}", func_id);
+				return Err(DispatchError::Other("Unimplemented func_id"))
+			},
+		}
+
+		Ok(RetVal::Converging(0))
+	}
+}
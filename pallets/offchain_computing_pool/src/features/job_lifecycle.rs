@@ -17,7 +17,11 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::{Fortitude, Restriction},
+};
+use scale_codec::Encode;
 use sp_runtime::{traits::Zero, Saturating};
 
 impl<T: Config> Pallet<T> {
@@ -30,6 +34,8 @@ impl<T: Config> Pallet<T> {
 		processing: bool,
 		expires_in: u64,
 	) -> DispatchResult {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Self::ensure_pool_live(&pool_info)?;
 		Self::ensure_subscribed_worker(&pool_id, &worker)?;
 		let worker_info =
 			PalletInfra::<T>::worker_info(&worker).ok_or(Error::<T>::WorkerNotFound)?;
@@ -38,32 +44,75 @@ impl<T: Config> Pallet<T> {
 
 		let current_assigned_jobs_count = CounterForWorkerAssignedJobs::<T>::get(&worker);
 		ensure!(
-			current_assigned_jobs_count < T::MaxAssignedJobsPerWorker::get(),
+			current_assigned_jobs_count < Self::worker_job_slots(&worker),
 			Error::<T>::WorkerAssignedJobsLimitExceeded
 		);
 
+		let capability_set = WorkerDeclaredCapabilities::<T>::get(&worker).unwrap_or_default();
+		let can_take = |job_id: &T::JobId| {
+			Self::worker_meets_job_requirements(&pool_id, job_id, &capability_set) &&
+				!JobFailedWorkers::<T>::contains_key((
+					pool_id.clone(),
+					job_id.clone(),
+					worker.clone(),
+				))
+		};
+
 		// TODO: the current design has thundering herd problem, but it's OK for now.
 		let mut job = 'block: {
 			if let Some(job_id) = maybe_job_id {
 				break 'block Jobs::<T>::get(&pool_id, &job_id).ok_or(Error::<T>::JobNotFound)
 			}
 
-			let job_id =
-				AssignableJobs::<T>::iter_key_prefix((pool_id.clone(), worker_impl_spec_version))
-					.next()
-					.ok_or(Error::<T>::NoAssignableJob)?;
+			// Jobs designated to the worker take precedence, skip designated jobs of other impl
+			// spec versions, and jobs whose requirements the worker doesn't meet or failed before
+			// Scan at most `TakeJobScanLimit` jobs of each, the others could still be taken by id
+			let scan_limit = T::TakeJobScanLimit::get() as usize;
+			let job_id = DesignatedJobs::<T>::iter_key_prefix((pool_id.clone(), worker.clone()))
+				.take(scan_limit)
+				.find(|job_id| {
+					Jobs::<T>::get(&pool_id, job_id)
+						.is_some_and(|job| job.impl_spec_version == worker_impl_spec_version) &&
+						can_take(job_id)
+				})
+				.or_else(|| {
+					AssignableJobs::<T>::iter_key_prefix((
+						pool_id.clone(),
+						worker_impl_spec_version,
+					))
+					.take(scan_limit)
+					.find(|job_id| can_take(job_id))
+				})
+				.ok_or(Error::<T>::NoAssignableJob)?;
 			Jobs::<T>::get(&pool_id, &job_id).ok_or(Error::<T>::JobNotFound)
 		}?;
 		ensure!(worker_impl_spec_version == job.impl_spec_version, Error::<T>::ImplMismatched);
-		AssignableJobs::<T>::remove((pool_id.clone(), job.impl_spec_version, job.id.clone()));
+		ensure!(
+			Self::worker_meets_job_requirements(&pool_id, &job.id, &capability_set),
+			Error::<T>::WorkerCapabilitiesMismatched
+		);
+		ensure!(
+			!JobFailedWorkers::<T>::contains_key((pool_id.clone(), job.id.clone(), worker.clone())),
+			Error::<T>::WorkerAlreadyFailedJob
+		);
+		if let Some(designated_worker) = Self::job_designated_worker(&pool_id, &job.id) {
+			ensure!(designated_worker == worker, Error::<T>::JobEncryptedForAnotherWorker);
+			DesignatedJobs::<T>::remove((pool_id.clone(), designated_worker, job.id.clone()));
+		} else {
+			AssignableJobs::<T>::remove((pool_id.clone(), job.impl_spec_version, job.id.clone()));
+		}
 
 		// It is possible to get a expired job, but actually it is a soft expiring
 		// Comment this because current `expires_at` actually a soft expiring
 		// Self::ensure_job_not_expired(&job, now)?;
 
 		ensure!(job.assignee.is_none(), Error::<T>::JobAlreadyAssigned);
+		let Some(impl_build_version) = worker_info.impl_build_version else {
+			return Err(Error::<T>::InternalError.into())
+		};
 		job.assignee = Some(worker.clone());
 		job.assigned_at = Some(now);
+		job.impl_build_version = Some(impl_build_version);
 
 		// job.expires_at = now + expires_in; // Not sure we need to expand expiring time
 		if processing {
@@ -74,11 +123,9 @@ impl<T: Config> Pallet<T> {
 
 		let job_id = job.id.clone();
 		CounterForWorkerAssignedJobs::<T>::insert(&worker, current_assigned_jobs_count + 1);
+		WorkerAssignedJobs::<T>::insert((worker.clone(), pool_id.clone(), job_id.clone()), ());
 		Jobs::<T>::insert(&pool_id, &job_id, job);
 
-		let Some(impl_build_version) = worker_info.impl_build_version else {
-			return Err(Error::<T>::InternalError.into())
-		};
 		Self::deposit_event(Event::JobAssigned {
 			pool_id: pool_id.clone(),
 			job_id: job_id.clone(),
@@ -120,7 +167,15 @@ impl<T: Config> Pallet<T> {
 				Ok(())
 			},
 		)?;
-		AssignableJobs::<T>::insert((pool_id.clone(), job.impl_spec_version, job_id.clone()), ());
+		WorkerAssignedJobs::<T>::remove((worker.clone(), pool_id.clone(), job_id.clone()));
+		if let Some(designated_worker) = Self::job_designated_worker(&pool_id, &job_id) {
+			DesignatedJobs::<T>::insert((pool_id.clone(), designated_worker, job_id.clone()), ());
+		} else {
+			AssignableJobs::<T>::insert(
+				(pool_id.clone(), job.impl_spec_version, job_id.clone()),
+				(),
+			);
+		}
 
 		Jobs::<T>::insert(&pool_id, &job_id, job);
 
@@ -128,13 +183,29 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	#[allow(clippy::too_many_arguments)]
+	/// Report the result to the build's health, so the impl owner could watch canary builds.
+	pub(crate) fn record_impl_build_job_result(
+		pool_id: &T::PoolId,
+		job: &JobInfo<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>>,
+		result: &JobResult,
+	) {
+		let Some(impl_build_version) = job.impl_build_version else { return };
+		let Some(pool_info) = Pools::<T>::get(pool_id) else { return };
+
+		PalletInfra::<T>::record_impl_build_job_result(
+			&pool_info.impl_id,
+			impl_build_version,
+			*result == JobResult::Success,
+		);
+	}
+
 	pub(crate) fn do_submit_job_result(
 		pool_id: T::PoolId,
 		job_id: T::JobId,
 		worker: T::AccountId,
 		result: JobResult,
-		output_data: Option<BoundedVec<u8, T::OutputLimit>>,
+		output_data: Option<JobPayload<T::OutputLimit>>,
+		output_encryption: EncryptionScheme<T::AccountId>,
 		proof_data: Option<BoundedVec<u8, T::ProofLimit>>,
 		now: u64,
 		expires_in: u64,
@@ -147,15 +218,34 @@ impl<T: Config> Pallet<T> {
 		// Comment this because current `expires_at` actually a soft expiring
 		// Self::ensure_job_not_expired(&task, now)?;
 		Self::ensure_job_assignee(&job, &worker)?;
+		ensure!(
+			matches!(
+				output_encryption,
+				EncryptionScheme::Plain | EncryptionScheme::SealedToBeneficiary
+			),
+			Error::<T>::UnsupportedEncryptionScheme
+		);
+
+		Self::record_impl_build_job_result(&pool_id, &job, &result);
+
+		// The worker caused the failure, let other workers retry if attempts left
+		if result == JobResult::Panic && Self::retry_failed_job(&pool_id, &mut job, &worker) {
+			Self::release_assigned_job(&pool_id, &job_id, &worker);
+			Jobs::<T>::insert(&pool_id, &job_id, job);
+			return Ok(())
+		}
 
 		job.expires_at = now + expires_in;
 		job.status = JobStatus::Processed;
 		job.result = Some(result.clone());
 		job.ended_at = Some(now);
 
+		let output_hash = output_data.as_ref().map(|x| x.hash());
 		if let Some(output_data) = output_data.clone() {
+			Self::ensure_valid_payload(&output_data)?;
+
 			let deposit = T::JobStorageDepositPerByte::get()
-				.saturating_mul(((output_data.len()) as u32).into());
+				.saturating_mul(((output_data.storage_len()) as u32).into());
 			let depositor = job.assignee.clone().ok_or(Error::<T>::NoPermission)?;
 			<T as Config>::Currency::hold(
 				&HoldReason::JobStorageReserve.into(),
@@ -163,10 +253,11 @@ impl<T: Config> Pallet<T> {
 				deposit,
 			)?;
 
-			let output_entry = ChainStoredData::<T::AccountId, BalanceOf<T>, T::OutputLimit> {
+			let output_entry = JobData::<T::AccountId, BalanceOf<T>, T::OutputLimit> {
 				depositor,
 				actual_deposit: deposit,
 				surplus_deposit: Zero::zero(),
+				encryption: output_encryption.clone(),
 				data: output_data.clone(),
 			};
 			JobOutputs::<T>::insert(&pool_id, &job_id, output_entry);
@@ -197,12 +288,15 @@ impl<T: Config> Pallet<T> {
 				Ok(())
 			},
 		)?;
+		WorkerAssignedJobs::<T>::remove((worker.clone(), pool_id.clone(), job_id.clone()));
 
 		Self::deposit_event(Event::JobResultUpdated {
 			pool_id: pool_id.clone(),
 			job_id: job_id.clone(),
-			result,
+			result: result.clone(),
 			output: output_data,
+			output_hash,
+			output_encryption,
 			proof: proof_data,
 		});
 		Self::deposit_event(Event::JobStatusUpdated {
@@ -211,6 +305,23 @@ impl<T: Config> Pallet<T> {
 			status: JobStatus::Processed,
 		});
 
+		Self::do_settle_job_fee(&pool_id, &mut job, &worker)?;
+
+		if let Some(mut callback) = job.callback.clone() {
+			// Persist the job first, so the contract could read the result in the callback
+			Jobs::<T>::insert(&pool_id, &job_id, job.clone());
+			Self::do_execute_job_callback(
+				&pool_id,
+				&job_id,
+				&job.depositor,
+				&worker,
+				&mut callback,
+				result,
+				output_hash,
+			)?;
+			job.callback = Some(callback);
+		}
+
 		let pool_info = Pools::<T>::get(pool_id.clone()).ok_or(Error::<T>::PoolNotFound)?;
 		if pool_info.auto_destroy_processed_job_enabled {
 			Self::do_actual_destroy_job(pool_id, job, worker, JobDestroyReason::Completed)?
@@ -220,4 +331,164 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Call the callback contract with `(pool_id, job_id, result, output_hash)`,
+	/// failures of the call won't revert the result submission.
+	/// The fee of the consumed gas will be paid to the worker, and the rest will be released.
+	pub(crate) fn do_execute_job_callback(
+		pool_id: &T::PoolId,
+		job_id: &T::JobId,
+		depositor: &T::AccountId,
+		worker: &T::AccountId,
+		callback: &mut JobCallback<T::AccountId, BalanceOf<T>>,
+		result: JobResult,
+		output_hash: Option<DataHash>,
+	) -> DispatchResult {
+		let mut data = callback.selector.to_vec();
+		data.append(&mut (pool_id, job_id, result, output_hash).encode());
+		let gas_limit = Weight::from_parts(callback.ref_time_limit, callback.proof_size_limit);
+
+		// The depositor is the caller, so it pays the storage deposit of the call from the held
+		// storage deposit limit
+		<T as Config>::Currency::release(
+			&HoldReason::JobCallbackReserve.into(),
+			depositor,
+			callback.storage_deposit_limit,
+			Precision::BestEffort,
+		)?;
+		let storage_deposit_limit =
+			callback.storage_deposit_limit.saturated_into::<u128>().saturated_into();
+		let outcome = pallet_contracts::Pallet::<T>::bare_call(
+			depositor.clone(),
+			callback.contract.clone(),
+			Default::default(),
+			gas_limit,
+			Some(storage_deposit_limit),
+			data,
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+			Determinism::Enforced,
+		);
+		let error = match outcome.result {
+			Ok(ret) if !ret.did_revert() => None,
+			Ok(_) => Some(pallet_contracts::Error::<T>::ContractReverted.into()),
+			Err(error) => Some(error),
+		};
+		if let Some(error) = error {
+			log!(debug, "Job {}-{} callback failed: {:?}", pool_id, job_id, error);
+			Self::deposit_event(Event::JobCallbackFailed {
+				pool_id: pool_id.clone(),
+				job_id: job_id.clone(),
+				error,
+			});
+		} else {
+			Self::deposit_event(Event::JobCallbackExecuted {
+				pool_id: pool_id.clone(),
+				job_id: job_id.clone(),
+				gas_consumed: outcome.gas_consumed,
+			});
+		}
+
+		let fee = Self::calculate_gas_fee(outcome.gas_consumed).min(callback.prepaid_fee);
+		if !fee.is_zero() {
+			<T as Config>::Currency::transfer_on_hold(
+				&HoldReason::JobCallbackReserve.into(),
+				depositor,
+				worker,
+				fee,
+				Precision::BestEffort,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+		}
+		<T as Config>::Currency::release(
+			&HoldReason::JobCallbackReserve.into(),
+			depositor,
+			callback.prepaid_fee.saturating_sub(fee),
+			Precision::BestEffort,
+		)?;
+		callback.prepaid_fee = Zero::zero();
+		callback.storage_deposit_limit = Zero::zero();
+
+		Ok(())
+	}
+
+	/// Put the failed job back to pending if it has attempts left,
+	/// the failed worker won't be able to take it again, so the job ends once every worker of the
+	/// pool failed it.
+	/// Jobs designated to a worker are retried by the same worker because no one else could take
+	/// them.
+	pub(crate) fn retry_failed_job(
+		pool_id: &T::PoolId,
+		job: &mut JobInfo<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>>,
+		failed_worker: &T::AccountId,
+	) -> bool {
+		job.attempts = job.attempts.saturating_add(1);
+
+		let max_attempts =
+			JobPolicies::<T>::get(pool_id, &job.policy_id).map_or(1, |policy| policy.max_attempts);
+		if job.attempts >= max_attempts {
+			return false
+		}
+
+		if let Some(designated_worker) = Self::job_designated_worker(pool_id, &job.id) {
+			DesignatedJobs::<T>::insert((pool_id.clone(), designated_worker, job.id.clone()), ());
+		} else {
+			JobFailedWorkers::<T>::insert(
+				(pool_id.clone(), job.id.clone(), failed_worker.clone()),
+				(),
+			);
+
+			// The failed workers are bounded by `MaxJobAttempts`
+			let failed_workers_count =
+				JobFailedWorkers::<T>::iter_key_prefix((pool_id.clone(), job.id.clone()))
+					.filter(|worker| PoolAuthorizedWorkers::<T>::contains_key(worker, pool_id))
+					.count() as u32;
+			let workers_count =
+				Pools::<T>::get(pool_id).map_or(0, |pool_info| pool_info.workers_count);
+			if failed_workers_count >= workers_count {
+				return false
+			}
+
+			AssignableJobs::<T>::insert(
+				(pool_id.clone(), job.impl_spec_version, job.id.clone()),
+				(),
+			);
+		}
+		job.status = JobStatus::Pending;
+		job.result = None;
+		job.impl_build_version = None;
+		job.assignee = None;
+		job.assigned_at = None;
+		job.processing_at = None;
+		job.ended_at = None;
+
+		Self::deposit_event(Event::JobRetried {
+			pool_id: pool_id.clone(),
+			job_id: job.id.clone(),
+			failed_worker: failed_worker.clone(),
+			attempts: job.attempts,
+		});
+		Self::deposit_event(Event::JobStatusUpdated {
+			pool_id: pool_id.clone(),
+			job_id: job.id.clone(),
+			status: JobStatus::Pending,
+		});
+		true
+	}
+
+	/// Remove the job from the worker's assigned jobs.
+	pub(crate) fn release_assigned_job(
+		pool_id: &T::PoolId,
+		job_id: &T::JobId,
+		worker: &T::AccountId,
+	) {
+		if WorkerAssignedJobs::<T>::take((worker.clone(), pool_id.clone(), job_id.clone()))
+			.is_some()
+		{
+			CounterForWorkerAssignedJobs::<T>::mutate(worker, |counter| {
+				*counter = counter.saturating_sub(1);
+			});
+		}
+	}
 }
@@ -17,23 +17,195 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::{Fortitude, Restriction},
+};
 use frame_system::pallet_prelude::*;
-use sp_runtime::{traits::Zero, Saturating};
+use sp_runtime::{
+	traits::{Convert, Zero},
+	Perbill, Percent, Saturating,
+};
+use sp_std::prelude::*;
 
 impl<T: Config> Pallet<T> {
+	/// Check the pool and the policy are applicable for the `creator`,
+	/// then create a job with the next job id of the pool.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn do_create_next_job(
+		creator: T::AccountId,
+		depositor: T::AccountId,
+		pool_id: T::PoolId,
+		policy_id: T::PolicyId,
+		unique_track_id: Option<UniqueTrackId>,
+		beneficiary: T::AccountId,
+		impl_spec_version: ImplSpecVersion,
+		input: Option<JobPayload<T::InputLimit>>,
+		input_encryption: EncryptionScheme<T::AccountId>,
+		now: u64,
+		soft_expires_in: Option<u64>,
+		requirements: Option<JobRequirements>,
+		callback: Option<JobCallbackRequest<T::AccountId, BalanceOf<T>>>,
+		quoted_fee: Option<BalanceOf<T>>,
+	) -> Result<T::JobId, DispatchError> {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(pool_info.create_job_enabled, Error::<T>::PoolCreateNewJobUnavailable);
+		ensure!(
+			pool_info.jobs_count <= T::MaxJobsPerPool::get(),
+			Error::<T>::TasksPerPoolLimitExceeded
+		);
+
+		if let Some(unique_track_id) = unique_track_id.clone() {
+			ensure!(
+				!IndexedJobs::<T>::contains_key(&pool_id, unique_track_id),
+				Error::<T>::UniqueTrackIdNotUnique
+			);
+		}
+
+		let policy =
+			JobPolicies::<T>::get(&pool_id, &policy_id).ok_or(Error::<T>::JobPolicyNotFound)?;
+		ensure!(policy.enabled, Error::<T>::JobPolicyUnavailable);
+		let current_block = frame_system::Pallet::<T>::block_number();
+		if let Some(start_block) = policy.start_block {
+			ensure!(current_block >= start_block, Error::<T>::JobPolicyNotApplicable);
+		}
+		if let Some(end_block) = policy.end_block {
+			ensure!(current_block <= end_block, Error::<T>::JobPolicyNotApplicable);
+		}
+		match policy.applicable_scope {
+			ApplicableScope::Owner => {
+				ensure!(pool_info.owner == creator, Error::<T>::JobPolicyNotApplicable)
+			},
+			ApplicableScope::Public => {},
+			ApplicableScope::AllowList => {
+				ensure!(
+					JobPolicyAuthorizedAccounts::<T>::contains_key((
+						pool_id.clone(),
+						policy_id.clone(),
+						creator.clone()
+					)),
+					Error::<T>::JobPolicyNotApplicable
+				)
+			},
+		};
+
+		let job_id = NextJobId::<T>::get(&pool_id).unwrap_or(1u32.into());
+		Self::do_create_job(
+			pool_info,
+			policy,
+			job_id.clone(),
+			unique_track_id,
+			beneficiary,
+			depositor,
+			impl_spec_version,
+			input,
+			input_encryption,
+			now,
+			soft_expires_in,
+			requirements,
+			callback,
+			quoted_fee,
+		)?;
+
+		let next_id = job_id.clone().increment();
+		NextJobId::<T>::set(&pool_id, next_id);
+
+		Ok(job_id)
+	}
+
+	/// The message that the signer of a pre-signed job should sign, it's the domain tag,
+	/// the genesis hash and the encoded `job_data`, so the signature can't be replayed on other
+	/// chains.
+	pub fn pre_signed_job_message(job_data: &PreSignedJobOf<T>) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+
+		let mut message = PRE_SIGNED_JOB_DOMAIN_TAG.to_vec();
+		message.extend(genesis_hash.encode());
+		message.extend(job_data.encode());
+		message
+	}
+
+	/// Create a job on behalf of the `signer` of a pre-signed message.
+	pub(crate) fn do_create_job_pre_signed(
+		relayer: T::AccountId,
+		job_data: PreSignedJobOf<T>,
+		signer: T::AccountId,
+		relayer_pays_deposit: bool,
+		now: u64,
+	) -> DispatchResult {
+		let PreSignedJob {
+			pool_id,
+			policy_id,
+			unique_track_id,
+			beneficiary,
+			impl_spec_version,
+			input,
+			input_encryption,
+			soft_expires_in,
+			only_relayer,
+			deadline,
+			nonce,
+		} = job_data;
+
+		if let Some(account) = only_relayer {
+			ensure!(account == relayer, Error::<T>::WrongRelayer);
+		}
+
+		let current_block = frame_system::Pallet::<T>::block_number();
+		ensure!(deadline >= current_block, Error::<T>::DeadlineExpired);
+
+		PreSignedJobNonces::<T>::try_mutate(&signer, |next_nonce| -> DispatchResult {
+			ensure!(*next_nonce == nonce, Error::<T>::InvalidNonce);
+			*next_nonce = next_nonce.saturating_add(1);
+			Ok(())
+		})?;
+
+		let input = input
+			.map(|input| {
+				BoundedVec::<u8, T::InputLimit>::try_from(input)
+					.map(JobPayload::Inline)
+					.map_err(|_| Error::<T>::InputTooLong)
+			})
+			.transpose()?;
+		let beneficiary = beneficiary.unwrap_or(signer.clone());
+		let depositor = if relayer_pays_deposit { relayer } else { signer.clone() };
+
+		Self::do_create_next_job(
+			signer,
+			depositor,
+			pool_id,
+			policy_id,
+			unique_track_id,
+			beneficiary,
+			impl_spec_version,
+			input,
+			input_encryption,
+			now,
+			soft_expires_in,
+			None,
+			None,
+			None,
+		)?;
+
+		Ok(())
+	}
+
 	#[allow(clippy::too_many_arguments)]
 	pub(crate) fn do_create_job(
 		pool_info: PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
-		policy_info: JobPolicy<T::PolicyId, BlockNumberFor<T>>,
+		policy_info: JobPolicy<T::PolicyId, T::AccountId, BlockNumberFor<T>>,
 		job_id: T::JobId,
 		unique_track_id: Option<UniqueTrackId>,
 		beneficiary: T::AccountId,
 		depositor: T::AccountId,
 		impl_spec_version: ImplSpecVersion,
-		input_data: Option<BoundedVec<u8, T::InputLimit>>,
+		input_data: Option<JobPayload<T::InputLimit>>,
+		input_encryption: EncryptionScheme<T::AccountId>,
 		now: u64,
 		expires_in: Option<u64>,
+		requirements: Option<JobRequirements>,
+		callback: Option<JobCallbackRequest<T::AccountId, BalanceOf<T>>>,
+		quoted_fee: Option<BalanceOf<T>>,
 	) -> DispatchResult {
 		ensure!(
 			impl_spec_version >= pool_info.min_impl_spec_version &&
@@ -41,6 +213,8 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::UnsupportedImplSpecVersion
 		);
 
+		let designated_worker = Self::ensure_valid_input(&input_data, &input_encryption)?;
+
 		let expires_in = expires_in.unwrap_or(T::DefaultJobExpiresIn::get());
 		ensure!(expires_in >= T::MinJobExpiresIn::get(), Error::<T>::ExpiresInTooSmall);
 		ensure!(expires_in <= T::MaxJobExpiresIn::get(), Error::<T>::ExpiresInTooLarge);
@@ -55,7 +229,7 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		let input_deposit = T::JobStorageDepositPerByte::get().saturating_mul(
-			((input_data.as_ref().map(|x| x.len()).unwrap_or_default()) as u32).into(),
+			((input_data.as_ref().map(|x| x.storage_len()).unwrap_or_default()) as u32).into(),
 		);
 		<T as Config>::Currency::hold(
 			&HoldReason::JobStorageReserve.into(),
@@ -63,6 +237,19 @@ impl<T: Config> Pallet<T> {
 			input_deposit,
 		)?;
 
+		let callback = callback
+			.map(|callback| Self::prepay_job_callback(&depositor, callback))
+			.transpose()?;
+
+		let fee = Self::hold_job_fee(
+			&pool_info.id,
+			&policy_info,
+			&job_id,
+			&depositor,
+			&beneficiary,
+			quoted_fee,
+		)?;
+
 		let expires_at = now + expires_in;
 		let job = JobInfo::<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>> {
 			id: job_id.clone(),
@@ -81,6 +268,9 @@ impl<T: Config> Pallet<T> {
 			assigned_at: None,
 			processing_at: None,
 			ended_at: None,
+			attempts: 0,
+			fee,
+			callback,
 		};
 		Jobs::<T>::insert(&pool_info.id, &job_id, job);
 		if let Some(unique_track_id) = unique_track_id.clone() {
@@ -88,14 +278,18 @@ impl<T: Config> Pallet<T> {
 		}
 
 		if let Some(input_data) = input_data.clone() {
-			let input = ChainStoredData::<T::AccountId, BalanceOf<T>, T::InputLimit> {
+			let input = JobData::<T::AccountId, BalanceOf<T>, T::InputLimit> {
 				depositor: depositor.clone(),
 				actual_deposit: input_deposit,
 				surplus_deposit: Zero::zero(),
+				encryption: input_encryption.clone(),
 				data: input_data.clone(),
 			};
 			JobInputs::<T>::insert(&pool_info.id, &job_id, input);
 		}
+		if let Some(requirements) = requirements.clone() {
+			JobWorkerRequirements::<T>::insert(&pool_info.id, &job_id, requirements);
+		}
 
 		let mut new_pool_info = pool_info.clone();
 		new_pool_info.jobs_count += 1;
@@ -105,7 +299,14 @@ impl<T: Config> Pallet<T> {
 		new_policy_info.jobs_count += 1;
 		JobPolicies::<T>::insert(&pool_info.id, &policy_info.id, new_policy_info);
 
-		AssignableJobs::<T>::insert((pool_info.id.clone(), impl_spec_version, job_id.clone()), ());
+		if let Some(worker) = designated_worker {
+			DesignatedJobs::<T>::insert((pool_info.id.clone(), worker, job_id.clone()), ());
+		} else {
+			AssignableJobs::<T>::insert(
+				(pool_info.id.clone(), impl_spec_version, job_id.clone()),
+				(),
+			);
+		}
 		AccountBeneficialJobs::<T>::insert(
 			(beneficiary.clone(), pool_info.id.clone(), job_id.clone()),
 			(),
@@ -120,11 +321,238 @@ impl<T: Config> Pallet<T> {
 			beneficiary,
 			impl_spec_version,
 			input: input_data,
+			input_encryption,
+			requirements,
 			expires_in,
 		});
 		Ok(())
 	}
 
+	/// Validate the callback request and hold the fee of its gas limit from the depositor.
+	pub(crate) fn prepay_job_callback(
+		depositor: &T::AccountId,
+		callback: JobCallbackRequest<T::AccountId, BalanceOf<T>>,
+	) -> Result<JobCallback<T::AccountId, BalanceOf<T>>, DispatchError> {
+		ensure!(
+			pallet_contracts::Pallet::<T>::code_hash(&callback.contract).is_some(),
+			Error::<T>::JobCallbackContractNotFound
+		);
+		ensure!(
+			callback.gas_limit.all_lte(T::MaxJobCallbackGasLimit::get()),
+			Error::<T>::JobCallbackGasLimitExceeded
+		);
+
+		let prepaid_fee = Self::calculate_gas_fee(callback.gas_limit);
+		<T as Config>::Currency::hold(
+			&HoldReason::JobCallbackReserve.into(),
+			depositor,
+			prepaid_fee.saturating_add(callback.storage_deposit_limit),
+		)?;
+
+		Ok(JobCallback {
+			contract: callback.contract,
+			selector: callback.selector,
+			ref_time_limit: callback.gas_limit.ref_time(),
+			proof_size_limit: callback.gas_limit.proof_size(),
+			prepaid_fee,
+			storage_deposit_limit: callback.storage_deposit_limit,
+		})
+	}
+
+	/// The fee of the gas, priced by `pallet_contracts`.
+	pub(crate) fn calculate_gas_fee(gas: Weight) -> BalanceOf<T> {
+		<T as pallet_contracts::Config>::WeightPrice::convert(gas)
+			.saturated_into::<u128>()
+			.saturated_into::<BalanceOf<T>>()
+	}
+
+	/// The worker which the job's input encrypted to, only the worker can take the job.
+	pub(crate) fn job_designated_worker(
+		pool_id: &T::PoolId,
+		job_id: &T::JobId,
+	) -> Option<T::AccountId> {
+		JobInputs::<T>::get(pool_id, job_id).and_then(|input| match input.encryption {
+			EncryptionScheme::NaclBoxToWorker(worker) => Some(worker),
+			_ => None,
+		})
+	}
+
+	/// Ensure the input and its encryption scheme are valid,
+	/// returns the designated worker if the input encrypted to a particular worker.
+	pub(crate) fn ensure_valid_input(
+		input: &Option<JobPayload<T::InputLimit>>,
+		input_encryption: &EncryptionScheme<T::AccountId>,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		if let Some(input) = input {
+			Self::ensure_valid_payload(input)?;
+		}
+
+		match input_encryption {
+			EncryptionScheme::Plain => Ok(None),
+			EncryptionScheme::NaclBoxToWorker(worker) => {
+				ensure!(input.is_some(), Error::<T>::UnsupportedEncryptionScheme);
+				ensure!(
+					PalletInfra::<T>::worker_encryption_key(worker).is_some(),
+					Error::<T>::WorkerEncryptionKeyNotFound
+				);
+				Ok(Some(worker.clone()))
+			},
+			EncryptionScheme::SealedToBeneficiary =>
+				Err(Error::<T>::UnsupportedEncryptionScheme.into()),
+		}
+	}
+
+	/// Ensure the reference to the off-chain data is well-formed.
+	pub(crate) fn ensure_valid_payload<DataLimit: Get<u32>>(
+		payload: &JobPayload<DataLimit>,
+	) -> DispatchResult {
+		if let JobPayload::Reference(reference) = payload {
+			ensure!(
+				!reference.uri.is_empty() && reference.size > 0,
+				Error::<T>::InvalidDataReference
+			);
+		}
+
+		Ok(())
+	}
+
+	/// The total deposits required for creating a job with `input_len` bytes of input.
+	pub(crate) fn calculate_job_creation_deposit(input_len: usize) -> BalanceOf<T> {
+		T::JobCreationDeposit::get().saturating_add(
+			T::JobStorageDepositPerByte::get().saturating_mul((input_len as u32).into()),
+		)
+	}
+
+	/// Replace the input of a pending job, the storage deposit will be re-calculated,
+	/// the over-payment will be kept as surplus deposit which could be reclaimed later.
+	pub(crate) fn do_update_job_input(
+		who: T::AccountId,
+		pool_id: T::PoolId,
+		job_id: T::JobId,
+		input: Option<JobPayload<T::InputLimit>>,
+		input_encryption: EncryptionScheme<T::AccountId>,
+	) -> DispatchResult {
+		let job = Jobs::<T>::get(&pool_id, &job_id).ok_or(Error::<T>::JobNotFound)?;
+		ensure!(who == job.depositor, Error::<T>::NoPermission);
+		ensure!(job.status == JobStatus::Pending, Error::<T>::JobIsProcessing);
+		ensure!(job.assignee.is_none(), Error::<T>::JobAlreadyAssigned);
+
+		let designated_worker = Self::ensure_valid_input(&input, &input_encryption)?;
+
+		if let Some(worker) = Self::job_designated_worker(&pool_id, &job_id) {
+			DesignatedJobs::<T>::remove((pool_id.clone(), worker, job_id.clone()));
+		} else {
+			AssignableJobs::<T>::remove((pool_id.clone(), job.impl_spec_version, job_id.clone()));
+		}
+
+		let held_deposit = JobInputs::<T>::take(&pool_id, &job_id)
+			.map(|entry| entry.actual_deposit.saturating_add(entry.surplus_deposit))
+			.unwrap_or_default();
+		if let Some(input_data) = input.clone() {
+			let deposit = T::JobStorageDepositPerByte::get()
+				.saturating_mul((input_data.storage_len() as u32).into());
+			let surplus_deposit = if held_deposit >= deposit {
+				held_deposit.saturating_sub(deposit)
+			} else {
+				<T as Config>::Currency::hold(
+					&HoldReason::JobStorageReserve.into(),
+					&who,
+					deposit.saturating_sub(held_deposit),
+				)?;
+				Zero::zero()
+			};
+
+			let input_entry = JobData::<T::AccountId, BalanceOf<T>, T::InputLimit> {
+				depositor: who.clone(),
+				actual_deposit: deposit,
+				surplus_deposit,
+				encryption: input_encryption.clone(),
+				data: input_data,
+			};
+			JobInputs::<T>::insert(&pool_id, &job_id, input_entry);
+		} else if !held_deposit.is_zero() {
+			<T as Config>::Currency::release(
+				&HoldReason::JobStorageReserve.into(),
+				&who,
+				held_deposit,
+				Precision::BestEffort,
+			)?;
+		}
+
+		if let Some(worker) = designated_worker {
+			DesignatedJobs::<T>::insert((pool_id.clone(), worker, job_id.clone()), ());
+		} else {
+			AssignableJobs::<T>::insert(
+				(pool_id.clone(), job.impl_spec_version, job_id.clone()),
+				(),
+			);
+		}
+
+		Self::deposit_event(Event::JobInputUpdated { pool_id, job_id, input, input_encryption });
+		Ok(())
+	}
+
+	/// Release the surplus deposit of the job's input back to the depositor.
+	pub(crate) fn do_reclaim_job_surplus_deposit(
+		who: T::AccountId,
+		pool_id: T::PoolId,
+		job_id: T::JobId,
+	) -> DispatchResult {
+		JobInputs::<T>::try_mutate(&pool_id, &job_id, |input_entry| -> DispatchResult {
+			let Some(input_entry) = input_entry else {
+				return Err(Error::<T>::JobInputNotFound.into())
+			};
+			ensure!(who == input_entry.depositor, Error::<T>::NoPermission);
+
+			let amount = input_entry.surplus_deposit;
+			ensure!(!amount.is_zero(), Error::<T>::NoSurplusDeposit);
+			<T as Config>::Currency::release(
+				&HoldReason::JobStorageReserve.into(),
+				&who,
+				amount,
+				Precision::Exact,
+			)?;
+			input_entry.surplus_deposit = Zero::zero();
+
+			Self::deposit_event(Event::JobSurplusDepositReclaimed {
+				pool_id: pool_id.clone(),
+				job_id: job_id.clone(),
+				depositor: who.clone(),
+				amount,
+			});
+			Ok(())
+		})
+	}
+
+	/// Hold more deposit for the job, e.g. advanceable or metered jobs which consume
+	/// the deposit progressively.
+	pub(crate) fn do_top_up_job_deposit(
+		who: T::AccountId,
+		pool_id: T::PoolId,
+		job_id: T::JobId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Jobs::<T>::try_mutate(&pool_id, &job_id, |job| -> DispatchResult {
+			let Some(job) = job else { return Err(Error::<T>::JobNotFound.into()) };
+			ensure!(who == job.depositor, Error::<T>::NoPermission);
+			ensure!(
+				matches!(job.status, JobStatus::Pending | JobStatus::Processing),
+				Error::<T>::JobIsProcessed
+			);
+
+			<T as Config>::Currency::hold(&HoldReason::JobDepositorReserve.into(), &who, amount)?;
+			job.deposit = job.deposit.saturating_add(amount);
+
+			Self::deposit_event(Event::JobDepositToppedUp {
+				pool_id: pool_id.clone(),
+				job_id: job_id.clone(),
+				amount,
+				deposit: job.deposit,
+			});
+			Ok(())
+		})
+	}
+
 	pub(crate) fn do_destroy_job(
 		who: T::AccountId,
 		pool_id: T::PoolId,
@@ -138,13 +566,94 @@ impl<T: Config> Pallet<T> {
 		}
 
 		ensure!(
-			matches!(job.status, JobStatus::Pending | JobStatus::Processed | JobStatus::Discarded),
+			matches!(
+				job.status,
+				JobStatus::Pending |
+					JobStatus::Processed |
+					JobStatus::Discarded |
+					JobStatus::Cancelled
+			),
 			Error::<T>::JobIsProcessing
 		);
 
 		Self::do_actual_destroy_job(pool_id, job, who, reason)
 	}
 
+	/// Cancel the processing job, the worker gets the compensation defined by the policy from the
+	/// held fee, and the rest is refunded to the depositor, or to the sponsorship if the job is
+	/// sponsored.
+	pub(crate) fn do_cancel_job(
+		who: T::AccountId,
+		pool_id: T::PoolId,
+		job_id: T::JobId,
+		now: u64,
+	) -> DispatchResult {
+		let mut job = Jobs::<T>::get(&pool_id, &job_id).ok_or(Error::<T>::JobNotFound)?;
+		ensure!(who == job.depositor, Error::<T>::NoPermission);
+		ensure!(job.status == JobStatus::Processing, Error::<T>::JobIsNotProcessing);
+		let worker = job.assignee.clone().ok_or(Error::<T>::InternalError)?;
+
+		let cancellation_compensation = JobPolicies::<T>::get(&pool_id, &job.policy_id)
+			.map(|policy| policy.cancellation_compensation)
+			.unwrap_or(JobCancellationCompensation::Nothing);
+		let compensation = match cancellation_compensation {
+			JobCancellationCompensation::Nothing => Zero::zero(),
+			JobCancellationCompensation::Percent(percent) =>
+				Percent::from_percent(percent) * job.fee,
+			JobCancellationCompensation::ProRata => {
+				let processing_at = job.processing_at.unwrap_or(now);
+				let elapsed = now.saturating_sub(processing_at);
+				let duration = job.expires_at.saturating_sub(processing_at);
+				if duration.is_zero() {
+					job.fee
+				} else {
+					Perbill::from_rational(elapsed.min(duration), duration) * job.fee
+				}
+			},
+		};
+
+		if !compensation.is_zero() {
+			<T as Config>::Currency::transfer_on_hold(
+				&HoldReason::JobFeeReserve.into(),
+				&job.depositor,
+				&worker,
+				compensation,
+				Precision::BestEffort,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+		}
+		let refund = job.fee.saturating_sub(compensation);
+		<T as Config>::Currency::release(
+			&HoldReason::JobFeeReserve.into(),
+			&job.depositor,
+			refund,
+			Precision::BestEffort,
+		)?;
+		Self::refund_job_sponsorship(&pool_id, &job, refund)?;
+
+		job.fee = Zero::zero();
+		job.status = JobStatus::Cancelled;
+		job.ended_at = Some(now);
+		Jobs::<T>::insert(&pool_id, &job_id, job);
+
+		Self::release_assigned_job(&pool_id, &job_id, &worker);
+
+		Self::deposit_event(Event::JobCancelled {
+			pool_id: pool_id.clone(),
+			job_id: job_id.clone(),
+			worker,
+			compensation,
+			refund,
+		});
+		Self::deposit_event(Event::JobStatusUpdated {
+			pool_id,
+			job_id,
+			status: JobStatus::Cancelled,
+		});
+		Ok(())
+	}
+
 	pub(crate) fn do_destroy_expired_job(
 		pool_id: T::PoolId,
 		job_id: T::JobId,
@@ -165,23 +674,43 @@ impl<T: Config> Pallet<T> {
 		destroyer: T::AccountId,
 		reason: JobDestroyReason,
 	) -> DispatchResult {
-		let job_id = job.id;
-		let unique_track_id = job.unique_track_id;
+		let job_id = job.id.clone();
+		let unique_track_id = job.unique_track_id.clone();
+		let designated_worker = Self::job_designated_worker(&pool_id, &job_id);
 
-		<T as Config>::Currency::release(
+		let mut refund = <T as Config>::Currency::release(
 			&HoldReason::JobDepositorReserve.into(),
 			&job.depositor,
 			job.deposit,
 			Precision::BestEffort,
 		)?;
+		if !job.fee.is_zero() {
+			refund.saturating_accrue(<T as Config>::Currency::release(
+				&HoldReason::JobFeeReserve.into(),
+				&job.depositor,
+				job.fee,
+				Precision::BestEffort,
+			)?);
+		}
+		if let Some(callback) = &job.callback {
+			refund.saturating_accrue(<T as Config>::Currency::release(
+				&HoldReason::JobCallbackReserve.into(),
+				&job.depositor,
+				callback.prepaid_fee.saturating_add(callback.storage_deposit_limit),
+				Precision::BestEffort,
+			)?);
+		}
 		if let Some(input_entry) = JobInputs::<T>::take(&pool_id, &job_id) {
 			let deposit = input_entry.actual_deposit.saturating_add(input_entry.surplus_deposit);
-			<T as Config>::Currency::release(
+			let released = <T as Config>::Currency::release(
 				&HoldReason::JobStorageReserve.into(),
 				&input_entry.depositor,
 				deposit,
 				Precision::BestEffort,
 			)?;
+			if input_entry.depositor == job.depositor {
+				refund.saturating_accrue(released);
+			}
 		}
 		if let Some(output_entry) = JobOutputs::<T>::take(&pool_id, &job_id) {
 			let deposit = output_entry.actual_deposit.saturating_add(output_entry.surplus_deposit);
@@ -202,9 +731,12 @@ impl<T: Config> Pallet<T> {
 			)?;
 		}
 
+		Self::return_job_sponsorship(&pool_id, &job, refund)?;
+
 		if let Some(unique_track_id) = unique_track_id.clone() {
 			IndexedJobs::<T>::remove(&pool_id, unique_track_id);
 		}
+		JobWorkerRequirements::<T>::remove(&pool_id, &job_id);
 		Jobs::<T>::remove(&pool_id, &job_id);
 		Jobs::<T>::remove(&pool_id, &job_id);
 
@@ -231,18 +763,25 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		if job.status == JobStatus::Pending {
-			AssignableJobs::<T>::remove((pool_id.clone(), job.impl_spec_version, job_id.clone()));
-		} else if job.status == JobStatus::Processing || job.status == JobStatus::Discarded {
-			if let Some(worker) = &job.assignee {
-				CounterForWorkerAssignedJobs::<T>::try_mutate(
-					worker,
-					|counter| -> Result<(), DispatchError> {
-						*counter -= 1;
-						Ok(())
-					},
-				)?;
+			if let Some(worker) = designated_worker {
+				DesignatedJobs::<T>::remove((pool_id.clone(), worker, job_id.clone()));
+			} else {
+				AssignableJobs::<T>::remove((
+					pool_id.clone(),
+					job.impl_spec_version,
+					job_id.clone(),
+				));
 			}
 		}
+		// Release the worker if the job is still assigned to it
+		if let Some(worker) = &job.assignee {
+			Self::release_assigned_job(&pool_id, &job_id, worker);
+		}
+		let _ = JobFailedWorkers::<T>::clear_prefix(
+			(pool_id.clone(), job_id.clone()),
+			T::MaxJobAttempts::get(),
+			None,
+		);
 		AccountBeneficialJobs::<T>::remove((
 			job.beneficiary.clone(),
 			pool_id.clone(),
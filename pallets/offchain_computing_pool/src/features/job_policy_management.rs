@@ -33,10 +33,13 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::PolicyIdTaken
 		);
 
-		let policy = JobPolicy::<T::PolicyId, BlockNumberFor<T>> {
+		let policy = JobPolicy::<T::PolicyId, T::AccountId, BlockNumberFor<T>> {
 			id: policy_id.clone(),
 			enabled: true,
 			applicable_scope: applicable_scope.clone(),
+			rate_strategy: JobRateStrategy::Free,
+			cancellation_compensation: JobCancellationCompensation::Nothing,
+			max_attempts: 1,
 			start_block,
 			end_block,
 			jobs_count: 0,
@@ -89,6 +92,72 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_update_job_policy_rate_strategy(
+		pool_id: T::PoolId,
+		policy_id: T::PolicyId,
+		rate_strategy: JobRateStrategy<T::AccountId>,
+	) -> DispatchResult {
+		let mut policy =
+			JobPolicies::<T>::get(&pool_id, &policy_id).ok_or(Error::<T>::JobPolicyNotFound)?;
+		if let JobRateStrategy::Contract(contract) = &rate_strategy {
+			ensure!(RateContracts::<T>::contains_key(contract), Error::<T>::RateContractNotAllowed);
+		}
+		policy.rate_strategy = rate_strategy.clone();
+		JobPolicies::<T>::insert(&pool_id, &policy_id, policy);
+
+		Self::deposit_event(Event::JobPolicyRateStrategyUpdated {
+			pool_id,
+			policy_id,
+			rate_strategy,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_update_job_policy_cancellation_compensation(
+		pool_id: T::PoolId,
+		policy_id: T::PolicyId,
+		cancellation_compensation: JobCancellationCompensation,
+	) -> DispatchResult {
+		if let JobCancellationCompensation::Percent(percent) = cancellation_compensation {
+			ensure!(percent <= 100, Error::<T>::InvalidCancellationCompensation);
+		}
+
+		let mut policy =
+			JobPolicies::<T>::get(&pool_id, &policy_id).ok_or(Error::<T>::JobPolicyNotFound)?;
+		policy.cancellation_compensation = cancellation_compensation.clone();
+		JobPolicies::<T>::insert(&pool_id, &policy_id, policy);
+
+		Self::deposit_event(Event::JobPolicyCancellationCompensationUpdated {
+			pool_id,
+			policy_id,
+			cancellation_compensation,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_update_job_policy_max_attempts(
+		pool_id: T::PoolId,
+		policy_id: T::PolicyId,
+		max_attempts: u32,
+	) -> DispatchResult {
+		ensure!(
+			max_attempts > 0 && max_attempts <= T::MaxJobAttempts::get(),
+			Error::<T>::InvalidMaxAttempts
+		);
+
+		let mut policy =
+			JobPolicies::<T>::get(&pool_id, &policy_id).ok_or(Error::<T>::JobPolicyNotFound)?;
+		policy.max_attempts = max_attempts;
+		JobPolicies::<T>::insert(&pool_id, &policy_id, policy);
+
+		Self::deposit_event(Event::JobPolicyMaxAttemptsUpdated {
+			pool_id,
+			policy_id,
+			max_attempts,
+		});
+		Ok(())
+	}
+
 	pub(crate) fn do_authorize_account(
 		pool_id: T::PoolId,
 		policy_id: T::PolicyId,
@@ -0,0 +1,209 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	traits::tokens::{Fortitude, Restriction},
+};
+use scale_codec::{Decode, Encode};
+use sp_runtime::{traits::Zero, Saturating};
+
+/// The selector of the rate contract's message which calculates the fee of a job,
+/// the message is `fn job_fee(stage: JobFeeStage, pool_id, policy_id, job_id, beneficiary,
+/// result: Option<JobResult>) -> Balance`.
+pub const RATE_CONTRACT_FEE_SELECTOR: [u8; 4] = [0x4a, 0x0b, 0x5e, 0xe1];
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_add_rate_contract(contract: T::AccountId) -> DispatchResult {
+		ensure!(
+			pallet_contracts::Pallet::<T>::code_hash(&contract).is_some(),
+			Error::<T>::ContractNotFound
+		);
+		ensure!(
+			!RateContracts::<T>::contains_key(&contract),
+			Error::<T>::RateContractAlreadyAllowed
+		);
+
+		RateContracts::<T>::insert(&contract, ());
+
+		Self::deposit_event(Event::RateContractAdded { contract });
+		Ok(())
+	}
+
+	pub(crate) fn do_remove_rate_contract(contract: T::AccountId) -> DispatchResult {
+		ensure!(RateContracts::<T>::contains_key(&contract), Error::<T>::RateContractNotAllowed);
+
+		RateContracts::<T>::remove(&contract);
+
+		Self::deposit_event(Event::RateContractRemoved { contract });
+		Ok(())
+	}
+
+	/// Query the fee from the rate contract by dry-run, all changes made by the contract will be
+	/// reverted.
+	///
+	/// The gas consumed isn't charged here, callers must include `RateContractGasLimit` in their
+	/// weights.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn query_job_fee(
+		contract: &T::AccountId,
+		caller: &T::AccountId,
+		stage: JobFeeStage,
+		pool_id: &T::PoolId,
+		policy_id: &T::PolicyId,
+		job_id: &T::JobId,
+		beneficiary: &T::AccountId,
+		result: Option<JobResult>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(RateContracts::<T>::contains_key(contract), Error::<T>::RateContractNotAllowed);
+
+		let mut data = RATE_CONTRACT_FEE_SELECTOR.to_vec();
+		data.append(&mut (stage, pool_id, policy_id, job_id, beneficiary, result).encode());
+
+		let outcome = with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
+			TransactionOutcome::Rollback(Ok(pallet_contracts::Pallet::<T>::bare_call(
+				caller.clone(),
+				contract.clone(),
+				Default::default(),
+				T::RateContractGasLimit::get(),
+				None,
+				data,
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+			)))
+		})?;
+		let ret = outcome.result.map_err(|error| {
+			log!(debug, "Querying rate contract failed: {:?}", error);
+			Error::<T>::RateContractQueryFailed
+		})?;
+		ensure!(!ret.did_revert(), Error::<T>::RateContractQueryFailed);
+
+		// ink! wraps the return value of messages with `Result<T, LangError>`
+		match Result::<BalanceOf<T>, u8>::decode(&mut &ret.data[..]) {
+			Ok(Ok(fee)) => Ok(fee),
+			_ => Err(Error::<T>::RateContractQueryFailed.into()),
+		}
+	}
+
+	/// Calculate the fee of a new job.
+	pub(crate) fn quote_job_fee(
+		pool_id: &T::PoolId,
+		policy_info: &JobPolicy<T::PolicyId, T::AccountId, BlockNumberFor<T>>,
+		job_id: &T::JobId,
+		depositor: &T::AccountId,
+		beneficiary: &T::AccountId,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		match &policy_info.rate_strategy {
+			JobRateStrategy::Free => Ok(Zero::zero()),
+			JobRateStrategy::Contract(contract) => Self::query_job_fee(
+				contract,
+				depositor,
+				JobFeeStage::Creating,
+				pool_id,
+				&policy_info.id,
+				job_id,
+				beneficiary,
+				None,
+			),
+		}
+	}
+
+	/// Hold the fee of a new job from the depositor, the fee is calculated unless it's quoted.
+	pub(crate) fn hold_job_fee(
+		pool_id: &T::PoolId,
+		policy_info: &JobPolicy<T::PolicyId, T::AccountId, BlockNumberFor<T>>,
+		job_id: &T::JobId,
+		depositor: &T::AccountId,
+		beneficiary: &T::AccountId,
+		quoted_fee: Option<BalanceOf<T>>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let fee = match quoted_fee {
+			Some(fee) => fee,
+			None => Self::quote_job_fee(pool_id, policy_info, job_id, depositor, beneficiary)?,
+		};
+		if !fee.is_zero() {
+			<T as Config>::Currency::hold(&HoldReason::JobFeeReserve.into(), depositor, fee)?;
+		}
+
+		Ok(fee)
+	}
+
+	/// Pay the fee of the processed job to the worker, and return the rest to the depositor, the
+	/// rest returns to the sponsorship if the job is sponsored.
+	///
+	/// The rate contract is queried again with the result, the fee can't exceed the held one,
+	/// the held fee will be paid in full if the contract no longer available.
+	pub(crate) fn do_settle_job_fee(
+		pool_id: &T::PoolId,
+		job: &mut JobInfo<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>>,
+		worker: &T::AccountId,
+	) -> DispatchResult {
+		if job.fee.is_zero() {
+			return Ok(())
+		}
+
+		let rate_strategy =
+			JobPolicies::<T>::get(pool_id, &job.policy_id).map(|policy| policy.rate_strategy);
+		let fee = match rate_strategy {
+			Some(JobRateStrategy::Contract(contract)) => Self::query_job_fee(
+				&contract,
+				worker,
+				JobFeeStage::Settling,
+				pool_id,
+				&job.policy_id,
+				&job.id,
+				&job.beneficiary,
+				job.result.clone(),
+			)
+			.unwrap_or(job.fee),
+			_ => job.fee,
+		}
+		.min(job.fee);
+
+		if !fee.is_zero() {
+			<T as Config>::Currency::transfer_on_hold(
+				&HoldReason::JobFeeReserve.into(),
+				&job.depositor,
+				worker,
+				fee,
+				Precision::BestEffort,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+		}
+		let refund = <T as Config>::Currency::release(
+			&HoldReason::JobFeeReserve.into(),
+			&job.depositor,
+			job.fee.saturating_sub(fee),
+			Precision::BestEffort,
+		)?;
+		Self::refund_job_sponsorship(pool_id, job, refund)?;
+		job.fee = Zero::zero();
+
+		Self::deposit_event(Event::JobFeeSettled {
+			pool_id: pool_id.clone(),
+			job_id: job.id.clone(),
+			worker: worker.clone(),
+			fee,
+		});
+		Ok(())
+	}
+}
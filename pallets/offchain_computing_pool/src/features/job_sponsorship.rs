@@ -0,0 +1,267 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_runtime::{traits::Zero, Saturating};
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_fund_job_sponsorship(
+		sponsor: T::AccountId,
+		pool_id: T::PoolId,
+		policy_id: T::PolicyId,
+		amount: BalanceOf<T>,
+		allowance_per_account: BalanceOf<T>,
+	) -> DispatchResult {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Self::ensure_pool_live(&pool_info)?;
+		ensure!(
+			JobPolicies::<T>::contains_key(&pool_id, &policy_id),
+			Error::<T>::JobPolicyNotFound
+		);
+
+		// Funding nothing only updates the allowance of an existing sponsorship
+		if amount.is_zero() {
+			ensure!(
+				JobSponsorships::<T>::contains_key((
+					pool_id.clone(),
+					policy_id.clone(),
+					sponsor.clone()
+				)),
+				Error::<T>::JobSponsorshipNotFound
+			);
+		} else {
+			<T as Config>::Currency::hold(
+				&HoldReason::JobSponsorshipReserve.into(),
+				&sponsor,
+				amount,
+			)?;
+		}
+
+		let budget = JobSponsorships::<T>::mutate(
+			(pool_id.clone(), policy_id.clone(), sponsor.clone()),
+			|sponsorship| {
+				let mut new_sponsorship = sponsorship
+					.take()
+					.unwrap_or(JobSponsorship { budget: Zero::zero(), allowance_per_account });
+				new_sponsorship.budget = new_sponsorship.budget.saturating_add(amount);
+				new_sponsorship.allowance_per_account = allowance_per_account;
+
+				let budget = new_sponsorship.budget;
+				*sponsorship = Some(new_sponsorship);
+				budget
+			},
+		);
+
+		Self::deposit_event(Event::JobSponsorshipFunded {
+			pool_id,
+			policy_id,
+			sponsor,
+			amount,
+			budget,
+			allowance_per_account,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_withdraw_job_sponsorship(
+		sponsor: T::AccountId,
+		pool_id: T::PoolId,
+		policy_id: T::PolicyId,
+		amount: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let key = (pool_id.clone(), policy_id.clone(), sponsor.clone());
+		let mut sponsorship =
+			JobSponsorships::<T>::get(key.clone()).ok_or(Error::<T>::JobSponsorshipNotFound)?;
+
+		let amount = amount.unwrap_or(sponsorship.budget);
+		ensure!(amount <= sponsorship.budget, Error::<T>::InsufficientJobSponsorshipBudget);
+
+		<T as Config>::Currency::release(
+			&HoldReason::JobSponsorshipReserve.into(),
+			&sponsor,
+			amount,
+			Precision::BestEffort,
+		)?;
+
+		sponsorship.budget = sponsorship.budget.saturating_sub(amount);
+		let budget = sponsorship.budget;
+		if budget.is_zero() {
+			JobSponsorships::<T>::remove(key);
+		} else {
+			JobSponsorships::<T>::insert(key, sponsorship);
+		}
+
+		Self::deposit_event(Event::JobSponsorshipWithdrawn {
+			pool_id,
+			policy_id,
+			sponsor,
+			amount,
+			budget,
+		});
+		Ok(())
+	}
+
+	/// Create a job for `beneficiary`, the deposits and the fee are taken from the sponsorship's
+	/// budget, so the sponsor will be the depositor of the job, and the refunds return to the
+	/// budget.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn do_create_sponsored_job(
+		beneficiary: T::AccountId,
+		sponsor: T::AccountId,
+		pool_id: T::PoolId,
+		policy_id: T::PolicyId,
+		unique_track_id: Option<UniqueTrackId>,
+		impl_spec_version: ImplSpecVersion,
+		input: Option<JobPayload<T::InputLimit>>,
+		input_encryption: EncryptionScheme<T::AccountId>,
+		now: u64,
+		soft_expires_in: Option<u64>,
+	) -> DispatchResult {
+		let key = (pool_id.clone(), policy_id.clone(), sponsor.clone());
+		let mut sponsorship =
+			JobSponsorships::<T>::get(key.clone()).ok_or(Error::<T>::JobSponsorshipNotFound)?;
+
+		// Quote the fee in advance, so it counts in the budget and the allowance
+		let policy =
+			JobPolicies::<T>::get(&pool_id, &policy_id).ok_or(Error::<T>::JobPolicyNotFound)?;
+		let job_id = NextJobId::<T>::get(&pool_id).unwrap_or(1u32.into());
+		let fee = Self::quote_job_fee(&pool_id, &policy, &job_id, &sponsor, &beneficiary)?;
+		let cost = Self::calculate_job_creation_deposit(
+			input.as_ref().map(|x| x.storage_len()).unwrap_or_default(),
+		)
+		.saturating_add(fee);
+		ensure!(cost <= sponsorship.budget, Error::<T>::JobSponsorshipBudgetExhausted);
+
+		let spending_key =
+			(pool_id.clone(), policy_id.clone(), sponsor.clone(), beneficiary.clone());
+		let spent = JobSponsorshipSpending::<T>::get(spending_key.clone()).saturating_add(cost);
+		ensure!(
+			spent <= sponsorship.allowance_per_account,
+			Error::<T>::JobSponsorshipAllowanceExceeded
+		);
+
+		// Release from the budget, `do_create_job` will hold it again as the job's deposits and
+		// fee.
+		<T as Config>::Currency::release(
+			&HoldReason::JobSponsorshipReserve.into(),
+			&sponsor,
+			cost,
+			Precision::Exact,
+		)?;
+		sponsorship.budget = sponsorship.budget.saturating_sub(cost);
+		JobSponsorships::<T>::insert(key, sponsorship);
+		JobSponsorshipSpending::<T>::insert(spending_key, spent);
+
+		let job_id = Self::do_create_next_job(
+			beneficiary.clone(),
+			sponsor.clone(),
+			pool_id.clone(),
+			policy_id,
+			unique_track_id,
+			beneficiary.clone(),
+			impl_spec_version,
+			input,
+			input_encryption,
+			now,
+			soft_expires_in,
+			None,
+			None,
+			Some(fee),
+		)?;
+		SponsoredJobs::<T>::insert(&pool_id, &job_id, cost);
+
+		Self::deposit_event(Event::JobSponsored {
+			pool_id,
+			job_id,
+			sponsor,
+			beneficiary,
+			amount: cost,
+		});
+		Ok(())
+	}
+
+	/// Return the refund of a sponsored job to the sponsorship's budget, at most the amount that
+	/// the sponsorship paid for the job, and lower the beneficiary's spending accordingly.
+	/// The sponsor keeps the refund if the sponsorship has been withdrawn.
+	pub(crate) fn refund_job_sponsorship(
+		pool_id: &T::PoolId,
+		job: &JobInfo<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>>,
+		refund: BalanceOf<T>,
+	) -> DispatchResult {
+		let Some(paid) = SponsoredJobs::<T>::get(pool_id, &job.id) else { return Ok(()) };
+		let amount = refund.min(paid);
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		SponsoredJobs::<T>::insert(pool_id, &job.id, paid.saturating_sub(amount));
+		Self::lower_job_sponsorship_spending(pool_id, job, amount);
+
+		JobSponsorships::<T>::try_mutate(
+			(pool_id.clone(), job.policy_id.clone(), job.depositor.clone()),
+			|sponsorship| -> DispatchResult {
+				let Some(sponsorship) = sponsorship else { return Ok(()) };
+
+				<T as Config>::Currency::hold(
+					&HoldReason::JobSponsorshipReserve.into(),
+					&job.depositor,
+					amount,
+				)?;
+				sponsorship.budget.saturating_accrue(amount);
+
+				Ok(())
+			},
+		)
+	}
+
+	/// Return the refund of a destroyed sponsored job to the sponsorship, what the sponsorship
+	/// paid for the job no longer counts in the beneficiary's spending, so the allowance limits
+	/// the sponsored jobs alive at a time.
+	pub(crate) fn return_job_sponsorship(
+		pool_id: &T::PoolId,
+		job: &JobInfo<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>>,
+		refund: BalanceOf<T>,
+	) -> DispatchResult {
+		Self::refund_job_sponsorship(pool_id, job, refund)?;
+		if let Some(paid) = SponsoredJobs::<T>::take(pool_id, &job.id) {
+			Self::lower_job_sponsorship_spending(pool_id, job, paid);
+		}
+
+		Ok(())
+	}
+
+	fn lower_job_sponsorship_spending(
+		pool_id: &T::PoolId,
+		job: &JobInfo<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>>,
+		amount: BalanceOf<T>,
+	) {
+		JobSponsorshipSpending::<T>::mutate_exists(
+			(
+				pool_id.clone(),
+				job.policy_id.clone(),
+				job.depositor.clone(),
+				job.beneficiary.clone(),
+			),
+			|spending| {
+				let spent = spending.unwrap_or_default().saturating_sub(amount);
+				*spending = if spent.is_zero() { None } else { Some(spent) };
+			},
+		);
+	}
+}
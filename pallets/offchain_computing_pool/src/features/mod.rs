@@ -19,5 +19,10 @@
 pub mod job_lifecycle;
 pub mod job_management;
 pub mod job_policy_management;
+pub mod job_rates;
+pub mod job_sponsorship;
 pub mod pool_management;
+pub mod pool_roles;
+#[cfg(any(feature = "try-runtime", test))]
+pub mod try_state;
 pub mod worker_management;
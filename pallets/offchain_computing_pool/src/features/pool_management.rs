@@ -17,9 +17,12 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::{Fortitude, Restriction},
+};
 use sp_runtime::{traits::Zero, Saturating};
-use sp_std::cmp::Ordering;
+use sp_std::{cmp::Ordering, prelude::*};
 
 impl<T: Config> Pallet<T> {
 	pub(crate) fn do_create_pool(
@@ -55,6 +58,7 @@ impl<T: Config> Pallet<T> {
 			job_policies_count: 0,
 			jobs_count: 0,
 			workers_count: 0,
+			status: PoolStatus::Live,
 		};
 
 		Pools::<T>::insert(&pool_id, pool_info);
@@ -77,6 +81,133 @@ impl<T: Config> Pallet<T> {
 		ensure!(pool_info.jobs_count == 0, Error::<T>::PoolNotEmpty);
 		ensure!(pool_info.workers_count == 0, Error::<T>::PoolNotEmpty);
 
+		Self::do_actual_destroy_pool(pool_info)
+	}
+
+	/// Freeze the pool, then anyone could remove its jobs and workers in batches.
+	pub(crate) fn do_start_destroy_pool(who: T::AccountId, pool_id: T::PoolId) -> DispatchResult {
+		let mut pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Self::ensure_pool_owner(&who, &pool_info)?;
+		Self::ensure_pool_live(&pool_info)?;
+
+		pool_info.status = PoolStatus::Destroying;
+		pool_info.create_job_enabled = false;
+		Pools::<T>::insert(&pool_id, pool_info);
+
+		Self::deposit_event(Event::PoolDestructionStarted { pool_id });
+		Ok(())
+	}
+
+	/// Destroy at most `RemoveItemsLimit` jobs of the destroying pool, deposits are refunded.
+	/// Once the jobs are gone, the rest of the limit is spent on returning the sponsorships to
+	/// the sponsors and removing the authorized accounts of the job policies.
+	pub(crate) fn do_destroy_pool_jobs(who: T::AccountId, pool_id: T::PoolId) -> DispatchResult {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(pool_info.status == PoolStatus::Destroying, Error::<T>::PoolIsLive);
+
+		let mut remaining = T::RemoveItemsLimit::get();
+
+		let jobs = Jobs::<T>::iter_prefix_values(&pool_id)
+			.take(remaining as usize)
+			.collect::<Vec<_>>();
+		remaining = remaining.saturating_sub(jobs.len() as u32);
+		for job in jobs {
+			Self::do_actual_destroy_job(
+				pool_id.clone(),
+				job,
+				who.clone(),
+				JobDestroyReason::Force,
+			)?;
+		}
+		if remaining.is_zero() {
+			return Ok(())
+		}
+
+		let sponsorships = JobSponsorships::<T>::iter_prefix((pool_id.clone(),))
+			.take(remaining as usize)
+			.collect::<Vec<_>>();
+		remaining = remaining.saturating_sub(sponsorships.len() as u32);
+		for ((policy_id, sponsor), sponsorship) in sponsorships {
+			<T as Config>::Currency::release(
+				&HoldReason::JobSponsorshipReserve.into(),
+				&sponsor,
+				sponsorship.budget,
+				Precision::BestEffort,
+			)?;
+			JobSponsorships::<T>::remove((pool_id.clone(), policy_id.clone(), sponsor.clone()));
+
+			Self::deposit_event(Event::JobSponsorshipWithdrawn {
+				pool_id: pool_id.clone(),
+				policy_id,
+				sponsor,
+				amount: sponsorship.budget,
+				budget: Zero::zero(),
+			});
+		}
+		if remaining.is_zero() {
+			return Ok(())
+		}
+
+		// No sponsored job is alive, so the spending left should be none
+		let res = JobSponsorshipSpending::<T>::clear_prefix((pool_id.clone(),), remaining, None);
+		remaining = remaining.saturating_sub(res.unique);
+		if remaining.is_zero() {
+			return Ok(())
+		}
+
+		let _ = JobPolicyAuthorizedAccounts::<T>::clear_prefix((pool_id,), remaining, None);
+
+		Ok(())
+	}
+
+	/// Revoke at most `RemoveItemsLimit` workers of the destroying pool.
+	pub(crate) fn do_revoke_pool_workers(pool_id: T::PoolId) -> DispatchResult {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(pool_info.status == PoolStatus::Destroying, Error::<T>::PoolIsLive);
+
+		let workers = PoolWorkers::<T>::iter_key_prefix(&pool_id)
+			.take(T::RemoveItemsLimit::get() as usize)
+			.collect::<Vec<_>>();
+		for worker in workers {
+			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::do_revoke_worker(pool_info, worker)?;
+		}
+
+		Ok(())
+	}
+
+	/// Complete the destruction once all jobs, sponsorships, authorized accounts and workers
+	/// removed.
+	pub(crate) fn do_finish_destroy_pool(pool_id: T::PoolId) -> DispatchResult {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(pool_info.status == PoolStatus::Destroying, Error::<T>::PoolIsLive);
+		ensure!(pool_info.jobs_count == 0, Error::<T>::PoolNotEmpty);
+		ensure!(pool_info.workers_count == 0, Error::<T>::PoolNotEmpty);
+
+		Self::do_actual_destroy_pool(pool_info)
+	}
+
+	/// The sponsorships and the authorized accounts are not counted, so they are removed by
+	/// `do_destroy_pool_jobs` in batches, the pool can't be destroyed while any of them left.
+	pub(crate) fn do_actual_destroy_pool(
+		pool_info: PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
+	) -> DispatchResult {
+		let pool_id = pool_info.id.clone();
+		ensure!(
+			JobSponsorships::<T>::iter_prefix((pool_id.clone(),)).next().is_none(),
+			Error::<T>::PoolNotEmpty
+		);
+		ensure!(
+			JobSponsorshipSpending::<T>::iter_prefix((pool_id.clone(),)).next().is_none(),
+			Error::<T>::PoolNotEmpty
+		);
+		ensure!(
+			JobPolicyAuthorizedAccounts::<T>::iter_prefix((pool_id.clone(),))
+				.next()
+				.is_none(),
+			Error::<T>::PoolNotEmpty
+		);
+
 		if let Some(metadata_entry) = PoolMetadata::<T>::take(&pool_id) {
 			<T as Config>::Currency::release(
 				&HoldReason::PoolMetadataStorageReserve.into(),
@@ -87,6 +218,10 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let _ = JobPolicies::<T>::clear_prefix(&pool_id, pool_info.job_policies_count, None);
+		Self::clear_pool_roles(&pool_id)?;
+		PoolMinWorkerDeposits::<T>::remove(&pool_id);
+		NextJobPolicyId::<T>::remove(&pool_id);
+		NextJobId::<T>::remove(&pool_id);
 
 		Pools::<T>::remove(&pool_id);
 		AccountOwningPools::<T>::remove(&pool_info.owner, &pool_id);
@@ -102,6 +237,98 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfer the pool to the new owner, who must have accepted the ownership.
+	/// The deposits are moved to the new owner.
+	pub(crate) fn do_transfer_pool_ownership(
+		who: T::AccountId,
+		pool_id: T::PoolId,
+		new_owner: T::AccountId,
+	) -> DispatchResult {
+		let acceptable_pool = PoolOwnershipAcceptance::<T>::get(&new_owner);
+		ensure!(acceptable_pool.as_ref() == Some(&pool_id), Error::<T>::Unaccepted);
+
+		let mut pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Self::ensure_pool_owner(&who, &pool_info)?;
+		Self::ensure_pool_live(&pool_info)?;
+		if pool_info.owner == new_owner {
+			return Ok(())
+		}
+
+		<T as Config>::Currency::transfer_on_hold(
+			&HoldReason::PoolCreationReserve.into(),
+			&pool_info.owner,
+			&new_owner,
+			pool_info.owner_deposit,
+			Precision::BestEffort,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+
+		PoolMetadata::<T>::try_mutate(&pool_id, |maybe_metadata_entry| -> DispatchResult {
+			let Some(metadata_entry) = maybe_metadata_entry else { return Ok(()) };
+
+			<T as Config>::Currency::transfer_on_hold(
+				&HoldReason::PoolMetadataStorageReserve.into(),
+				&pool_info.owner,
+				&new_owner,
+				metadata_entry.actual_deposit,
+				Precision::BestEffort,
+				Restriction::OnHold,
+				Fortitude::Polite,
+			)?;
+			metadata_entry.depositor = new_owner.clone();
+
+			Ok(())
+		})?;
+
+		AccountOwningPools::<T>::remove(&pool_info.owner, &pool_id);
+		AccountOwningPools::<T>::insert(&new_owner, &pool_id, ());
+
+		pool_info.owner = new_owner.clone();
+		Pools::<T>::insert(&pool_id, pool_info);
+
+		PoolOwnershipAcceptance::<T>::remove(&new_owner);
+		frame_system::Pallet::<T>::dec_consumers(&new_owner);
+
+		// The team was appointed by the previous owner, the new owner sets up its own
+		Self::clear_pool_roles(&pool_id)?;
+
+		Self::deposit_event(Event::PoolOwnerChanged { pool_id: pool_id.clone(), new_owner });
+		Self::deposit_event(Event::PoolTeamChanged {
+			pool_id,
+			admin: None,
+			worker_manager: None,
+			policy_manager: None,
+			finance: None,
+		});
+		Ok(())
+	}
+
+	/// Set or unset the pool that the account is willing to accept the ownership of.
+	pub(crate) fn do_accept_pool_ownership(
+		who: T::AccountId,
+		maybe_pool_id: Option<T::PoolId>,
+	) -> DispatchResult {
+		let exists = PoolOwnershipAcceptance::<T>::contains_key(&who);
+		match (exists, maybe_pool_id.is_some()) {
+			(false, true) => {
+				frame_system::Pallet::<T>::inc_consumers(&who)?;
+			},
+			(true, false) => {
+				frame_system::Pallet::<T>::dec_consumers(&who);
+			},
+			_ => {},
+		}
+		if let Some(pool_id) = maybe_pool_id.as_ref() {
+			PoolOwnershipAcceptance::<T>::insert(&who, pool_id);
+		} else {
+			PoolOwnershipAcceptance::<T>::remove(&who);
+		}
+
+		Self::deposit_event(Event::PoolOwnershipAcceptanceChanged { who, maybe_pool_id });
+		Ok(())
+	}
+
 	pub(crate) fn do_update_pool_metadata(
 		pool_info: PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
 		new_metadata: BoundedVec<u8, T::PoolMetadataLimit>,
@@ -172,6 +399,7 @@ impl<T: Config> Pallet<T> {
 		create_job_enabled: bool,
 		auto_destroy_processed_job_enabled: bool,
 	) -> DispatchResult {
+		Self::ensure_pool_live(&pool_info)?;
 		ensure!(
 			max_impl_spec_version >= min_impl_spec_version,
 			Error::<T>::InvalidImplSpecVersionRange
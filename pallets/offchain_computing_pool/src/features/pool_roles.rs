@@ -0,0 +1,92 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+
+impl<T: Config> Pallet<T> {
+	/// Set the team roles of the pool, the previous roles will be cleared.
+	///
+	/// - `maybe_check_owner`: The account to check the ownership, `None` is considered as the root.
+	pub(crate) fn do_set_pool_team(
+		maybe_check_owner: Option<T::AccountId>,
+		pool_id: T::PoolId,
+		admin: Option<T::AccountId>,
+		worker_manager: Option<T::AccountId>,
+		policy_manager: Option<T::AccountId>,
+		finance: Option<T::AccountId>,
+	) -> DispatchResult {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		if let Some(check_owner) = maybe_check_owner {
+			Self::ensure_pool_owner(&check_owner, &pool_info)?;
+		}
+
+		let roles = [
+			(admin.clone(), PoolRole::Admin),
+			(worker_manager.clone(), PoolRole::WorkerManager),
+			(policy_manager.clone(), PoolRole::PolicyManager),
+			(finance.clone(), PoolRole::Finance),
+		]
+		.into_iter()
+		.filter_map(|(account, role)| account.map(|account| (account, role)))
+		.collect();
+
+		let account_to_roles = Self::group_pool_roles_by_account(roles);
+
+		// Delete the previous records.
+		Self::clear_pool_roles(&pool_id)?;
+
+		// Insert new records.
+		for (account, roles) in account_to_roles {
+			PoolRoleOf::<T>::insert(&pool_id, &account, roles);
+		}
+
+		Self::deposit_event(Event::PoolTeamChanged {
+			pool_id,
+			admin,
+			worker_manager,
+			policy_manager,
+			finance,
+		});
+		Ok(())
+	}
+
+	/// Clears all the roles of the pool.
+	pub(crate) fn clear_pool_roles(pool_id: &T::PoolId) -> DispatchResult {
+		let res = PoolRoleOf::<T>::clear_prefix(pool_id, PoolRoles::max_roles() as u32, None);
+		ensure!(res.maybe_cursor.is_none(), Error::<T>::RolesNotCleared);
+
+		Ok(())
+	}
+
+	/// Returns true if the account has the role in the pool.
+	pub fn has_pool_role(pool_id: &T::PoolId, who: &T::AccountId, role: PoolRole) -> bool {
+		PoolRoleOf::<T>::get(pool_id, who).map_or(false, |roles| roles.has_role(role))
+	}
+
+	/// Groups the roles by account, given one account could have multiple roles.
+	pub fn group_pool_roles_by_account(
+		input: Vec<(T::AccountId, PoolRole)>,
+	) -> Vec<(T::AccountId, PoolRoles)> {
+		let mut result = BTreeMap::new();
+		for (account, role) in input.into_iter() {
+			result.entry(account).or_insert(PoolRoles::none()).add_role(role);
+		}
+		result.into_iter().collect()
+	}
+}
@@ -0,0 +1,179 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_runtime::{Saturating, TryRuntimeError};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+/// Add the amount to the account's expected held balance.
+fn accrue<AccountId: Ord, Balance: Saturating + Default>(
+	deposits: &mut BTreeMap<AccountId, Balance>,
+	who: AccountId,
+	amount: Balance,
+) {
+	deposits.entry(who).or_default().saturating_accrue(amount);
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check the invariants of the pallet's storage, it iterates all pools, jobs and workers,
+	/// so only use it in tests and `try-runtime` checks.
+	///
+	/// - The denormalized counters equal to the numbers recomputed from the underlying maps.
+	/// - The held deposits of pools, jobs, fees, callbacks and sponsorships equal to the recorded
+	///   ones, and the accounts known to the pallet hold nothing else of the pallet's hold reasons.
+	pub fn do_try_state() -> Result<(), TryRuntimeError> {
+		ensure!(Pools::<T>::iter().count() as u32 == Pools::<T>::count(), "Pools count mismatch");
+
+		let mut creation_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		let mut metadata_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		for pool_info in Pools::<T>::iter_values() {
+			let pool_id = pool_info.id.clone();
+			ensure!(
+				pool_info.job_policies_count ==
+					JobPolicies::<T>::iter_prefix(&pool_id).count() as u32,
+				"Pool's job policies count mismatch"
+			);
+			ensure!(
+				pool_info.jobs_count == Jobs::<T>::iter_prefix(&pool_id).count() as u32,
+				"Pool's jobs count mismatch"
+			);
+			ensure!(
+				pool_info.workers_count == PoolWorkers::<T>::iter_prefix(&pool_id).count() as u32,
+				"Pool's workers count mismatch"
+			);
+
+			let mut policy_jobs_count = BTreeMap::<T::PolicyId, u32>::new();
+			for job in Jobs::<T>::iter_prefix_values(&pool_id) {
+				*policy_jobs_count.entry(job.policy_id).or_default() += 1;
+			}
+			for policy in JobPolicies::<T>::iter_prefix_values(&pool_id) {
+				ensure!(
+					policy.jobs_count ==
+						policy_jobs_count.get(&policy.id).copied().unwrap_or_default(),
+					"Job policy's jobs count mismatch"
+				);
+			}
+
+			accrue(&mut creation_deposits, pool_info.owner, pool_info.owner_deposit);
+			if let Some(metadata_entry) = PoolMetadata::<T>::get(&pool_id) {
+				accrue(
+					&mut metadata_deposits,
+					metadata_entry.depositor,
+					metadata_entry.actual_deposit,
+				);
+			}
+		}
+
+		let mut job_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		let mut callback_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		let mut fee_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		for job in Jobs::<T>::iter_values() {
+			if let Some(callback) = job.callback {
+				let deposit = callback.prepaid_fee.saturating_add(callback.storage_deposit_limit);
+				accrue(&mut callback_deposits, job.depositor.clone(), deposit);
+			}
+			accrue(&mut fee_deposits, job.depositor.clone(), job.fee);
+			accrue(&mut job_deposits, job.depositor, job.deposit);
+		}
+		let mut storage_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		for input_entry in JobInputs::<T>::iter_values() {
+			let deposit = input_entry.actual_deposit.saturating_add(input_entry.surplus_deposit);
+			accrue(&mut storage_deposits, input_entry.depositor, deposit);
+		}
+		for output_entry in JobOutputs::<T>::iter_values() {
+			let deposit = output_entry.actual_deposit.saturating_add(output_entry.surplus_deposit);
+			accrue(&mut storage_deposits, output_entry.depositor, deposit);
+		}
+		for proof_entry in JobProofs::<T>::iter_values() {
+			let deposit = proof_entry.actual_deposit.saturating_add(proof_entry.surplus_deposit);
+			accrue(&mut storage_deposits, proof_entry.depositor, deposit);
+		}
+		let mut sponsorship_deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		for ((_, _, sponsor), sponsorship) in JobSponsorships::<T>::iter() {
+			accrue(&mut sponsorship_deposits, sponsor, sponsorship.budget);
+		}
+		for (pool_id, job_id) in SponsoredJobs::<T>::iter_keys() {
+			ensure!(Jobs::<T>::contains_key(&pool_id, &job_id), "Sponsored job not found");
+		}
+
+		let held_deposits = [
+			(HoldReason::PoolCreationReserve, creation_deposits),
+			(HoldReason::PoolMetadataStorageReserve, metadata_deposits),
+			(HoldReason::JobDepositorReserve, job_deposits),
+			(HoldReason::JobCallbackReserve, callback_deposits),
+			(HoldReason::JobFeeReserve, fee_deposits),
+			(HoldReason::JobStorageReserve, storage_deposits),
+			(HoldReason::JobSponsorshipReserve, sponsorship_deposits),
+		];
+		// Check every known account against every reason, so the accounts hold nothing without
+		// a record, and the held sum of each reason equals to the recorded sum
+		let accounts = held_deposits
+			.iter()
+			.flat_map(|(_, deposits)| deposits.keys().cloned())
+			.collect::<BTreeSet<_>>();
+		for (reason, deposits) in held_deposits {
+			let reason: <T as Config>::RuntimeHoldReason = reason.into();
+			for who in accounts.iter() {
+				ensure!(
+					<T as Config>::Currency::balance_on_hold(&reason, who) ==
+						deposits.get(who).copied().unwrap_or_default(),
+					"Held deposit mismatch"
+				);
+			}
+		}
+
+		let mut assigned_jobs_count = BTreeMap::<T::AccountId, u32>::new();
+		for (worker, _, _) in WorkerAssignedJobs::<T>::iter_keys() {
+			*assigned_jobs_count.entry(worker).or_default() += 1;
+		}
+		for (worker, count) in CounterForWorkerAssignedJobs::<T>::iter() {
+			ensure!(
+				assigned_jobs_count.remove(&worker).unwrap_or_default() == count,
+				"Worker's assigned jobs count mismatch"
+			);
+		}
+		ensure!(assigned_jobs_count.is_empty(), "Worker's assigned jobs aren't counted");
+
+		let mut subscribed_pools_count = BTreeMap::<T::AccountId, u32>::new();
+		for (worker, _) in WorkerSubscribedPools::<T>::iter_keys() {
+			*subscribed_pools_count.entry(worker).or_default() += 1;
+		}
+		for (worker, count) in CounterForWorkerSubscribedPools::<T>::iter() {
+			ensure!(
+				subscribed_pools_count.remove(&worker).unwrap_or_default() == count,
+				"Worker's subscribed pools count mismatch"
+			);
+		}
+		ensure!(subscribed_pools_count.is_empty(), "Worker's subscribed pools aren't counted");
+
+		let mut added_pools_count = BTreeMap::<T::AccountId, u32>::new();
+		for (worker, _) in PoolAuthorizedWorkers::<T>::iter_keys() {
+			*added_pools_count.entry(worker).or_default() += 1;
+		}
+		for (worker, count) in CounterForWorkerAddedPools::<T>::iter() {
+			ensure!(
+				added_pools_count.remove(&worker).unwrap_or_default() == count,
+				"Worker's added pools count mismatch"
+			);
+		}
+		ensure!(added_pools_count.is_empty(), "Worker's added pools aren't counted");
+
+		Ok(())
+	}
+}
@@ -24,6 +24,7 @@ impl<T: Config> Pallet<T> {
 		pool_info: PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
 		worker: T::AccountId,
 	) -> DispatchResult {
+		Self::ensure_pool_live(&pool_info)?;
 		ensure!(
 			!PoolAuthorizedWorkers::<T>::contains_key(&worker, &pool_info.id),
 			Error::<T>::WorkerAlreadyAuthorized
@@ -32,8 +33,11 @@ impl<T: Config> Pallet<T> {
 		let worker_info =
 			PalletInfra::<T>::worker_info(&worker).ok_or(Error::<T>::WorkerNotFound)?;
 		ensure!(worker_info.impl_id == pool_info.impl_id.clone(), Error::<T>::ImplMismatched);
+		Self::ensure_pool_accepts_worker_deposit(&pool_info.id, worker_info.deposit)?;
 
 		PoolAuthorizedWorkers::<T>::insert(&worker, &pool_info.id, ());
+		PoolWorkers::<T>::insert(&pool_info.id, &worker, ());
+		CounterForWorkerAddedPools::<T>::mutate(&worker, |counter| *counter += 1);
 
 		let mut new_pool_info = pool_info.clone();
 		new_pool_info.workers_count += 1;
@@ -57,6 +61,9 @@ impl<T: Config> Pallet<T> {
 
 		if WorkerSubscribedPools::<T>::contains_key(&worker, &pool_info.id) {
 			WorkerSubscribedPools::<T>::remove(&worker, &pool_info.id);
+			CounterForWorkerSubscribedPools::<T>::mutate(&worker, |counter| {
+				*counter = counter.saturating_sub(1);
+			});
 
 			Self::deposit_event(Event::WorkerUnsubscribed {
 				worker: worker.clone(),
@@ -65,6 +72,10 @@ impl<T: Config> Pallet<T> {
 		}
 
 		PoolAuthorizedWorkers::<T>::remove(&worker, &pool_info.id);
+		PoolWorkers::<T>::remove(&pool_info.id, &worker);
+		CounterForWorkerAddedPools::<T>::mutate(&worker, |counter| {
+			*counter = counter.saturating_sub(1);
+		});
 
 		let mut new_pool_info = pool_info.clone();
 		new_pool_info.workers_count -= 1;
@@ -75,6 +86,8 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub(crate) fn do_subscribe_pool(worker: T::AccountId, pool_id: T::PoolId) -> DispatchResult {
+		let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Self::ensure_pool_live(&pool_info)?;
 		ensure!(
 			PoolAuthorizedWorkers::<T>::contains_key(&worker, &pool_id),
 			Error::<T>::WorkerNotInThePool
@@ -83,6 +96,9 @@ impl<T: Config> Pallet<T> {
 			!WorkerSubscribedPools::<T>::contains_key(&worker, &pool_id),
 			Error::<T>::WorkerAlreadySubscribed
 		);
+		let worker_info =
+			PalletInfra::<T>::worker_info(&worker).ok_or(Error::<T>::WorkerNotFound)?;
+		Self::ensure_pool_accepts_worker_deposit(&pool_id, worker_info.deposit)?;
 
 		let subscribed_pools_count = CounterForWorkerSubscribedPools::<T>::get(&worker);
 		ensure!(
@@ -104,8 +120,118 @@ impl<T: Config> Pallet<T> {
 		);
 
 		WorkerSubscribedPools::<T>::remove(&worker, &pool_id);
+		CounterForWorkerSubscribedPools::<T>::mutate(&worker, |counter| {
+			*counter = counter.saturating_sub(1);
+		});
 
 		Self::deposit_event(Event::WorkerUnsubscribed { worker, pool_id });
 		Ok(())
 	}
+
+	/// Save the capabilities declared in the worker's online payload,
+	/// they're attested if the attestation carries the payload's signature.
+	pub(crate) fn update_worker_capabilities(
+		worker: &T::AccountId,
+		payload: &OnlinePayload<T::ImplId>,
+		verified_attestation: &VerifiedAttestation,
+	) {
+		let max_job_slots = T::MaxAssignedJobsPerWorker::get();
+		let capability_set = WorkerCapabilitySet {
+			capabilities: payload.capabilities.clone(),
+			attested: verified_attestation.payload().is_some(),
+			job_slots: payload.job_slots.unwrap_or(max_job_slots).min(max_job_slots),
+		};
+		WorkerDeclaredCapabilities::<T>::insert(worker, capability_set.clone());
+
+		Self::deposit_event(Event::WorkerCapabilitiesUpdated {
+			worker: worker.clone(),
+			capabilities: capability_set.capabilities,
+			attested: capability_set.attested,
+			job_slots: capability_set.job_slots,
+		});
+	}
+
+	/// The worker's declared job slots, capped by `MaxAssignedJobsPerWorker`.
+	pub fn worker_job_slots(worker: &T::AccountId) -> u32 {
+		WorkerDeclaredCapabilities::<T>::get(worker)
+			.map(|capability_set| capability_set.job_slots)
+			.unwrap_or(T::MaxAssignedJobsPerWorker::get())
+	}
+
+	/// How many more jobs the worker could take.
+	pub fn worker_free_job_slots(worker: &T::AccountId) -> u32 {
+		Self::worker_job_slots(worker)
+			.saturating_sub(CounterForWorkerAssignedJobs::<T>::get(worker))
+	}
+
+	/// Check the worker meets the job's requirements, jobs without requirements match any worker.
+	pub(crate) fn worker_meets_job_requirements(
+		pool_id: &T::PoolId,
+		job_id: &T::JobId,
+		capability_set: &WorkerCapabilitySet,
+	) -> bool {
+		JobWorkerRequirements::<T>::get(pool_id, job_id)
+			.map(|requirements| requirements.is_satisfied_by(capability_set))
+			.unwrap_or(true)
+	}
+
+	/// Check the subscribed pools still accept the spec version,
+	/// and the assigned jobs could still be processed with it.
+	pub(crate) fn ensure_worker_spec_version_acceptable(
+		worker: &T::AccountId,
+		impl_spec_version: ImplSpecVersion,
+	) -> DispatchResult {
+		for pool_id in WorkerSubscribedPools::<T>::iter_key_prefix(worker) {
+			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(
+				impl_spec_version >= pool_info.min_impl_spec_version &&
+					impl_spec_version <= pool_info.max_impl_spec_version,
+				Error::<T>::UnsupportedImplSpecVersion
+			);
+		}
+
+		for (pool_id, job_id) in WorkerAssignedJobs::<T>::iter_key_prefix((worker.clone(),)) {
+			let job = Jobs::<T>::get(&pool_id, &job_id).ok_or(Error::<T>::JobNotFound)?;
+			ensure!(job.impl_spec_version == impl_spec_version, Error::<T>::ImplMismatched);
+		}
+
+		Ok(())
+	}
+
+	pub(crate) fn do_set_pool_min_worker_deposit(
+		pool_info: PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
+		min_worker_deposit: Option<pallet_offchain_computing_infra::BalanceOf<T>>,
+	) -> DispatchResult {
+		Self::ensure_pool_live(&pool_info)?;
+
+		PoolMinWorkerDeposits::<T>::set(&pool_info.id, min_worker_deposit);
+
+		Self::deposit_event(Event::PoolMinWorkerDepositUpdated {
+			pool_id: pool_info.id,
+			min_worker_deposit,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn ensure_pool_accepts_worker_deposit(
+		pool_id: &T::PoolId,
+		deposit: pallet_offchain_computing_infra::BalanceOf<T>,
+	) -> DispatchResult {
+		if let Some(min_worker_deposit) = PoolMinWorkerDeposits::<T>::get(pool_id) {
+			ensure!(deposit >= min_worker_deposit, Error::<T>::WorkerDepositTooLow);
+		}
+
+		Ok(())
+	}
+
+	/// Check the subscribed pools still accept the worker's deposit.
+	pub(crate) fn ensure_worker_deposit_acceptable(worker: &T::AccountId) -> DispatchResult {
+		let worker_info =
+			PalletInfra::<T>::worker_info(worker).ok_or(Error::<T>::WorkerNotFound)?;
+		for pool_id in WorkerSubscribedPools::<T>::iter_key_prefix(worker) {
+			Self::ensure_pool_accepts_worker_deposit(&pool_id, worker_info.deposit)?;
+		}
+
+		Ok(())
+	}
 }
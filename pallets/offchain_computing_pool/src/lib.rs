@@ -18,15 +18,25 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod chain_extension;
 mod features;
+pub mod migrations;
+mod types;
+pub mod weights;
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use chain_extension::*;
 pub use pallet::*;
 pub use primitives::*;
+pub use types::*;
+pub use weights::WeightInfo;
 
 /// The log target of this pallet.
 pub const LOG_TARGET: &str = "runtime::offchain_computing-pool";
@@ -47,7 +57,7 @@ use frame_support::{
 	transactional,
 };
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, StaticLookup},
+	traits::{AtLeast32BitUnsigned, IdentifyAccount, StaticLookup, Verify},
 	SaturatedConversion,
 };
 
@@ -65,11 +75,12 @@ pub(crate) use pallet_offchain_computing_infra::OffchainWorkerLifecycleHooks;
 
 pub(crate) type PalletInfra<T> = pallet_offchain_computing_infra::Pallet<T>;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub use benchmarking::BenchmarkHelper;
+
 pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as InspectFungible<<T as frame_system::Config>::AccountId>>::Balance;
-pub type ContractBalanceOf<T> =
-	<<T as pallet_contracts::Config>::Currency as InspectFungible<<T as frame_system::Config>::AccountId>>::Balance;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -79,7 +90,7 @@ pub mod pallet {
 	use sp_std::{fmt::Display, prelude::*};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -106,6 +117,7 @@ pub mod pallet {
 		type PoolId: Member
 			+ Parameter
 			+ MaxEncodedLen
+			+ MaybeSerializeDeserialize
 			+ Display
 			+ AtLeast32BitUnsigned
 			+ Incrementable;
@@ -122,6 +134,7 @@ pub mod pallet {
 		type PolicyId: Member
 			+ Parameter
 			+ MaxEncodedLen
+			+ MaybeSerializeDeserialize
 			+ Display
 			+ AtLeast32BitUnsigned
 			+ Incrementable;
@@ -149,7 +162,7 @@ pub mod pallet {
 		#[pallet::constant]
 		type PoolMetadataDepositPerByte: Get<BalanceOf<Self>>;
 
-		/// The limit of jobs a worker could be assigned
+		/// The limit of jobs a worker could be assigned, workers could declare fewer job slots
 		#[pallet::constant]
 		type MaxAssignedJobsPerWorker: Get<u32>;
 
@@ -169,6 +182,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxWorkersPerPool: Get<u32>;
 
+		/// The limit of jobs scanned when taking a job without specifying one
+		#[pallet::constant]
+		type TakeJobScanLimit: Get<u32>;
+
 		/// The min `expires_in` can be set
 		#[pallet::constant]
 		type MinJobExpiresIn: Get<u64>;
@@ -197,16 +214,45 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProofLimit: Get<u32>;
 
-		// TODO: Support to create new job by off-chain pre-sign message
-		// /// Off-Chain signature type.
-		// ///
-		// /// Can verify whether an `Self::OffchainPublic` created a signature.
-		// type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
-		//
-		// /// Off-Chain public key.
-		// ///
-		// /// Must identify as an on-chain `Self::AccountId`.
-		// type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// The maximum gas limit of a job's callback.
+		#[pallet::constant]
+		type MaxJobCallbackGasLimit: Get<Weight>;
+
+		/// The maximum number of jobs or workers removed in a single call of destroying a pool.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
+		/// The maximum attempts of a job, including retries.
+		#[pallet::constant]
+		type MaxJobAttempts: Get<u32>;
+
+		/// The gas limit of querying a rate contract.
+		#[pallet::constant]
+		type RateContractGasLimit: Get<Weight>;
+
+		/// The origin which may whitelist rate contracts.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Off-Chain signature type.
+		///
+		/// Can verify whether an `Self::OffchainPublic` created a signature.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Off-Chain public key.
+		///
+		/// Must identify as an on-chain `Self::AccountId`.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Weight information for extrinsic calls in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// A set of helper functions for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::OffchainPublic,
+			Self::AccountId,
+			Self::OffchainSignature,
+		>;
 	}
 
 	#[pallet::event]
@@ -230,6 +276,25 @@ pub mod pallet {
 		PoolMetadataRemoved {
 			pool_id: T::PoolId,
 		},
+		PoolTeamChanged {
+			pool_id: T::PoolId,
+			admin: Option<T::AccountId>,
+			worker_manager: Option<T::AccountId>,
+			policy_manager: Option<T::AccountId>,
+			finance: Option<T::AccountId>,
+		},
+		PoolOwnershipAcceptanceChanged {
+			who: T::AccountId,
+			maybe_pool_id: Option<T::PoolId>,
+		},
+		PoolOwnerChanged {
+			pool_id: T::PoolId,
+			new_owner: T::AccountId,
+		},
+		PoolMinWorkerDepositUpdated {
+			pool_id: T::PoolId,
+			min_worker_deposit: Option<pallet_offchain_computing_infra::BalanceOf<T>>,
+		},
 		PoolSettingsUpdated {
 			pool_id: T::PoolId,
 			min_impl_spec_version: ImplSpecVersion,
@@ -276,6 +341,12 @@ pub mod pallet {
 			worker: T::AccountId,
 			pool_id: T::PoolId,
 		},
+		WorkerCapabilitiesUpdated {
+			worker: T::AccountId,
+			capabilities: WorkerCapabilities,
+			attested: bool,
+			job_slots: u32,
+		},
 		WorkerUnsubscribed {
 			worker: T::AccountId,
 			pool_id: T::PoolId,
@@ -288,7 +359,9 @@ pub mod pallet {
 			depositor: T::AccountId,
 			beneficiary: T::AccountId,
 			impl_spec_version: ImplSpecVersion,
-			input: Option<BoundedVec<u8, T::InputLimit>>,
+			input: Option<JobPayload<T::InputLimit>>,
+			input_encryption: EncryptionScheme<T::AccountId>,
+			requirements: Option<JobRequirements>,
 			expires_in: u64,
 		},
 		JobDestroyed {
@@ -317,9 +390,105 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			job_id: T::JobId,
 			result: JobResult,
-			output: Option<BoundedVec<u8, T::OutputLimit>>,
+			output: Option<JobPayload<T::OutputLimit>>,
+			/// The worker's commitment to the output
+			output_hash: Option<DataHash>,
+			output_encryption: EncryptionScheme<T::AccountId>,
 			proof: Option<BoundedVec<u8, T::ProofLimit>>,
 		},
+		RateContractAdded {
+			contract: T::AccountId,
+		},
+		RateContractRemoved {
+			contract: T::AccountId,
+		},
+		JobPolicyCancellationCompensationUpdated {
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			cancellation_compensation: JobCancellationCompensation,
+		},
+		PoolDestructionStarted {
+			pool_id: T::PoolId,
+		},
+		JobPolicyMaxAttemptsUpdated {
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			max_attempts: u32,
+		},
+		JobRetried {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			failed_worker: T::AccountId,
+			attempts: u32,
+		},
+		JobCancelled {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			worker: T::AccountId,
+			compensation: BalanceOf<T>,
+			refund: BalanceOf<T>,
+		},
+		JobPolicyRateStrategyUpdated {
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			rate_strategy: JobRateStrategy<T::AccountId>,
+		},
+		JobFeeSettled {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			worker: T::AccountId,
+			fee: BalanceOf<T>,
+		},
+		JobCallbackExecuted {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			gas_consumed: Weight,
+		},
+		JobCallbackFailed {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			error: DispatchError,
+		},
+		JobInputUpdated {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			input: Option<JobPayload<T::InputLimit>>,
+			input_encryption: EncryptionScheme<T::AccountId>,
+		},
+		JobSurplusDepositReclaimed {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			depositor: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		JobDepositToppedUp {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			amount: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+		},
+		JobSponsorshipFunded {
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			sponsor: T::AccountId,
+			amount: BalanceOf<T>,
+			budget: BalanceOf<T>,
+			allowance_per_account: BalanceOf<T>,
+		},
+		JobSponsorshipWithdrawn {
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			sponsor: T::AccountId,
+			amount: BalanceOf<T>,
+			budget: BalanceOf<T>,
+		},
+		JobSponsored {
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			sponsor: T::AccountId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -330,9 +499,19 @@ pub mod pallet {
 		PolicyIdTaken,
 		JobIdTaken,
 		PoolNotEmpty,
+		/// The pool is being destroyed.
+		PoolIsDestroying,
+		/// The pool isn't being destroyed.
+		PoolIsLive,
 		NoPermission,
+		/// The new owner hasn't accepted the pool's ownership.
+		Unaccepted,
+		/// Some roles were not cleared.
+		RolesNotCleared,
 		WorkerNotFound,
 		WorkerNotInThePool,
+		/// The worker's deposit is lower than the pool's minimum worker deposit.
+		WorkerDepositTooLow,
 		WorkerNotSubscribeThePool,
 		PoolNotFound,
 		PoolCreateNewJobUnavailable,
@@ -356,12 +535,62 @@ pub mod pallet {
 		UniqueTrackIdNotUnique,
 		JobIsProcessing,
 		JobIsProcessed,
+		/// Only the processing job could be cancelled.
+		JobIsNotProcessing,
+		/// The compensation percent can't exceed 100.
+		InvalidCancellationCompensation,
+		/// The max attempts must be in `1..=MaxJobAttempts`.
+		InvalidMaxAttempts,
+		/// The worker failed the job before.
+		WorkerAlreadyFailedJob,
 		JobAssigneeLocked,
 		JobStillValid,
 		JobExpired,
 		JobAlreadyAssigned,
 		UnsupportedImplSpecVersion,
 		InvalidImplSpecVersionRange,
+		/// The provided signature is incorrect.
+		WrongSignature,
+		/// The pre-signed message's deadline has passed.
+		DeadlineExpired,
+		/// The pre-signed message is restricted to another relayer.
+		WrongRelayer,
+		/// The pre-signed message's nonce mismatched, it may be replayed.
+		InvalidNonce,
+		/// The input is longer than `InputLimit`.
+		InputTooLong,
+		JobSponsorshipNotFound,
+		/// The sponsorship's remaining budget can't afford the job.
+		JobSponsorshipBudgetExhausted,
+		/// The account has spent all its allowance of the sponsorship.
+		JobSponsorshipAllowanceExceeded,
+		/// Withdrawing more than the remaining budget.
+		InsufficientJobSponsorshipBudget,
+		/// The encryption scheme isn't applicable for the data.
+		UnsupportedEncryptionScheme,
+		/// The worker hasn't published an encryption key.
+		WorkerEncryptionKeyNotFound,
+		/// The job's input is encrypted to another worker.
+		JobEncryptedForAnotherWorker,
+		/// The worker doesn't meet the job's requirements.
+		WorkerCapabilitiesMismatched,
+		/// The reference to the off-chain data is malformed.
+		InvalidDataReference,
+		JobInputNotFound,
+		/// There is no surplus deposit to reclaim.
+		NoSurplusDeposit,
+		/// The callback's contract doesn't exist.
+		JobCallbackContractNotFound,
+		/// The callback's gas limit exceeds `MaxJobCallbackGasLimit`.
+		JobCallbackGasLimitExceeded,
+		/// The contract doesn't exist.
+		ContractNotFound,
+		/// The contract isn't whitelisted as a rate contract.
+		RateContractNotAllowed,
+		/// The contract is already whitelisted as a rate contract.
+		RateContractAlreadyAllowed,
+		/// The rate contract reverted or returned an unexpected value.
+		RateContractQueryFailed,
 	}
 
 	#[pallet::composite_enum]
@@ -370,6 +599,9 @@ pub mod pallet {
 		PoolMetadataStorageReserve,
 		JobDepositorReserve,
 		JobStorageReserve,
+		JobSponsorshipReserve,
+		JobCallbackReserve,
+		JobFeeReserve,
 	}
 
 	/// Pools info.
@@ -399,7 +631,7 @@ pub mod pallet {
 		T::PoolId,
 		Blake2_128Concat,
 		T::PolicyId,
-		JobPolicy<T::PolicyId, BlockNumberFor<T>>,
+		JobPolicy<T::PolicyId, T::AccountId, BlockNumberFor<T>>,
 		OptionQuery,
 	>;
 
@@ -428,6 +660,28 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The minimum deposit that workers must hold to be authorized by or subscribe the pool.
+	#[pallet::storage]
+	pub type PoolMinWorkerDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		pallet_offchain_computing_infra::BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// The workers authorized by the pool, the reverse index of `PoolAuthorizedWorkers`.
+	#[pallet::storage]
+	pub type PoolWorkers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	pub type WorkerSubscribedPools<T: Config> = StorageDoubleMap<
 		_,
@@ -462,6 +716,23 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The requirements that the worker must meet to take the job.
+	#[pallet::storage]
+	pub type JobWorkerRequirements<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::JobId,
+		JobRequirements,
+		OptionQuery,
+	>;
+
+	/// The capabilities that workers declared in their online payloads.
+	#[pallet::storage]
+	pub type WorkerDeclaredCapabilities<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, WorkerCapabilitySet>;
+
 	#[pallet::storage]
 	pub type JobInputs<T: Config> = StorageDoubleMap<
 		_,
@@ -469,7 +740,7 @@ pub mod pallet {
 		T::PoolId,
 		Blake2_128Concat,
 		T::JobId,
-		ChainStoredData<T::AccountId, BalanceOf<T>, T::InputLimit>,
+		JobData<T::AccountId, BalanceOf<T>, T::InputLimit>,
 		OptionQuery,
 	>;
 
@@ -480,7 +751,7 @@ pub mod pallet {
 		T::PoolId,
 		Blake2_128Concat,
 		T::JobId,
-		ChainStoredData<T::AccountId, BalanceOf<T>, T::OutputLimit>,
+		JobData<T::AccountId, BalanceOf<T>, T::OutputLimit>,
 		OptionQuery,
 	>;
 
@@ -525,6 +796,23 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The roles of the pool's team members.
+	#[pallet::storage]
+	pub type PoolRoleOf<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		PoolRoles,
+		OptionQuery,
+	>;
+
+	/// The pool that an account is willing to accept the ownership of.
+	#[pallet::storage]
+	pub type PoolOwnershipAcceptance<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::PoolId, OptionQuery>;
+
 	/// The jobs held by any given account; set out this way so that jobs owned by a single
 	/// account can be enumerated.
 	#[pallet::storage]
@@ -551,6 +839,19 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The workers who failed the job, they can't take the job again.
+	#[pallet::storage]
+	pub type JobFailedWorkers<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::PoolId>,
+			NMapKey<Blake2_128Concat, T::JobId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	pub type AssignableJobs<T: Config> = StorageNMap<
 		_,
@@ -563,6 +864,20 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The pending jobs which input encrypted to a particular worker,
+	/// they can only be taken by the worker.
+	#[pallet::storage]
+	pub type DesignatedJobs<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::PoolId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // Designated worker
+			NMapKey<Blake2_128Concat, T::JobId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	pub type CounterForWorkerAddedPools<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
@@ -575,11 +890,179 @@ pub mod pallet {
 	pub type CounterForWorkerAssignedJobs<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
+	/// Sponsorships of job policies, keyed by the pool, the policy and the sponsor.
+	#[pallet::storage]
+	pub type JobSponsorships<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::PoolId>,
+			NMapKey<Blake2_128Concat, T::PolicyId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // Sponsor
+		),
+		JobSponsorship<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The amount that any given account is spending from a sponsorship, it decreases when the
+	/// sponsored jobs destroyed, so the allowance limits the sponsored jobs alive at a time.
+	#[pallet::storage]
+	pub type JobSponsorshipSpending<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::PoolId>,
+			NMapKey<Blake2_128Concat, T::PolicyId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // Sponsor
+			NMapKey<Blake2_128Concat, T::AccountId>, // Beneficiary
+		),
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The amount that a sponsorship paid for any given job, it returns to the sponsorship when
+	/// the job destroyed.
+	#[pallet::storage]
+	pub type SponsoredJobs<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PoolId, Blake2_128Concat, T::JobId, BalanceOf<T>>;
+
+	/// The contracts which could be used to calculate jobs' fee.
+	#[pallet::storage]
+	pub type RateContracts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The next pre-signed job nonce of any given signer, used for replay protection.
+	#[pallet::storage]
+	pub type PreSignedJobNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The pools to create, as `(pool_id, owner, impl_id, job_scheduler, create_job_enabled,
+		/// auto_destroy_processed_job_enabled)`, the implementations should exist.
+		///
+		/// The owners should be endowed to hold the creation and the metadata deposits.
+		pub pools: Vec<(T::PoolId, T::AccountId, T::ImplId, JobScheduler, bool, bool)>,
+		/// The metadata of the pools, as `(pool_id, metadata)`.
+		pub pool_metadata: Vec<(T::PoolId, Vec<u8>)>,
+		/// The accepted impl spec versions of the pools, as `(pool_id, min, max)`.
+		pub pool_impl_spec_versions: Vec<(T::PoolId, ImplSpecVersion, ImplSpecVersion)>,
+		/// The job policies to create, as `(pool_id, policy_id, applicable_scope, start_block,
+		/// end_block)`.
+		pub job_policies: Vec<(
+			T::PoolId,
+			T::PolicyId,
+			ApplicableScope,
+			Option<BlockNumberFor<T>>,
+			Option<BlockNumberFor<T>>,
+		)>,
+		/// The workers to authorize, as `(pool_id, worker)`, the workers should be registered.
+		pub authorized_workers: Vec<(T::PoolId, T::AccountId)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let mut next_pool_id = NextPoolId::<T>::get().unwrap_or(101u32.into());
+			for (
+				pool_id,
+				owner,
+				impl_id,
+				job_scheduler,
+				create_job_enabled,
+				auto_destroy_processed_job_enabled,
+			) in &self.pools
+			{
+				Pallet::<T>::do_create_pool(
+					owner.clone(),
+					pool_id.clone(),
+					impl_id.clone(),
+					job_scheduler.clone(),
+					*create_job_enabled,
+					*auto_destroy_processed_job_enabled,
+				)
+				.expect("Genesis pool should be created");
+
+				if *pool_id >= next_pool_id {
+					next_pool_id = pool_id.increment().expect("Genesis pool id is too large");
+				}
+			}
+			NextPoolId::<T>::put(next_pool_id);
+
+			for (pool_id, metadata) in &self.pool_metadata {
+				let pool_info = Pools::<T>::get(pool_id)
+					.expect("Genesis metadata should belong to a genesis pool");
+				let metadata = BoundedVec::try_from(metadata.clone())
+					.expect("Genesis pool metadata is too long");
+				Pallet::<T>::do_update_pool_metadata(pool_info, metadata)
+					.expect("Genesis pool metadata should be set");
+			}
+
+			for (pool_id, min_impl_spec_version, max_impl_spec_version) in
+				&self.pool_impl_spec_versions
+			{
+				let pool_info = Pools::<T>::get(pool_id)
+					.expect("Genesis impl spec versions should belong to a genesis pool");
+				let job_scheduler = pool_info.job_scheduler.clone();
+				let create_job_enabled = pool_info.create_job_enabled;
+				let auto_destroy_processed_job_enabled =
+					pool_info.auto_destroy_processed_job_enabled;
+				Pallet::<T>::do_update_pool_settings(
+					pool_info,
+					*min_impl_spec_version,
+					*max_impl_spec_version,
+					job_scheduler,
+					create_job_enabled,
+					auto_destroy_processed_job_enabled,
+				)
+				.expect("Genesis pool impl spec versions should be set");
+			}
+
+			for (pool_id, policy_id, applicable_scope, start_block, end_block) in &self.job_policies
+			{
+				let pool_info = Pools::<T>::get(pool_id)
+					.expect("Genesis job policy should belong to a genesis pool");
+				assert!(
+					pool_info.job_policies_count <= T::MaxPoliciesPerPool::get(),
+					"Genesis job policies of a pool exceed the limit"
+				);
+				Pallet::<T>::do_create_job_policy(
+					pool_info,
+					policy_id.clone(),
+					applicable_scope.clone(),
+					*start_block,
+					*end_block,
+				)
+				.expect("Genesis job policy should be created");
+
+				let next_policy_id = NextJobPolicyId::<T>::get(pool_id).unwrap_or(1u32.into());
+				if *policy_id >= next_policy_id {
+					let next_policy_id =
+						policy_id.increment().expect("Genesis job policy id is too large");
+					NextJobPolicyId::<T>::insert(pool_id, next_policy_id);
+				}
+			}
+
+			for (pool_id, worker) in &self.authorized_workers {
+				let pool_info = Pools::<T>::get(pool_id)
+					.expect("Genesis authorized worker should belong to a genesis pool");
+				Pallet::<T>::do_authorize_worker(pool_info, worker.clone())
+					.expect("Genesis worker should be authorized");
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[transactional]
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
 		pub fn create_pool(
 			origin: OriginFor<T>,
 			impl_id: T::ImplId,
@@ -607,7 +1090,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_pool(T::MaxPoliciesPerPool::get()))]
 		pub fn destroy_pool(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -616,7 +1099,9 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::update_pool_metadata(T::PoolMetadataLimit::get())
+		)]
 		pub fn update_pool_metadata(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -625,7 +1110,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::Admin)?;
 
 			if let Some(metadata) = metadata {
 				Self::do_update_pool_metadata(pool_info, metadata)
@@ -636,7 +1121,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(3)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_settings())]
 		pub fn update_pool_settings(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -649,7 +1134,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::Admin)?;
 
 			Self::do_update_pool_settings(
 				pool_info,
@@ -663,7 +1148,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::create_job_policy())]
 		pub fn create_job_policy(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -674,7 +1159,8 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::PolicyManager)?;
+			Self::ensure_pool_live(&pool_info)?;
 
 			ensure!(
 				pool_info.job_policies_count <= T::MaxPoliciesPerPool::get(),
@@ -698,7 +1184,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(5)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::authorize_account())]
 		pub fn authorize_account(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -708,7 +1194,8 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::PolicyManager)?;
+			Self::ensure_pool_live(&pool_info)?;
 
 			let account = T::Lookup::lookup(account.clone())?;
 
@@ -717,7 +1204,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(6)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_account())]
 		pub fn revoke_account(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -727,7 +1214,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::PolicyManager)?;
 
 			let account = T::Lookup::lookup(account.clone())?;
 
@@ -736,7 +1223,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(7)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_job_policy())]
 		pub fn destroy_job_policy(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -745,14 +1232,14 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::PolicyManager)?;
 
 			Self::do_destroy_job_policy(pool_info, policy_id)
 		}
 
 		#[transactional]
 		#[pallet::call_index(8)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::update_job_policy_enablement())]
 		pub fn update_job_policy_enablement(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -762,14 +1249,14 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::PolicyManager)?;
 
 			Self::do_update_job_policy_enablement(pool_id, policy_id, enabled)
 		}
 
 		#[transactional]
 		#[pallet::call_index(9)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::authorize_worker())]
 		pub fn authorize_worker(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -778,7 +1265,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			Self::ensure_pool_owner(&who, &pool_info)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::WorkerManager)?;
 
 			let worker = T::Lookup::lookup(worker.clone())?;
 
@@ -787,7 +1274,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(10)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_worker())]
 		pub fn revoke_worker(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -800,16 +1287,16 @@ pub mod pallet {
 			let worker_info =
 				PalletInfra::<T>::worker_info(&worker).ok_or(Error::<T>::WorkerNotFound)?;
 
-			let pool_owner = pool_info.owner.clone();
-			let worker_owner = worker_info.owner;
-			ensure!(pool_owner == who || worker_owner == who, Error::<T>::NoPermission);
+			if worker_info.owner != who {
+				Self::ensure_pool_role(&who, &pool_info, PoolRole::WorkerManager)?;
+			}
 
 			Self::do_revoke_worker(pool_info, worker)
 		}
 
 		#[transactional]
 		#[pallet::call_index(11)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::subscribe_pool())]
 		pub fn subscribe_pool(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -818,7 +1305,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(12)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::unsubscribe_pool())]
 		pub fn unsubscribe_pool(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -827,7 +1314,10 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(13)]
-		#[pallet::weight({0})]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_job(T::InputLimit::get())
+				.saturating_add(T::RateContractGasLimit::get())
+		)]
 		pub fn create_job(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -835,79 +1325,45 @@ pub mod pallet {
 			unique_track_id: Option<UniqueTrackId>,
 			beneficiary: Option<AccountIdLookupOf<T>>,
 			impl_spec_version: ImplSpecVersion,
-			input: Option<BoundedVec<u8, T::InputLimit>>,
+			input: Option<JobPayload<T::InputLimit>>,
+			input_encryption: EncryptionScheme<T::AccountId>,
 			soft_expires_in: Option<u64>,
+			requirements: Option<JobRequirements>,
+			callback: Option<JobCallbackRequest<T::AccountId, BalanceOf<T>>>,
 			// TODO: Tips?
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			ensure!(pool_info.create_job_enabled, Error::<T>::PoolCreateNewJobUnavailable);
-			ensure!(
-				pool_info.jobs_count <= T::MaxJobsPerPool::get(),
-				Error::<T>::TasksPerPoolLimitExceeded
-			);
-
-			if let Some(unique_track_id) = unique_track_id.clone() {
-				ensure!(
-					!IndexedJobs::<T>::contains_key(&pool_id, unique_track_id),
-					Error::<T>::UniqueTrackIdNotUnique
-				);
-			}
-
 			let beneficiary = if let Some(beneficiary) = beneficiary {
 				T::Lookup::lookup(beneficiary)?
 			} else {
 				who.clone()
 			};
 
-			let policy =
-				JobPolicies::<T>::get(&pool_id, &policy_id).ok_or(Error::<T>::JobPolicyNotFound)?;
-			ensure!(policy.enabled, Error::<T>::JobPolicyUnavailable);
-			let current_block = frame_system::Pallet::<T>::block_number();
-			if let Some(start_block) = policy.start_block {
-				ensure!(current_block >= start_block, Error::<T>::JobPolicyNotApplicable);
-			}
-			if let Some(end_block) = policy.end_block {
-				ensure!(current_block <= end_block, Error::<T>::JobPolicyNotApplicable);
-			}
-			match policy.applicable_scope {
-				ApplicableScope::Owner => {
-					ensure!(pool_info.owner == who, Error::<T>::JobPolicyNotApplicable)
-				},
-				ApplicableScope::Public => {},
-				ApplicableScope::AllowList => {
-					ensure!(
-						JobPolicyAuthorizedAccounts::<T>::contains_key((pool_id.clone(), policy_id.clone(), who.clone())),
-						Error::<T>::JobPolicyNotApplicable
-					)
-				},
-			};
-
-			let job_id = NextJobId::<T>::get(&pool_id).unwrap_or(1u32.into());
 			let now = T::UnixTime::now().as_secs().saturated_into::<u64>();
-			Self::do_create_job(
-				pool_info,
-				policy,
-				job_id.clone(),
+			Self::do_create_next_job(
+				who.clone(),
+				who,
+				pool_id,
+				policy_id,
 				unique_track_id,
 				beneficiary,
-				who,
 				impl_spec_version,
 				input,
+				input_encryption,
 				now,
 				soft_expires_in,
+				requirements,
+				callback,
+				None,
 			)?;
 
-			let next_id = job_id.increment();
-			NextJobId::<T>::set(&pool_id, next_id);
-
 			Ok(())
 		}
 
 		#[transactional]
 		#[pallet::call_index(14)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_job())]
 		pub fn destroy_job(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -918,9 +1374,13 @@ pub mod pallet {
 			Self::do_destroy_job(who, pool_id, job_id, JobDestroyReason::Safe)
 		}
 
+		// The expired job could be still processing, so the worker is released additionally
 		#[transactional]
 		#[pallet::call_index(15)]
-		#[pallet::weight({0})]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::destroy_job()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+		)]
 		pub fn destroy_expired_job(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -934,7 +1394,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(16)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::take_job(T::TakeJobScanLimit::get()))]
 		pub fn take_job(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -952,7 +1412,7 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(17)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as Config>::WeightInfo::resign_job())]
 		pub fn resign_job(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -965,13 +1425,21 @@ pub mod pallet {
 
 		#[transactional]
 		#[pallet::call_index(18)]
-		#[pallet::weight({0})]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::submit_job_result(
+				T::OutputLimit::get(),
+				T::ProofLimit::get(),
+			)
+				.saturating_add(T::RateContractGasLimit::get())
+				.saturating_add(T::MaxJobCallbackGasLimit::get())
+		)]
 		pub fn submit_job_result(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
 			job_id: T::JobId,
 			result: JobResult,
-			output: Option<BoundedVec<u8, T::OutputLimit>>,
+			output: Option<JobPayload<T::OutputLimit>>,
+			output_encryption: EncryptionScheme<T::AccountId>,
 			proof: Option<BoundedVec<u8, T::ProofLimit>>,
 			soft_expires_in: Option<u64>,
 		) -> DispatchResult {
@@ -979,52 +1447,419 @@ pub mod pallet {
 
 			let now = T::UnixTime::now().as_secs().saturated_into::<u64>();
 			let expires_in = soft_expires_in.unwrap_or(T::DefaultJobExpiresIn::get());
-			Self::do_submit_job_result(pool_id, job_id, who, result, output, proof, now, expires_in)
+			Self::do_submit_job_result(
+				pool_id,
+				job_id,
+				who,
+				result,
+				output,
+				output_encryption,
+				proof,
+				now,
+				expires_in,
+			)
 		}
 
-		#[pallet::call_index(19)]
-		#[pallet::weight({0})]
-		pub fn call_contract(
+		/// Create a job by providing the pre-signed message of the signer.
+		///
+		/// Origin must be Signed, the caller will be the relayer.
+		///
+		/// - `job_data`: The pre-signed message that consists of the information about the job, who
+		///   can relay it (`None` for anyone), until what block number, and the nonce.
+		/// - `signature`: The signature of the message built by [`Pallet::pre_signed_job_message`]
+		///   with the `job_data` object.
+		/// - `signer`: The `job_data` object's signer, it will be the creator of the job.
+		/// - `relayer_pays_deposit`: The relayer pays the deposits instead of the signer.
+		///
+		/// Emits `JobCreated` on success.
+		#[transactional]
+		#[pallet::call_index(20)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_job_pre_signed(T::InputLimit::get())
+				.saturating_add(T::RateContractGasLimit::get())
+		)]
+		pub fn create_job_pre_signed(
 			origin: OriginFor<T>,
-			dest: T::AccountId, // <- This is the address of the deployed contract we're calling
+			job_data: Box<PreSignedJobOf<T>>,
+			signature: T::OffchainSignature,
+			signer: T::AccountId,
+			relayer_pays_deposit: bool,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			Self::validate_signature(
+				&Self::pre_signed_job_message(&job_data),
+				&signature,
+				&signer,
+			)?;
+
+			let now = T::UnixTime::now().as_secs().saturated_into::<u64>();
+			Self::do_create_job_pre_signed(relayer, *job_data, signer, relayer_pays_deposit, now)
+		}
+
+		/// Fund a sponsorship for a job policy, the amount will be held from the sponsor,
+		/// and the sponsorship's allowance per account will be updated.
+		#[transactional]
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::fund_job_sponsorship())]
+		pub fn fund_job_sponsorship(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			amount: BalanceOf<T>,
+			allowance_per_account: BalanceOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// Amount to transfer to the message. Not gonna transfer anything here, so we'll
-			// leave this as `0`.
-			let value: ContractBalanceOf<T> = Default::default();
+			Self::do_fund_job_sponsorship(who, pool_id, policy_id, amount, allowance_per_account)
+		}
 
-			// You'll have to play around with this depending on your contract. I don't recommend
-			// hardcoding it but for demo purposes this'll do the trick
-			let gas_limit = Weight::zero();
+		/// Withdraw the unused budget of a sponsorship, withdraw all if `amount` is `None`.
+		#[transactional]
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_job_sponsorship())]
+		pub fn withdraw_job_sponsorship(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			// Remember, we pulled this out from the `metadata.json` file.
-			//
-			// Again, probably shouldn't be hardcoded but :shrug:
-			let mut selector: Vec<u8> = [0x63, 0x3A, 0xA5, 0x51].into();
-			let mut message_arg = 15663040u32.encode();
+			Self::do_withdraw_job_sponsorship(who, pool_id, policy_id, amount)
+		}
 
-			let mut data = Vec::new();
-			data.append(&mut selector);
-			data.append(&mut message_arg);
+		/// Create a job which deposits are paid by the `sponsor`,
+		/// the caller will be the beneficiary of the job.
+		#[transactional]
+		#[pallet::call_index(23)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_sponsored_job(T::InputLimit::get())
+				.saturating_add(T::RateContractGasLimit::get())
+		)]
+		pub fn create_sponsored_job(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			sponsor: AccountIdLookupOf<T>,
+			unique_track_id: Option<UniqueTrackId>,
+			impl_spec_version: ImplSpecVersion,
+			input: Option<JobPayload<T::InputLimit>>,
+			input_encryption: EncryptionScheme<T::AccountId>,
+			soft_expires_in: Option<u64>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let sponsor = T::Lookup::lookup(sponsor)?;
 
-			pallet_contracts::Pallet::<T>::bare_call(
+			let now = T::UnixTime::now().as_secs().saturated_into::<u64>();
+			Self::do_create_sponsored_job(
 				who,
-				dest.clone(),
-				value,
-				gas_limit,
-				None,
-				data,
-				DebugInfo::Skip,
-				CollectEvents::Skip,
-				Determinism::Enforced,
-			).result?;
+				sponsor,
+				pool_id,
+				policy_id,
+				unique_track_id,
+				impl_spec_version,
+				input,
+				input_encryption,
+				now,
+				soft_expires_in,
+			)
+		}
 
-			Ok(())
+		/// Replace the input of a pending job which hasn't been assigned.
+		///
+		/// Origin must be Signed and the sender should be the depositor of the job.
+		///
+		/// The storage deposit will be re-calculated, the insufficient part will be held from the
+		/// depositor, and the over-payment will be kept as surplus deposit.
+		#[transactional]
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_job_input(T::InputLimit::get()))]
+		pub fn update_job_input(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			input: Option<JobPayload<T::InputLimit>>,
+			input_encryption: EncryptionScheme<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_update_job_input(who, pool_id, job_id, input, input_encryption)
+		}
+
+		/// Reclaim the surplus deposit of the job's input.
+		#[transactional]
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::reclaim_job_surplus_deposit())]
+		pub fn reclaim_job_surplus_deposit(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_reclaim_job_surplus_deposit(who, pool_id, job_id)
+		}
+
+		/// Hold more deposit for a pending or processing job,
+		/// the deposit will be released when the job destroyed.
+		#[transactional]
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_job_deposit())]
+		pub fn top_up_job_deposit(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_top_up_job_deposit(who, pool_id, job_id, amount)
+		}
+
+		/// Whitelist a contract for calculating jobs' fee.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[transactional]
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_rate_contract())]
+		pub fn add_rate_contract(origin: OriginFor<T>, contract: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Self::do_add_rate_contract(contract)
+		}
+
+		/// Remove a contract from the whitelist, policies using it can't create new jobs.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[transactional]
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_rate_contract())]
+		pub fn remove_rate_contract(
+			origin: OriginFor<T>,
+			contract: T::AccountId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Self::do_remove_rate_contract(contract)
+		}
+
+		/// Set how to charge for jobs created with the policy,
+		/// the contract of `JobRateStrategy::Contract` must be whitelisted.
+		#[transactional]
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_job_policy_rate_strategy())]
+		pub fn update_job_policy_rate_strategy(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			rate_strategy: JobRateStrategy<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::Finance)?;
+
+			Self::do_update_job_policy_rate_strategy(pool_id, policy_id, rate_strategy)
+		}
+
+		/// Cancel a processing job, only the depositor could do this.
+		/// The worker gets the compensation defined by the policy, and the rest fee is refunded.
+		#[transactional]
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_job())]
+		pub fn cancel_job(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			job_id: T::JobId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let now = T::UnixTime::now().as_secs().saturated_into::<u64>();
+			Self::do_cancel_job(who, pool_id, job_id, now)
+		}
+
+		#[transactional]
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_job_policy_cancellation_compensation())]
+		pub fn update_job_policy_cancellation_compensation(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			cancellation_compensation: JobCancellationCompensation,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::Finance)?;
+
+			Self::do_update_job_policy_cancellation_compensation(
+				pool_id,
+				policy_id,
+				cancellation_compensation,
+			)
+		}
+
+		/// Set how many times a job could be attempted,
+		/// discarded and panicked jobs will be retried by other workers until attempts exhausted.
+		#[transactional]
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_job_policy_max_attempts())]
+		pub fn update_job_policy_max_attempts(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			policy_id: T::PolicyId,
+			max_attempts: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::PolicyManager)?;
+
+			Self::do_update_job_policy_max_attempts(pool_id, policy_id, max_attempts)
+		}
+
+		/// Start destroying the pool, it will stop accepting new jobs and workers.
+		/// Then anyone could call `destroy_pool_jobs` and `revoke_pool_workers` to remove them in
+		/// batches, and `finish_destroy_pool` at the end.
+		#[transactional]
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as Config>::WeightInfo::start_destroy_pool())]
+		pub fn start_destroy_pool(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_start_destroy_pool(who, pool_id)
+		}
+
+		/// Destroy at most `RemoveItemsLimit` jobs of the destroying pool, then its sponsorships
+		/// and authorized accounts.
+		#[transactional]
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_pool_jobs(T::RemoveItemsLimit::get()))]
+		pub fn destroy_pool_jobs(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_destroy_pool_jobs(who, pool_id)
+		}
+
+		/// Revoke at most `RemoveItemsLimit` workers of the destroying pool.
+		#[transactional]
+		#[pallet::call_index(35)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::revoke_pool_workers(T::RemoveItemsLimit::get())
+		)]
+		pub fn revoke_pool_workers(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_revoke_pool_workers(pool_id)
+		}
+
+		/// Complete destroying the pool when all jobs, sponsorships, authorized accounts and
+		/// workers removed.
+		#[transactional]
+		#[pallet::call_index(36)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::finish_destroy_pool(T::MaxPoliciesPerPool::get())
+		)]
+		pub fn finish_destroy_pool(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_finish_destroy_pool(pool_id)
+		}
+
+		/// Change the Admin, WorkerManager, PolicyManager and Finance of the pool,
+		/// the previous team will be cleared.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the pool's owner.
+		#[transactional]
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_team())]
+		pub fn set_pool_team(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			admin: Option<AccountIdLookupOf<T>>,
+			worker_manager: Option<AccountIdLookupOf<T>>,
+			policy_manager: Option<AccountIdLookupOf<T>>,
+			finance: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let admin = admin.map(T::Lookup::lookup).transpose()?;
+			let worker_manager = worker_manager.map(T::Lookup::lookup).transpose()?;
+			let policy_manager = policy_manager.map(T::Lookup::lookup).transpose()?;
+			let finance = finance.map(T::Lookup::lookup).transpose()?;
+
+			Self::do_set_pool_team(
+				maybe_check_owner,
+				pool_id,
+				admin,
+				worker_manager,
+				policy_manager,
+				finance,
+			)
+		}
+
+		/// Set (or reset) the acceptance of ownership for a particular account.
+		///
+		/// - `maybe_pool_id`: The pool whose ownership the sender is willing to accept, `None` to
+		///   accept none.
+		#[transactional]
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_accept_pool_ownership())]
+		pub fn set_accept_pool_ownership(
+			origin: OriginFor<T>,
+			maybe_pool_id: Option<T::PoolId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_accept_pool_ownership(who, maybe_pool_id)
+		}
+
+		/// Change the owner of the pool, the new owner must have accepted the ownership by
+		/// `set_accept_pool_ownership`.
+		#[transactional]
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_pool_ownership())]
+		pub fn transfer_pool_ownership(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			new_owner: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+
+			Self::do_transfer_pool_ownership(who, pool_id, new_owner)
+		}
+
+		/// Require workers to hold a minimum deposit to be authorized by or subscribe the pool,
+		/// `None` to remove the requirement.
+		/// Workers already authorized are checked when they subscribe the pool.
+		#[transactional]
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_min_worker_deposit())]
+		pub fn set_pool_min_worker_deposit(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			min_worker_deposit: Option<pallet_offchain_computing_infra::BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_role(&who, &pool_info, PoolRole::WorkerManager)?;
+
+			Self::do_set_pool_min_worker_deposit(pool_info, min_worker_deposit)
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		pub(crate) fn ensure_pool_live(
+			pool_info: &PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
+		) -> DispatchResult {
+			ensure!(pool_info.status == PoolStatus::Live, Error::<T>::PoolIsDestroying);
+
+			Ok(())
+		}
+
 		pub(crate) fn ensure_pool_owner(
 			who: &T::AccountId,
 			pool_info: &PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
@@ -1034,6 +1869,20 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// The owner has all roles of the pool.
+		pub(crate) fn ensure_pool_role(
+			who: &T::AccountId,
+			pool_info: &PoolInfo<T::PoolId, T::AccountId, BalanceOf<T>, T::ImplId>,
+			role: PoolRole,
+		) -> DispatchResult {
+			ensure!(
+				who == &pool_info.owner || Self::has_pool_role(&pool_info.id, who, role),
+				Error::<T>::NoPermission
+			);
+
+			Ok(())
+		}
+
 		pub(crate) fn ensure_job_beneficiary_or_depositor(
 			who: &T::AccountId,
 			job: &JobInfo<T::JobId, T::PolicyId, T::AccountId, BalanceOf<T>>,
@@ -1101,6 +1950,35 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Validates the signature of the given data with the provided signer's account ID.
+		///
+		/// # Errors
+		///
+		/// This function returns a [`WrongSignature`](crate::Error::WrongSignature) error if the
+		/// signature is invalid or the verification process fails.
+		pub fn validate_signature(
+			data: &Vec<u8>,
+			signature: &T::OffchainSignature,
+			signer: &T::AccountId,
+		) -> DispatchResult {
+			if signature.verify(&**data, &signer) {
+				return Ok(())
+			}
+
+			// NOTE: for security reasons modern UIs implicitly wrap the data requested to sign into
+			// <Bytes></Bytes>, that's why we support both wrapped and raw versions.
+			let prefix = b"<Bytes>";
+			let suffix = b"</Bytes>";
+			let mut wrapped: Vec<u8> = Vec::with_capacity(data.len() + prefix.len() + suffix.len());
+			wrapped.extend(prefix);
+			wrapped.extend(data);
+			wrapped.extend(suffix);
+
+			ensure!(signature.verify(&*wrapped, &signer), Error::<T>::WrongSignature);
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> OffchainWorkerLifecycleHooks<T::AccountId, T::ImplId> for Pallet<T> {
@@ -1112,8 +1990,12 @@ pub mod pallet {
 			Ok(())
 		}
 
-		fn after_online(_worker: &T::AccountId) {
-			// Nothing to do
+		fn after_online(
+			worker: &T::AccountId,
+			payload: &OnlinePayload<T::ImplId>,
+			verified_attestation: &VerifiedAttestation,
+		) {
+			Self::update_worker_capabilities(worker, payload, verified_attestation);
 		}
 
 		fn can_offline(worker: &T::AccountId) -> bool {
@@ -1125,45 +2007,85 @@ pub mod pallet {
 		}
 
 		fn before_offline(worker: &T::AccountId, _reason: OfflineReason) {
+			WorkerDeclaredCapabilities::<T>::remove(worker);
+
+			// The encryption key changes when the worker goes online again, so the pending jobs
+			// designated to the worker can't be processed anymore, they're bounded by
+			// `MaxJobsPerPool` of the subscribed pools
+			let now = T::UnixTime::now().as_secs().saturated_into::<u64>();
+			for pool_id in WorkerSubscribedPools::<T>::iter_key_prefix(worker) {
+				for (job_id, _) in
+					DesignatedJobs::<T>::drain_prefix((pool_id.clone(), worker.clone()))
+				{
+					Jobs::<T>::mutate(&pool_id, &job_id, |job| {
+						if let Some(job) = job.as_mut() {
+							job.status = JobStatus::Discarded;
+							job.ended_at = Some(now);
+
+							Self::deposit_event(Event::JobStatusUpdated {
+								pool_id: pool_id.clone(),
+								job_id: job_id.clone(),
+								status: JobStatus::Discarded,
+							});
+						}
+					});
+				}
+			}
+
 			if CounterForWorkerAssignedJobs::<T>::get(worker) == 0 {
 				return
 			}
 
-			for pool_id in WorkerSubscribedPools::<T>::iter_key_prefix(worker) {
-				for job_id in
-					WorkerAssignedJobs::<T>::iter_key_prefix((worker.clone(), pool_id.clone()))
-				{
-					let _: Result<(), DispatchError> = Jobs::<T>::try_mutate_exists(
-						&pool_id,
-						&job_id,
-						|job| -> Result<(), DispatchError> {
-							if let Some(job) = job.as_mut() {
-								job.status = JobStatus::Discarded;
-								job.ended_at =
-									Some(T::UnixTime::now().as_secs().saturated_into::<u64>());
-
-								Self::deposit_event(Event::JobStatusUpdated {
-									pool_id: pool_id.clone(),
-									job_id: job_id.clone(),
-									status: JobStatus::Discarded,
-								});
+			for ((pool_id, job_id), _) in WorkerAssignedJobs::<T>::drain_prefix((worker.clone(),)) {
+				let _: Result<(), DispatchError> = Jobs::<T>::try_mutate_exists(
+					&pool_id,
+					&job_id,
+					|job| -> Result<(), DispatchError> {
+						if let Some(job) = job.as_mut() {
+							// Let other workers retry the job if attempts left, except the job
+							// designated to the worker
+							if Self::job_designated_worker(&pool_id, &job_id).is_none() &&
+								Self::retry_failed_job(&pool_id, job, worker)
+							{
+								return Ok(())
 							}
 
-							Ok(())
-						},
-					);
-				}
+							job.status = JobStatus::Discarded;
+							job.ended_at = Some(now);
+
+							Self::deposit_event(Event::JobStatusUpdated {
+								pool_id: pool_id.clone(),
+								job_id: job_id.clone(),
+								status: JobStatus::Discarded,
+							});
+						}
+
+						Ok(())
+					},
+				);
 			}
 
 			CounterForWorkerAssignedJobs::<T>::insert(worker, 0);
 		}
 
 		fn after_refresh_attestation(
-			_worker: &T::AccountId,
-			_payload: &OnlinePayload<T::ImplId>,
-			_verified_attestation: &VerifiedAttestation,
+			worker: &T::AccountId,
+			payload: &OnlinePayload<T::ImplId>,
+			verified_attestation: &VerifiedAttestation,
 		) {
-			// Nothing to do
+			Self::update_worker_capabilities(worker, payload, verified_attestation);
+		}
+
+		fn can_upgrade_build(
+			worker: &T::AccountId,
+			payload: &OnlinePayload<T::ImplId>,
+			_verified_attestation: &VerifiedAttestation,
+		) -> DispatchResult {
+			Self::ensure_worker_spec_version_acceptable(worker, payload.impl_spec_version)
+		}
+
+		fn can_decrease_deposit(worker: &T::AccountId) -> DispatchResult {
+			Self::ensure_worker_deposit_acceptable(worker)
 		}
 
 		fn after_requesting_offline(_worker: &T::AccountId) {
@@ -1175,11 +2097,22 @@ pub mod pallet {
 		}
 
 		fn before_deregister(worker: &T::AccountId) {
+			WorkerDeclaredCapabilities::<T>::remove(worker);
+
 			let worker_added_pools_count = CounterForWorkerAddedPools::<T>::get(worker);
 			if worker_added_pools_count == 0 {
 				return
 			}
 
+			for pool_id in PoolAuthorizedWorkers::<T>::iter_key_prefix(worker) {
+				PoolWorkers::<T>::remove(&pool_id, worker);
+				Pools::<T>::mutate(&pool_id, |pool_info| {
+					if let Some(pool_info) = pool_info {
+						pool_info.workers_count = pool_info.workers_count.saturating_sub(1);
+					}
+				});
+			}
+
 			let _ = WorkerSubscribedPools::<T>::clear_prefix(
 				worker,
 				T::MaxSubscribedPoolsPerWorker::get(),
@@ -0,0 +1,356 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations for the off-chain computing pool pallet.
+//!
+//! The migrations translate every pool, job policy, job, job input, job output and authorized
+//! worker in a single block, so their weight grows with the jobs kept alive. Check the returned
+//! weight with `try-runtime` against the block limit before upgrading, and destroy the processed
+//! jobs first if it doesn't fit.
+
+use crate::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade,
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Migrate the storage from v1 to v2.
+///
+/// - Pools get the `Live` status.
+/// - Job policies get the free rate strategy, no cancellation compensation and a single attempt,
+///   the same as newly created policies.
+/// - Jobs get no attempts, no held fee and no callback.
+/// - Job inputs and outputs become plain inline payloads.
+/// - The authorized workers are indexed by pool in `PoolWorkers` and counted per worker in
+///   `CounterForWorkerAddedPools`.
+pub mod v2 {
+	use super::*;
+
+	/// The storage layouts before v2.
+	pub(crate) mod v1 {
+		use super::*;
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+		pub struct PoolInfo<PoolId, AccountId, Balance, ImplId> {
+			pub id: PoolId,
+			pub owner: AccountId,
+			pub owner_deposit: Balance,
+			pub impl_id: ImplId,
+			pub job_scheduler: JobScheduler,
+			pub create_job_enabled: bool,
+			pub auto_destroy_processed_job_enabled: bool,
+			pub min_impl_spec_version: ImplSpecVersion,
+			pub max_impl_spec_version: ImplSpecVersion,
+			pub job_policies_count: u32,
+			pub jobs_count: u32,
+			pub workers_count: u32,
+		}
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+		pub struct JobPolicy<PolicyId, BlockNumber> {
+			pub id: PolicyId,
+			pub enabled: bool,
+			pub applicable_scope: ApplicableScope,
+			pub start_block: Option<BlockNumber>,
+			pub end_block: Option<BlockNumber>,
+			pub jobs_count: u32,
+		}
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+		pub struct JobInfo<JobId, PolicyId, AccountId, Balance> {
+			pub id: JobId,
+			pub unique_track_id: Option<UniqueTrackId>,
+			pub policy_id: PolicyId,
+			pub depositor: AccountId,
+			pub deposit: Balance,
+			pub beneficiary: AccountId,
+			pub impl_build_version: Option<ImplBuildVersion>,
+			pub impl_spec_version: ImplSpecVersion,
+			pub status: JobStatus,
+			pub result: Option<JobResult>,
+			pub expires_at: u64,
+			pub created_at: u64,
+			pub assignee: Option<AccountId>,
+			pub assigned_at: Option<u64>,
+			pub processing_at: Option<u64>,
+			pub ended_at: Option<u64>,
+		}
+
+		pub type PoolInfoOf<T> = PoolInfo<
+			<T as Config>::PoolId,
+			<T as frame_system::Config>::AccountId,
+			BalanceOf<T>,
+			<T as pallet_offchain_computing_infra::Config>::ImplId,
+		>;
+		pub type JobPolicyOf<T> = JobPolicy<<T as Config>::PolicyId, BlockNumberFor<T>>;
+		pub type JobInfoOf<T> = JobInfo<
+			<T as Config>::JobId,
+			<T as Config>::PolicyId,
+			<T as frame_system::Config>::AccountId,
+			BalanceOf<T>,
+		>;
+
+		#[storage_alias]
+		pub type Pools<T: Config> =
+			CountedStorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::PoolId, PoolInfoOf<T>>;
+
+		#[storage_alias]
+		pub type JobPolicies<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as Config>::PoolId,
+			Blake2_128Concat,
+			<T as Config>::PolicyId,
+			JobPolicyOf<T>,
+		>;
+
+		#[storage_alias]
+		pub type Jobs<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as Config>::PoolId,
+			Blake2_128Concat,
+			<T as Config>::JobId,
+			JobInfoOf<T>,
+		>;
+
+		#[storage_alias]
+		pub type JobInputs<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as Config>::PoolId,
+			Blake2_128Concat,
+			<T as Config>::JobId,
+			ChainStoredData<
+				<T as frame_system::Config>::AccountId,
+				BalanceOf<T>,
+				<T as Config>::InputLimit,
+			>,
+		>;
+
+		#[storage_alias]
+		pub type JobOutputs<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as Config>::PoolId,
+			Blake2_128Concat,
+			<T as Config>::JobId,
+			ChainStoredData<
+				<T as frame_system::Config>::AccountId,
+				BalanceOf<T>,
+				<T as Config>::OutputLimit,
+			>,
+		>;
+	}
+
+	fn migrate_job_data<AccountId, Balance, DataLimit: Get<u32>>(
+		old: ChainStoredData<AccountId, Balance, DataLimit>,
+	) -> JobData<AccountId, Balance, DataLimit> {
+		JobData {
+			depositor: old.depositor,
+			actual_deposit: old.actual_deposit,
+			surplus_deposit: old.surplus_deposit,
+			encryption: EncryptionScheme::Plain,
+			data: JobPayload::Inline(old.data),
+		}
+	}
+
+	/// The migration without checking the storage version, use [`MigrateV1ToV2`] instead.
+	pub struct VersionUncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated: u64 = 0;
+
+			Pools::<T>::translate::<v1::PoolInfoOf<T>, _>(|_, old| {
+				translated.saturating_inc();
+
+				Some(PoolInfo {
+					id: old.id,
+					owner: old.owner,
+					owner_deposit: old.owner_deposit,
+					impl_id: old.impl_id,
+					job_scheduler: old.job_scheduler,
+					create_job_enabled: old.create_job_enabled,
+					auto_destroy_processed_job_enabled: old.auto_destroy_processed_job_enabled,
+					min_impl_spec_version: old.min_impl_spec_version,
+					max_impl_spec_version: old.max_impl_spec_version,
+					job_policies_count: old.job_policies_count,
+					jobs_count: old.jobs_count,
+					workers_count: old.workers_count,
+					status: PoolStatus::Live,
+				})
+			});
+
+			JobPolicies::<T>::translate::<v1::JobPolicyOf<T>, _>(|_, _, old| {
+				translated.saturating_inc();
+
+				Some(JobPolicy {
+					id: old.id,
+					enabled: old.enabled,
+					applicable_scope: old.applicable_scope,
+					rate_strategy: JobRateStrategy::Free,
+					cancellation_compensation: JobCancellationCompensation::Nothing,
+					max_attempts: 1,
+					start_block: old.start_block,
+					end_block: old.end_block,
+					jobs_count: old.jobs_count,
+				})
+			});
+
+			Jobs::<T>::translate::<v1::JobInfoOf<T>, _>(|_, _, old| {
+				translated.saturating_inc();
+
+				Some(JobInfo {
+					id: old.id,
+					unique_track_id: old.unique_track_id,
+					policy_id: old.policy_id,
+					depositor: old.depositor,
+					deposit: old.deposit,
+					beneficiary: old.beneficiary,
+					impl_build_version: old.impl_build_version,
+					impl_spec_version: old.impl_spec_version,
+					status: old.status,
+					result: old.result,
+					expires_at: old.expires_at,
+					created_at: old.created_at,
+					assignee: old.assignee,
+					assigned_at: old.assigned_at,
+					processing_at: old.processing_at,
+					ended_at: old.ended_at,
+					attempts: 0,
+					fee: Zero::zero(),
+					callback: None,
+				})
+			});
+
+			JobInputs::<T>::translate::<
+				ChainStoredData<T::AccountId, BalanceOf<T>, T::InputLimit>,
+				_,
+			>(|_, _, old| {
+				translated.saturating_inc();
+				Some(migrate_job_data(old))
+			});
+
+			JobOutputs::<T>::translate::<
+				ChainStoredData<T::AccountId, BalanceOf<T>, T::OutputLimit>,
+				_,
+			>(|_, _, old| {
+				translated.saturating_inc();
+				Some(migrate_job_data(old))
+			});
+
+			let mut added_pools_count = BTreeMap::<T::AccountId, u32>::new();
+			for (worker, pool_id) in PoolAuthorizedWorkers::<T>::iter_keys() {
+				translated.saturating_inc();
+
+				PoolWorkers::<T>::insert(&pool_id, &worker, ());
+				added_pools_count.entry(worker).or_default().saturating_inc();
+			}
+			for (worker, count) in added_pools_count {
+				translated.saturating_inc();
+
+				CounterForWorkerAddedPools::<T>::insert(&worker, count);
+			}
+
+			log!(info, "Migrated to v2, {} entries translated", translated);
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let pools_count = v1::Pools::<T>::count();
+			ensure!(
+				v1::Pools::<T>::iter().count() as u32 == pools_count,
+				"v1 pools are undecodable"
+			);
+			let policies_count = v1::JobPolicies::<T>::iter().count() as u32;
+			let jobs_count = v1::Jobs::<T>::iter().count() as u32;
+			let inputs_count = v1::JobInputs::<T>::iter().count() as u32;
+			let outputs_count = v1::JobOutputs::<T>::iter().count() as u32;
+			let workers_count = PoolAuthorizedWorkers::<T>::iter().count() as u32;
+
+			let state = (
+				pools_count,
+				policies_count,
+				jobs_count,
+				inputs_count,
+				outputs_count,
+				workers_count,
+			);
+			Ok(state.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (
+				pools_count,
+				policies_count,
+				jobs_count,
+				inputs_count,
+				outputs_count,
+				workers_count,
+			): (u32, u32, u32, u32, u32, u32) = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state of pre-upgrade is undecodable")?;
+
+			ensure!(Pools::<T>::count() == pools_count, "pools count mismatch");
+			ensure!(
+				Pools::<T>::iter_values().filter(|pool| pool.status == PoolStatus::Live).count()
+					as u32 == pools_count,
+				"v2 pools are undecodable or not live"
+			);
+			ensure!(
+				JobPolicies::<T>::iter().count() as u32 == policies_count,
+				"v2 job policies are undecodable"
+			);
+			ensure!(Jobs::<T>::iter().count() as u32 == jobs_count, "v2 jobs are undecodable");
+			ensure!(
+				JobInputs::<T>::iter().count() as u32 == inputs_count,
+				"v2 job inputs are undecodable"
+			);
+			ensure!(
+				JobOutputs::<T>::iter().count() as u32 == outputs_count,
+				"v2 job outputs are undecodable"
+			);
+			ensure!(
+				PoolWorkers::<T>::iter().count() as u32 == workers_count,
+				"pool workers aren't all indexed"
+			);
+			ensure!(
+				CounterForWorkerAddedPools::<T>::iter_values().sum::<u32>() == workers_count,
+				"workers' added pools aren't all counted"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Migrate v1 to v2, it only runs when the on-chain storage version is 1.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		VersionUncheckedMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
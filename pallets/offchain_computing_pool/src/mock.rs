@@ -17,19 +17,21 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate as pallet_offchain_computing_pool;
+use crate::OffchainComputingPoolExtension;
 
 use frame_support::{
-	assert_ok, parameter_types, derive_impl,
-	weights::Weight,
+	assert_ok, derive_impl, parameter_types,
 	traits::{Currency, Everything, OnFinalize, OnInitialize},
+	weights::Weight,
 };
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_contracts::{DefaultAddressGenerator, Frame, Schedule};
 use sp_core::{ConstBool, ConstU128, ConstU16, ConstU32, ConstU64};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
 	traits::{Convert, IdentifyAccount, IdentityLookup, Verify},
 	BuildStorage, MultiSignature, Perbill,
 };
-use pallet_contracts::{DefaultAddressGenerator, Frame, Schedule};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -83,7 +85,7 @@ impl pallet_balances::Config for Test {
 	type FreezeIdentifier = ();
 	type MaxLocks = ();
 	type MaxReserves = ();
-	type MaxHolds = ConstU32<3>;
+	type MaxHolds = ConstU32<8>;
 	type MaxFreezes = ();
 }
 
@@ -110,6 +112,9 @@ parameter_types! {
 	pub static CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(0);
 	// We need this one set high enough for running benchmarks.
 	pub static DefaultDepositLimit: ContractsBalanceOf<Test> = 1 * DOLLARS;
+
+	pub MaxJobCallbackGasLimit: Weight = Weight::from_parts(100_000_000_000, 3 * 1024 * 1024);
+	pub RateContractGasLimit: Weight = Weight::from_parts(10_000_000_000, 1024 * 1024);
 }
 
 impl Convert<Weight, ContractsBalanceOf<Test>> for Test {
@@ -128,7 +133,7 @@ impl pallet_contracts::Config for Test {
 	type CallStack = [Frame<Test>; 5];
 	type WeightPrice = Test;
 	type WeightInfo = ();
-	type ChainExtension = ();
+	type ChainExtension = OffchainComputingPoolExtension;
 	type Schedule = MySchedule;
 	type DepositPerByte = DepositPerByte;
 	type DepositPerItem = DepositPerItem;
@@ -155,14 +160,20 @@ impl pallet_offchain_computing_infra::Config for Test {
 	type Randomness = Randomness;
 	type ImplId = u32;
 	type RegisterImplOrigin = EnsureSigned<Self::AccountId>;
+	type WorkerForceOrigin = EnsureRoot<Self::AccountId>;
 	type RegisterWorkerDeposit = ConstU128<{ 100 * DOLLARS }>;
 	type RegisterImplDeposit = ConstU128<{ DOLLARS }>;
 	type ImplMetadataDepositBase = ConstU128<{ DOLLARS }>;
 	type ImplMetadataDepositPerByte = ConstU128<{ CENTS }>;
 	type ImplMetadataLimit = ConstU32<50>;
 	type MaxImplBuilds = ConstU32<4>;
+	type MaxImplBuildPublishers = ConstU32<2>;
 	type HandleUnresponsivePerBlockLimit = ConstU32<3>;
 	type CollectingHeartbeatsDurationInBlocks = ConstU32<6>;
+	type MinCollectingHeartbeatsDurationInBlocks = ConstU32<4>;
+	type WorkerDepositUnbondingDurationInBlocks = ConstU32<10>;
+	type ReapWorkerAfterInactiveBlocks = ConstU32<20>;
+	type WorkerReaperFee = ConstU128<{ DOLLARS }>;
 	type DisallowOptOutAttestation = ConstBool<false>;
 	type WeightInfo = ();
 	type OffchainWorkerLifecycleHooks = OffchainComputingPool;
@@ -186,6 +197,7 @@ impl pallet_offchain_computing_pool::Config for Test {
 	type MaxPoliciesPerPool = ConstU32<3>;
 	type MaxJobsPerPool = ConstU32<100>;
 	type MaxWorkersPerPool = ConstU32<100>;
+	type TakeJobScanLimit = ConstU32<4>;
 	type MinJobExpiresIn = ConstU64<6>;
 	type MaxJobExpiresIn = ConstU64<24>;
 	type DefaultJobExpiresIn = ConstU64<18>;
@@ -193,6 +205,16 @@ impl pallet_offchain_computing_pool::Config for Test {
 	type InputLimit = ConstU32<50>;
 	type OutputLimit = ConstU32<50>;
 	type ProofLimit = ConstU32<50>;
+	type MaxJobCallbackGasLimit = MaxJobCallbackGasLimit;
+	type RemoveItemsLimit = ConstU32<2>;
+	type MaxJobAttempts = ConstU32<3>;
+	type RateContractGasLimit = RateContractGasLimit;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -200,7 +222,18 @@ impl pallet_offchain_computing_pool::Config for Test {
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	// Customize genesis config here
-	t.into()
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext
+}
+
+/// Execute the test, then check the pallet's storage invariants.
+#[allow(unused)]
+pub(crate) fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		OffchainComputingPool::do_try_state().expect("storage invariants should hold");
+	});
 }
 
 #[allow(unused)]
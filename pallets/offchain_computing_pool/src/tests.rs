@@ -16,9 +16,2677 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
-#[allow(unused)]
-use crate::{mock::*, Error, Event};
-#[allow(unused)]
-use frame_support::{assert_err, assert_noop, assert_ok};
-#[allow(unused)]
-use frame_system::Account;
+use crate::{
+	mock::*, AssignableJobs, DesignatedJobs, Error, Event, HoldReason, JobInputs, JobOutputs,
+	JobSponsorshipSpending, JobSponsorships, Jobs, SponsoredJobs,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResult,
+	traits::{fungible::InspectHold, Get},
+	weights::Weight,
+};
+use pallet_contracts::{Code, CollectEvents, DebugInfo, Determinism};
+use primitives::*;
+use scale_codec::{Decode, Encode};
+use sp_core::{bounded::BoundedVec, sr25519, Pair};
+use sp_runtime::traits::IdentifyAccount;
+
+const ALICE: AccountId = AccountId::new([1u8; 32]);
+const ALICE_WORKER: AccountId = AccountId::new([2u8; 32]);
+const BOB: AccountId = AccountId::new([3u8; 32]);
+const BOB_WORKER: AccountId = AccountId::new([4u8; 32]);
+
+type PoolId = u32;
+type PolicyId = u32;
+type JobId = u32;
+
+fn held(reason: HoldReason, who: &AccountId) -> Balance {
+	Balances::balance_on_hold(&RuntimeHoldReason::OffchainComputingPool(reason), who)
+}
+
+fn inline_input(len: usize) -> Option<JobPayload<<Test as crate::Config>::InputLimit>> {
+	Some(JobPayload::Inline(BoundedVec::try_from(vec![0u8; len]).unwrap()))
+}
+
+fn mock_pool_and_policy(owner: AccountId) -> (PoolId, PolicyId) {
+	assert_ok!(OffchainComputingInfra::register_impl(
+		RuntimeOrigin::signed(owner.clone()),
+		AttestationMethod::OptOut,
+	));
+	assert_ok!(OffchainComputingPool::create_pool(
+		RuntimeOrigin::signed(owner.clone()),
+		101,
+		JobScheduler::DemoOnly,
+		true,
+		false,
+	));
+	assert_ok!(OffchainComputingPool::create_job_policy(
+		RuntimeOrigin::signed(owner),
+		101,
+		ApplicableScope::Public,
+		None,
+		None,
+	));
+
+	(101, 1)
+}
+
+fn mock_subscribed_worker(owner: AccountId, worker: AccountId, pool_id: PoolId) {
+	assert_ok!(OffchainComputingInfra::register_impl_build(
+		RuntimeOrigin::signed(owner.clone()),
+		101,
+		1,
+		None,
+	));
+	mock_capable_worker(owner, worker, pool_id, Default::default(), None, None);
+}
+
+/// Register a worker of the registered impl build, then online it with the capabilities and
+/// the encryption public key.
+fn mock_capable_worker(
+	owner: AccountId,
+	worker: AccountId,
+	pool_id: PoolId,
+	capabilities: WorkerCapabilities,
+	job_slots: Option<u32>,
+	encryption_public_key: Option<EncryptionPublicKey>,
+) {
+	assert_ok!(OffchainComputingInfra::register_worker(
+		RuntimeOrigin::signed(owner.clone()),
+		worker.clone(),
+		101,
+		101 * DOLLARS,
+	));
+	assert_ok!(OffchainComputingInfra::online(
+		RuntimeOrigin::signed(worker.clone()),
+		OnlinePayload {
+			impl_id: 101,
+			impl_spec_version: 1,
+			impl_build_version: 1,
+			impl_build_magic_bytes: Default::default(),
+			encryption_public_key,
+			capabilities,
+			job_slots,
+		},
+		Attestation::OptOut,
+	));
+	assert_ok!(OffchainComputingPool::authorize_worker(
+		RuntimeOrigin::signed(owner),
+		pool_id,
+		worker.clone(),
+	));
+	assert_ok!(OffchainComputingPool::subscribe_pool(RuntimeOrigin::signed(worker), pool_id));
+}
+
+fn create_job_for(who: AccountId, pool_id: PoolId, policy_id: PolicyId, input_len: usize) -> JobId {
+	let job_id = crate::NextJobId::<Test>::get(pool_id).unwrap_or(1);
+	assert_ok!(OffchainComputingPool::create_job(
+		RuntimeOrigin::signed(who),
+		pool_id,
+		policy_id,
+		None,
+		None,
+		1,
+		inline_input(input_len),
+		EncryptionScheme::Plain,
+		None,
+		None,
+		None,
+	));
+
+	job_id
+}
+
+/// The held deposits of the account must equal to the deposits recorded in jobs.
+fn assert_job_deposits_consistent(who: &AccountId) {
+	let job_deposits = Jobs::<Test>::iter_values()
+		.filter(|job| &job.depositor == who)
+		.fold(0, |acc, job| acc + job.deposit);
+	assert_eq!(held(HoldReason::JobDepositorReserve, who), job_deposits);
+
+	let storage_deposits = JobInputs::<Test>::iter_values()
+		.filter(|entry| &entry.depositor == who)
+		.fold(0, |acc, entry| acc + entry.actual_deposit + entry.surplus_deposit);
+	assert_eq!(held(HoldReason::JobStorageReserve, who), storage_deposits);
+}
+
+fn pre_signed_job_signer(pair: &sr25519::Pair) -> AccountId {
+	AccountPublic::from(pair.public()).into_account()
+}
+
+fn pre_signed_job(pool_id: PoolId, policy_id: PolicyId) -> crate::PreSignedJobOf<Test> {
+	crate::PreSignedJobOf::<Test> {
+		pool_id,
+		policy_id,
+		unique_track_id: None,
+		beneficiary: None,
+		impl_spec_version: 1,
+		input: Some(vec![0u8; 10]),
+		input_encryption: EncryptionScheme::Plain,
+		soft_expires_in: None,
+		only_relayer: None,
+		deadline: 10,
+		nonce: 0,
+	}
+}
+
+fn sign_pre_signed_job(pair: &sr25519::Pair, job_data: &crate::PreSignedJobOf<Test>) -> Signature {
+	Signature::Sr25519(pair.sign(&OffchainComputingPool::pre_signed_job_message(job_data)))
+}
+
+#[test]
+fn create_job_pre_signed_works() {
+	build_and_execute(|| {
+		let signer_pair = sr25519::Pair::from_seed(&[5u8; 32]);
+		let signer = pre_signed_job_signer(&signer_pair);
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		set_balance(signer.clone(), 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		let job_data = pre_signed_job(pool_id, policy_id);
+
+		// Signing the bare job data isn't enough, the message is bound to the pallet and the chain
+		let signature = Signature::Sr25519(signer_pair.sign(&job_data.encode()));
+		assert_noop!(
+			OffchainComputingPool::create_job_pre_signed(
+				RuntimeOrigin::signed(BOB),
+				Box::new(job_data.clone()),
+				signature,
+				signer.clone(),
+				false,
+			),
+			Error::<Test>::WrongSignature
+		);
+
+		// Signed by someone else
+		let signature = sign_pre_signed_job(&sr25519::Pair::from_seed(&[6u8; 32]), &job_data);
+		assert_noop!(
+			OffchainComputingPool::create_job_pre_signed(
+				RuntimeOrigin::signed(BOB),
+				Box::new(job_data.clone()),
+				signature,
+				signer.clone(),
+				false,
+			),
+			Error::<Test>::WrongSignature
+		);
+
+		let signature = sign_pre_signed_job(&signer_pair, &job_data);
+		assert_ok!(OffchainComputingPool::create_job_pre_signed(
+			RuntimeOrigin::signed(BOB),
+			Box::new(job_data.clone()),
+			signature.clone(),
+			signer.clone(),
+			false,
+		));
+		let job = Jobs::<Test>::get(pool_id, 1).unwrap();
+		assert_eq!(job.depositor, signer);
+		assert_eq!(job.beneficiary, signer);
+		assert_eq!(crate::PreSignedJobNonces::<Test>::get(&signer), 1);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &signer), job.deposit);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &BOB), 0);
+
+		// The nonce is consumed, so the message can't be relayed again
+		assert_noop!(
+			OffchainComputingPool::create_job_pre_signed(
+				RuntimeOrigin::signed(BOB),
+				Box::new(job_data),
+				signature,
+				signer.clone(),
+				false,
+			),
+			Error::<Test>::InvalidNonce
+		);
+	});
+}
+
+#[test]
+fn create_job_pre_signed_respects_relayer_and_deadline() {
+	build_and_execute(|| {
+		let signer_pair = sr25519::Pair::from_seed(&[5u8; 32]);
+		let signer = pre_signed_job_signer(&signer_pair);
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		set_balance(signer.clone(), 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+
+		let job_data = crate::PreSignedJobOf::<Test> {
+			only_relayer: Some(BOB),
+			..pre_signed_job(pool_id, policy_id)
+		};
+		let signature = sign_pre_signed_job(&signer_pair, &job_data);
+		assert_noop!(
+			OffchainComputingPool::create_job_pre_signed(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(job_data.clone()),
+				signature.clone(),
+				signer.clone(),
+				false,
+			),
+			Error::<Test>::WrongRelayer
+		);
+
+		// The relayer pays the deposits, the signer is still the beneficiary
+		assert_ok!(OffchainComputingPool::create_job_pre_signed(
+			RuntimeOrigin::signed(BOB),
+			Box::new(job_data),
+			signature,
+			signer.clone(),
+			true,
+		));
+		let job = Jobs::<Test>::get(pool_id, 1).unwrap();
+		assert_eq!(job.depositor, BOB);
+		assert_eq!(job.beneficiary, signer);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &BOB), job.deposit);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &signer), 0);
+		assert_eq!(held(HoldReason::JobStorageReserve, &signer), 0);
+
+		run_to_block(3);
+		let job_data = crate::PreSignedJobOf::<Test> {
+			deadline: 2,
+			nonce: 1,
+			..pre_signed_job(pool_id, policy_id)
+		};
+		let signature = sign_pre_signed_job(&signer_pair, &job_data);
+		assert_noop!(
+			OffchainComputingPool::create_job_pre_signed(
+				RuntimeOrigin::signed(BOB),
+				Box::new(job_data),
+				signature,
+				signer,
+				false,
+			),
+			Error::<Test>::DeadlineExpired
+		);
+	});
+}
+
+fn create_sponsored_job_for(
+	beneficiary: AccountId,
+	sponsor: AccountId,
+	pool_id: PoolId,
+	policy_id: PolicyId,
+) -> DispatchResult {
+	OffchainComputingPool::create_sponsored_job(
+		RuntimeOrigin::signed(beneficiary),
+		pool_id,
+		policy_id,
+		sponsor,
+		None,
+		1,
+		inline_input(10),
+		EncryptionScheme::Plain,
+		None,
+	)
+}
+
+#[test]
+fn job_sponsorship_funding_and_withdrawal_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+
+		assert_noop!(
+			OffchainComputingPool::fund_job_sponsorship(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				policy_id + 1,
+				10 * DOLLARS,
+				DOLLARS,
+			),
+			Error::<Test>::JobPolicyNotFound
+		);
+
+		assert_ok!(OffchainComputingPool::fund_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			10 * DOLLARS,
+			DOLLARS,
+		));
+		// Funding again accumulates the budget and replaces the allowance
+		assert_ok!(OffchainComputingPool::fund_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			5 * DOLLARS,
+			2 * DOLLARS,
+		));
+		System::assert_last_event(
+			Event::<Test>::JobSponsorshipFunded {
+				pool_id,
+				policy_id,
+				sponsor: BOB,
+				amount: 5 * DOLLARS,
+				budget: 15 * DOLLARS,
+				allowance_per_account: 2 * DOLLARS,
+			}
+			.into(),
+		);
+		assert_eq!(
+			JobSponsorships::<Test>::get((pool_id, policy_id, BOB)),
+			Some(JobSponsorship { budget: 15 * DOLLARS, allowance_per_account: 2 * DOLLARS })
+		);
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 15 * DOLLARS);
+
+		assert_noop!(
+			OffchainComputingPool::withdraw_job_sponsorship(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				policy_id,
+				Some(20 * DOLLARS),
+			),
+			Error::<Test>::InsufficientJobSponsorshipBudget
+		);
+		assert_ok!(OffchainComputingPool::withdraw_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			Some(5 * DOLLARS),
+		));
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 10 * DOLLARS);
+
+		// Withdrawing all removes the sponsorship
+		assert_ok!(OffchainComputingPool::withdraw_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			None,
+		));
+		System::assert_last_event(
+			Event::<Test>::JobSponsorshipWithdrawn {
+				pool_id,
+				policy_id,
+				sponsor: BOB,
+				amount: 10 * DOLLARS,
+				budget: 0,
+			}
+			.into(),
+		);
+		assert_eq!(JobSponsorships::<Test>::get((pool_id, policy_id, BOB)), None);
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 0);
+		assert_eq!(Balances::free_balance(&BOB), 100 * DOLLARS);
+
+		assert_noop!(
+			OffchainComputingPool::withdraw_job_sponsorship(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				policy_id,
+				None,
+			),
+			Error::<Test>::JobSponsorshipNotFound
+		);
+	});
+}
+
+#[test]
+fn sponsored_job_respects_budget_and_allowance() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		set_balance(ALICE_WORKER, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		// The job creation deposit and the storage deposit of a 10 bytes input
+		let cost = DOLLARS + 10 * CENTS;
+
+		assert_noop!(
+			create_sponsored_job_for(ALICE, BOB, pool_id, policy_id),
+			Error::<Test>::JobSponsorshipNotFound
+		);
+
+		assert_ok!(OffchainComputingPool::fund_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			3 * cost - 1,
+			2 * cost,
+		));
+
+		assert_ok!(create_sponsored_job_for(ALICE, BOB, pool_id, policy_id));
+		System::assert_last_event(
+			Event::<Test>::JobSponsored {
+				pool_id,
+				job_id: 1,
+				sponsor: BOB,
+				beneficiary: ALICE,
+				amount: cost,
+			}
+			.into(),
+		);
+		let job = Jobs::<Test>::get(pool_id, 1).unwrap();
+		assert_eq!(job.depositor, BOB);
+		assert_eq!(job.beneficiary, ALICE);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &ALICE), 0);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 0);
+
+		assert_ok!(create_sponsored_job_for(ALICE, BOB, pool_id, policy_id));
+		assert_eq!(JobSponsorshipSpending::<Test>::get((pool_id, policy_id, BOB, ALICE)), 2 * cost);
+		assert_noop!(
+			create_sponsored_job_for(ALICE, BOB, pool_id, policy_id),
+			Error::<Test>::JobSponsorshipAllowanceExceeded
+		);
+
+		// Another beneficiary has its own allowance, but the budget is shared
+		assert_noop!(
+			create_sponsored_job_for(ALICE_WORKER, BOB, pool_id, policy_id),
+			Error::<Test>::JobSponsorshipBudgetExhausted
+		);
+
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), cost - 1);
+		assert_eq!(Balances::free_balance(&BOB), 100 * DOLLARS - 3 * cost + 1);
+		assert_job_deposits_consistent(&BOB);
+	});
+}
+
+#[test]
+fn destroyed_sponsored_job_returns_to_sponsorship() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		let cost = DOLLARS + 10 * CENTS;
+
+		assert_ok!(OffchainComputingPool::fund_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			10 * DOLLARS,
+			cost,
+		));
+		assert_ok!(create_sponsored_job_for(ALICE, BOB, pool_id, policy_id));
+		assert_eq!(SponsoredJobs::<Test>::get(pool_id, 1), Some(cost));
+		assert_noop!(
+			create_sponsored_job_for(ALICE, BOB, pool_id, policy_id),
+			Error::<Test>::JobSponsorshipAllowanceExceeded
+		);
+
+		// The refund returns to the budget, and the allowance is available again
+		assert_ok!(OffchainComputingPool::destroy_job(RuntimeOrigin::signed(ALICE), pool_id, 1));
+		assert_eq!(SponsoredJobs::<Test>::get(pool_id, 1), None);
+		assert_eq!(JobSponsorshipSpending::<Test>::get((pool_id, policy_id, BOB, ALICE)), 0);
+		assert_eq!(
+			JobSponsorships::<Test>::get((pool_id, policy_id, BOB)).map(|s| s.budget),
+			Some(10 * DOLLARS)
+		);
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 10 * DOLLARS);
+		assert_eq!(Balances::free_balance(&BOB), 90 * DOLLARS);
+
+		assert_ok!(create_sponsored_job_for(ALICE, BOB, pool_id, policy_id));
+
+		// The sponsor keeps the refund if the sponsorship has been withdrawn
+		assert_ok!(OffchainComputingPool::withdraw_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			None,
+		));
+		assert_ok!(OffchainComputingPool::destroy_job(RuntimeOrigin::signed(ALICE), pool_id, 2));
+		assert_eq!(JobSponsorships::<Test>::get((pool_id, policy_id, BOB)), None);
+		assert_eq!(JobSponsorshipSpending::<Test>::get((pool_id, policy_id, BOB, ALICE)), 0);
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 0);
+		assert_eq!(Balances::free_balance(&BOB), 100 * DOLLARS);
+	});
+}
+
+fn create_encrypted_job_for(
+	who: AccountId,
+	pool_id: PoolId,
+	policy_id: PolicyId,
+	impl_spec_version: ImplSpecVersion,
+	input: Option<JobPayload<<Test as crate::Config>::InputLimit>>,
+	input_encryption: EncryptionScheme<AccountId>,
+) -> DispatchResult {
+	OffchainComputingPool::create_job(
+		RuntimeOrigin::signed(who),
+		pool_id,
+		policy_id,
+		None,
+		None,
+		impl_spec_version,
+		input,
+		input_encryption,
+		None,
+		None,
+		None,
+	)
+}
+
+#[test]
+fn encrypted_job_is_designated_to_worker() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, BOB_WORKER, pool_id);
+		mock_capable_worker(
+			ALICE,
+			ALICE_WORKER,
+			pool_id,
+			Default::default(),
+			None,
+			Some([7u8; 32]),
+		);
+
+		// The encryption key is published when the worker goes online
+		assert_eq!(OffchainComputingInfra::worker_encryption_key(&ALICE_WORKER), Some([7u8; 32]));
+		assert_eq!(OffchainComputingInfra::worker_encryption_key(&BOB_WORKER), None);
+
+		assert_noop!(
+			create_encrypted_job_for(
+				ALICE,
+				pool_id,
+				policy_id,
+				1,
+				inline_input(10),
+				EncryptionScheme::NaclBoxToWorker(BOB_WORKER),
+			),
+			Error::<Test>::WorkerEncryptionKeyNotFound
+		);
+		assert_noop!(
+			create_encrypted_job_for(
+				ALICE,
+				pool_id,
+				policy_id,
+				1,
+				None,
+				EncryptionScheme::NaclBoxToWorker(ALICE_WORKER),
+			),
+			Error::<Test>::UnsupportedEncryptionScheme
+		);
+		assert_noop!(
+			create_encrypted_job_for(
+				ALICE,
+				pool_id,
+				policy_id,
+				1,
+				inline_input(10),
+				EncryptionScheme::SealedToBeneficiary,
+			),
+			Error::<Test>::UnsupportedEncryptionScheme
+		);
+
+		assert_ok!(create_encrypted_job_for(
+			ALICE,
+			pool_id,
+			policy_id,
+			1,
+			inline_input(10),
+			EncryptionScheme::NaclBoxToWorker(ALICE_WORKER),
+		));
+		assert!(DesignatedJobs::<Test>::contains_key((pool_id, ALICE_WORKER, 1)));
+		assert!(!AssignableJobs::<Test>::contains_key((pool_id, 1, 1)));
+
+		// Other workers can't take the job
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(BOB_WORKER),
+				pool_id,
+				None,
+				true,
+				None,
+			),
+			Error::<Test>::NoAssignableJob
+		);
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(BOB_WORKER),
+				pool_id,
+				Some(1),
+				true,
+				None,
+			),
+			Error::<Test>::JobEncryptedForAnotherWorker
+		);
+
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			None,
+			true,
+			None,
+		));
+		assert_eq!(Jobs::<Test>::get(pool_id, 1).unwrap().assignee, Some(ALICE_WORKER));
+		assert!(!DesignatedJobs::<Test>::contains_key((pool_id, ALICE_WORKER, 1)));
+
+		// The output can only be sealed to the beneficiary
+		assert_noop!(
+			OffchainComputingPool::submit_job_result(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				1,
+				JobResult::Success,
+				None,
+				EncryptionScheme::NaclBoxToWorker(ALICE_WORKER),
+				None,
+				None,
+			),
+			Error::<Test>::UnsupportedEncryptionScheme
+		);
+		assert_ok!(OffchainComputingPool::submit_job_result(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			1,
+			JobResult::Success,
+			Some(JobPayload::Inline(BoundedVec::try_from(vec![1u8; 10]).unwrap())),
+			EncryptionScheme::SealedToBeneficiary,
+			None,
+			None,
+		));
+		assert_eq!(
+			JobOutputs::<Test>::get(pool_id, 1).unwrap().encryption,
+			EncryptionScheme::SealedToBeneficiary
+		);
+	});
+}
+
+#[test]
+fn take_job_skips_designated_job_of_other_impl_spec_version() {
+	build_and_execute(|| {
+		set_balance(ALICE, 300 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		assert_ok!(OffchainComputingPool::update_pool_settings(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			1,
+			2,
+			JobScheduler::DemoOnly,
+			true,
+			false,
+		));
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(ALICE),
+			101,
+			1,
+			None,
+		));
+		mock_capable_worker(
+			ALICE,
+			ALICE_WORKER,
+			pool_id,
+			Default::default(),
+			None,
+			Some([7u8; 32]),
+		);
+
+		assert_ok!(create_encrypted_job_for(
+			ALICE,
+			pool_id,
+			policy_id,
+			2,
+			inline_input(10),
+			EncryptionScheme::NaclBoxToWorker(ALICE_WORKER),
+		));
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 10);
+
+		// The designated job requires another impl spec version, so the plain one is taken
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			None,
+			true,
+			None,
+		));
+		assert_eq!(Jobs::<Test>::get(pool_id, job_id).unwrap().assignee, Some(ALICE_WORKER));
+		assert!(DesignatedJobs::<Test>::contains_key((pool_id, ALICE_WORKER, 1)));
+
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				Some(1),
+				true,
+				None,
+			),
+			Error::<Test>::ImplMismatched
+		);
+	});
+}
+
+fn data_reference(uri: &[u8], size: u64, hash: DataHash) -> DataReference {
+	DataReference { uri: BoundedVec::try_from(uri.to_vec()).unwrap(), size, hash }
+}
+
+#[test]
+fn referenced_job_payloads_work() {
+	build_and_execute(|| {
+		set_balance(ALICE, 300 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+
+		let invalid_references =
+			[data_reference(b"", 1024, [1u8; 32]), data_reference(b"ipfs://in", 0, [1u8; 32])];
+		for reference in invalid_references {
+			assert_noop!(
+				create_encrypted_job_for(
+					ALICE,
+					pool_id,
+					policy_id,
+					1,
+					Some(JobPayload::Reference(reference)),
+					EncryptionScheme::Plain,
+				),
+				Error::<Test>::InvalidDataReference
+			);
+		}
+
+		// The deposit is charged on the reference instead of the referenced data
+		let input_reference = data_reference(b"ipfs://in", 1024 * 1024, [1u8; 32]);
+		let input_deposit = input_reference.encoded_size() as Balance * CENTS;
+		assert_ok!(create_encrypted_job_for(
+			ALICE,
+			pool_id,
+			policy_id,
+			1,
+			Some(JobPayload::Reference(input_reference.clone())),
+			EncryptionScheme::Plain,
+		));
+		let input_entry = JobInputs::<Test>::get(pool_id, 1).unwrap();
+		assert_eq!(input_entry.data, JobPayload::Reference(input_reference));
+		assert_eq!(input_entry.actual_deposit, input_deposit);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), input_deposit);
+
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			Some(1),
+			true,
+			None,
+		));
+		assert_noop!(
+			OffchainComputingPool::submit_job_result(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				1,
+				JobResult::Success,
+				Some(JobPayload::Reference(data_reference(b"", 2048, [2u8; 32]))),
+				EncryptionScheme::Plain,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidDataReference
+		);
+
+		// The worker commits to the hash of the off-chain output
+		let output_reference = data_reference(b"ar://out", 2048, [2u8; 32]);
+		let output_deposit = output_reference.encoded_size() as Balance * CENTS;
+		assert_ok!(OffchainComputingPool::submit_job_result(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			1,
+			JobResult::Success,
+			Some(JobPayload::Reference(output_reference.clone())),
+			EncryptionScheme::Plain,
+			None,
+			None,
+		));
+		System::assert_has_event(
+			Event::<Test>::JobResultUpdated {
+				pool_id,
+				job_id: 1,
+				result: JobResult::Success,
+				output: Some(JobPayload::Reference(output_reference.clone())),
+				output_hash: Some([2u8; 32]),
+				output_encryption: EncryptionScheme::Plain,
+				proof: None,
+			}
+			.into(),
+		);
+		let output_entry = JobOutputs::<Test>::get(pool_id, 1).unwrap();
+		assert_eq!(output_entry.data, JobPayload::Reference(output_reference));
+		assert_eq!(output_entry.actual_deposit, output_deposit);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE_WORKER), output_deposit);
+	});
+}
+
+#[test]
+fn update_job_input_keeps_surplus_deposit() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 20);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 20 * CENTS);
+		assert_job_deposits_consistent(&ALICE);
+
+		// Shrinking keeps the over-payment as surplus
+		assert_ok!(OffchainComputingPool::update_job_input(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id,
+			inline_input(10),
+			EncryptionScheme::Plain,
+		));
+		let input_entry = JobInputs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(input_entry.actual_deposit, 10 * CENTS);
+		assert_eq!(input_entry.surplus_deposit, 10 * CENTS);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 20 * CENTS);
+		assert_job_deposits_consistent(&ALICE);
+
+		// Growing consumes the surplus first
+		assert_ok!(OffchainComputingPool::update_job_input(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id,
+			inline_input(30),
+			EncryptionScheme::Plain,
+		));
+		let input_entry = JobInputs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(input_entry.actual_deposit, 30 * CENTS);
+		assert_eq!(input_entry.surplus_deposit, 0);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 30 * CENTS);
+		assert_job_deposits_consistent(&ALICE);
+
+		// Removing releases all
+		assert_ok!(OffchainComputingPool::update_job_input(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id,
+			None,
+			EncryptionScheme::Plain,
+		));
+		assert!(JobInputs::<Test>::get(pool_id, job_id).is_none());
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 0);
+		assert_job_deposits_consistent(&ALICE);
+	});
+}
+
+#[test]
+fn update_job_input_requires_depositor() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 20);
+
+		assert_noop!(
+			OffchainComputingPool::update_job_input(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				job_id,
+				inline_input(10),
+				EncryptionScheme::Plain,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			OffchainComputingPool::update_job_input(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				job_id,
+				inline_input(10),
+				EncryptionScheme::SealedToBeneficiary,
+			),
+			Error::<Test>::UnsupportedEncryptionScheme
+		);
+	});
+}
+
+#[test]
+fn reclaim_job_surplus_deposit_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 20);
+
+		assert_noop!(
+			OffchainComputingPool::reclaim_job_surplus_deposit(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				job_id
+			),
+			Error::<Test>::NoSurplusDeposit
+		);
+
+		assert_ok!(OffchainComputingPool::update_job_input(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id,
+			inline_input(5),
+			EncryptionScheme::Plain,
+		));
+
+		assert_noop!(
+			OffchainComputingPool::reclaim_job_surplus_deposit(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				job_id
+			),
+			Error::<Test>::NoPermission
+		);
+
+		let free_balance = Balances::free_balance(&ALICE);
+		assert_ok!(OffchainComputingPool::reclaim_job_surplus_deposit(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id
+		));
+		assert_eq!(Balances::free_balance(&ALICE), free_balance + 15 * CENTS);
+		assert_eq!(JobInputs::<Test>::get(pool_id, job_id).unwrap().surplus_deposit, 0);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 5 * CENTS);
+		assert_job_deposits_consistent(&ALICE);
+	});
+}
+
+#[test]
+fn top_up_job_deposit_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 20);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &ALICE), DOLLARS);
+
+		assert_ok!(OffchainComputingPool::top_up_job_deposit(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id,
+			2 * DOLLARS,
+		));
+		assert_eq!(Jobs::<Test>::get(pool_id, job_id).unwrap().deposit, 3 * DOLLARS);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &ALICE), 3 * DOLLARS);
+		assert_job_deposits_consistent(&ALICE);
+
+		// Destroying the job releases all deposits including the surplus
+		assert_ok!(OffchainComputingPool::update_job_input(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id,
+			inline_input(5),
+			EncryptionScheme::Plain,
+		));
+		assert_ok!(OffchainComputingPool::destroy_job(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id
+		));
+		assert_eq!(held(HoldReason::JobDepositorReserve, &ALICE), 0);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 0);
+		assert_job_deposits_consistent(&ALICE);
+	});
+}
+
+const GAS_LIMIT: Weight = Weight::from_parts(100_000_000_000, 3 * 1024 * 1024);
+
+/// Deploy the fixture contract which forwards the call data to the chain extension.
+fn instantiate_chain_extension_proxy(owner: AccountId, value: Balance) -> AccountId {
+	let wasm = wat::parse_str(include_str!("../fixtures/chain_extension_proxy.wat")).unwrap();
+	Contracts::bare_instantiate(
+		owner,
+		value,
+		GAS_LIMIT,
+		None,
+		Code::Upload(wasm),
+		vec![],
+		vec![],
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+	)
+	.result
+	.unwrap()
+	.account_id
+}
+
+fn call_chain_extension(
+	caller: AccountId,
+	contract: AccountId,
+	func_id: u16,
+	input: impl Encode,
+) -> Vec<u8> {
+	let mut data = (func_id as u32).encode();
+	data.extend(input.encode());
+
+	let result = Contracts::bare_call(
+		caller,
+		contract,
+		0,
+		GAS_LIMIT,
+		None,
+		data,
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+		Determinism::Enforced,
+	)
+	.result
+	.unwrap();
+	assert!(!result.did_revert());
+
+	result.data
+}
+
+#[test]
+fn contract_creates_job_and_reads_result_via_chain_extension() {
+	build_and_execute(|| {
+		set_balance(ALICE, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		let contract = instantiate_chain_extension_proxy(ALICE, 10 * DOLLARS);
+
+		let output = call_chain_extension(
+			ALICE,
+			contract.clone(),
+			crate::CREATE_JOB_FUNC_ID,
+			(
+				pool_id,
+				policy_id,
+				None::<UniqueTrackId>,
+				1 as ImplSpecVersion,
+				inline_input(10),
+				EncryptionScheme::<AccountId>::Plain,
+				None::<u64>,
+				None::<crate::JobCallbackRequest<AccountId, Balance>>,
+			),
+		);
+		let job_id = JobId::decode(&mut &output[..]).unwrap();
+
+		// The contract pays the deposits
+		let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(job.depositor, contract);
+		assert_eq!(job.beneficiary, contract);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &contract), DOLLARS);
+		assert_eq!(held(HoldReason::JobStorageReserve, &contract), 10 * CENTS);
+
+		let output = call_chain_extension(
+			ALICE,
+			contract.clone(),
+			crate::JOB_STATUS_FUNC_ID,
+			(pool_id, job_id),
+		);
+		assert_eq!(
+			Option::<(JobStatus, Option<JobResult>)>::decode(&mut &output[..]).unwrap(),
+			Some((JobStatus::Pending, None))
+		);
+
+		type JobOutput = JobPayload<<Test as crate::Config>::OutputLimit>;
+		let output = call_chain_extension(
+			ALICE,
+			contract.clone(),
+			crate::JOB_OUTPUT_FUNC_ID,
+			(pool_id, job_id),
+		);
+		assert_eq!(Option::<JobOutput>::decode(&mut &output[..]).unwrap(), None);
+
+		let job_output = JobOutput::Inline(BoundedVec::try_from(vec![1u8; 8]).unwrap());
+		crate::JobOutputs::<Test>::insert(
+			pool_id,
+			job_id,
+			JobData {
+				depositor: ALICE,
+				actual_deposit: 0,
+				surplus_deposit: 0,
+				encryption: EncryptionScheme::Plain,
+				data: job_output.clone(),
+			},
+		);
+		let output =
+			call_chain_extension(ALICE, contract, crate::JOB_OUTPUT_FUNC_ID, (pool_id, job_id));
+		assert_eq!(Option::<JobOutput>::decode(&mut &output[..]).unwrap(), Some(job_output));
+	});
+}
+
+fn create_job_with_callback(
+	who: AccountId,
+	pool_id: PoolId,
+	policy_id: PolicyId,
+	callback: crate::JobCallbackRequest<AccountId, Balance>,
+) -> JobId {
+	let job_id = crate::NextJobId::<Test>::get(pool_id).unwrap_or(1);
+	assert_ok!(OffchainComputingPool::create_job(
+		RuntimeOrigin::signed(who),
+		pool_id,
+		policy_id,
+		None,
+		None,
+		1,
+		None,
+		EncryptionScheme::Plain,
+		None,
+		None,
+		Some(callback),
+	));
+
+	job_id
+}
+
+fn process_job(worker: AccountId, pool_id: PoolId, job_id: JobId) {
+	assert_ok!(OffchainComputingPool::take_job(
+		RuntimeOrigin::signed(worker.clone()),
+		pool_id,
+		Some(job_id),
+		true,
+		None,
+	));
+	assert_ok!(OffchainComputingPool::submit_job_result(
+		RuntimeOrigin::signed(worker),
+		pool_id,
+		job_id,
+		JobResult::Success,
+		None,
+		EncryptionScheme::Plain,
+		None,
+		None,
+	));
+}
+
+#[test]
+fn job_callback_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 300 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		let contract = instantiate_chain_extension_proxy(ALICE, 10 * DOLLARS);
+
+		// The proxy forwards the callback to read the job's status
+		let gas_limit = Weight::from_parts(10_000_000_000, 1024 * 1024);
+		let job_id = create_job_with_callback(
+			ALICE,
+			pool_id,
+			policy_id,
+			crate::JobCallbackRequest {
+				contract,
+				selector: (crate::JOB_STATUS_FUNC_ID as u32).to_le_bytes(),
+				gas_limit,
+				storage_deposit_limit: DOLLARS,
+			},
+		);
+		// The storage deposit limit is held along with the fee of the gas limit
+		let prepaid_fee = gas_limit.ref_time() as Balance;
+		assert_eq!(held(HoldReason::JobCallbackReserve, &ALICE), prepaid_fee + DOLLARS);
+
+		let alice_balance = Balances::free_balance(&ALICE);
+		let worker_balance = Balances::free_balance(&ALICE_WORKER);
+		process_job(ALICE_WORKER, pool_id, job_id);
+
+		let Some(Event::JobCallbackExecuted { gas_consumed, .. }) = take_events()
+			.into_iter()
+			.filter_map(|e| {
+				if let RuntimeEvent::OffchainComputingPool(inner) = e {
+					Some(inner)
+				} else {
+					None
+				}
+			})
+			.find(|e| matches!(e, Event::JobCallbackExecuted { .. }))
+		else {
+			panic!("Must have `JobCallbackExecuted` event");
+		};
+
+		// The worker earns the fee of the consumed gas, and the rest is released, the callback
+		// doesn't write storage so no storage deposit is charged
+		let fee = gas_consumed.ref_time() as Balance;
+		assert_eq!(held(HoldReason::JobCallbackReserve, &ALICE), 0);
+		assert_eq!(Balances::free_balance(&ALICE_WORKER), worker_balance + fee);
+		assert_eq!(Balances::free_balance(&ALICE), alice_balance + prepaid_fee + DOLLARS - fee);
+
+		let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(job.status, JobStatus::Processed);
+		let callback = job.callback.unwrap();
+		assert_eq!(callback.prepaid_fee, 0);
+		assert_eq!(callback.storage_deposit_limit, 0);
+	});
+}
+
+#[test]
+fn job_callback_failure_does_not_revert_result_submission() {
+	build_and_execute(|| {
+		set_balance(ALICE, 300 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		let contract = instantiate_chain_extension_proxy(ALICE, 10 * DOLLARS);
+
+		// The proxy forwards the callback to an unknown function, which makes the contract trap
+		let job_id = create_job_with_callback(
+			ALICE,
+			pool_id,
+			policy_id,
+			crate::JobCallbackRequest {
+				contract,
+				selector: [0xff, 0, 0, 0],
+				gas_limit: Weight::from_parts(10_000_000_000, 1024 * 1024),
+				storage_deposit_limit: DOLLARS,
+			},
+		);
+
+		process_job(ALICE_WORKER, pool_id, job_id);
+
+		assert!(System::events().into_iter().any(|r| matches!(
+			r.event,
+			RuntimeEvent::OffchainComputingPool(Event::JobCallbackFailed { .. })
+		)));
+		assert_eq!(Jobs::<Test>::get(pool_id, job_id).unwrap().status, JobStatus::Processed);
+		assert_eq!(held(HoldReason::JobCallbackReserve, &ALICE), 0);
+
+		assert_noop!(
+			OffchainComputingPool::create_job(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				None,
+				None,
+				1,
+				None,
+				EncryptionScheme::Plain,
+				None,
+				None,
+				Some(crate::JobCallbackRequest {
+					contract: BOB,
+					selector: [0; 4],
+					gas_limit: Weight::from_parts(1, 1),
+					storage_deposit_limit: 0,
+				}),
+			),
+			Error::<Test>::JobCallbackContractNotFound
+		);
+	});
+}
+
+fn instantiate_rate_contract(owner: AccountId) -> AccountId {
+	let wasm = wat::parse_str(include_str!("../fixtures/rate_contract.wat")).unwrap();
+	Contracts::bare_instantiate(
+		owner,
+		0,
+		GAS_LIMIT,
+		None,
+		Code::Upload(wasm),
+		vec![],
+		vec![],
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+	)
+	.result
+	.unwrap()
+	.account_id
+}
+
+#[test]
+fn contract_rate_strategy_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 300 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		let contract = instantiate_rate_contract(ALICE);
+
+		assert_noop!(
+			OffchainComputingPool::update_job_policy_rate_strategy(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				JobRateStrategy::Contract(contract.clone()),
+			),
+			Error::<Test>::RateContractNotAllowed
+		);
+		assert_noop!(
+			OffchainComputingPool::add_rate_contract(
+				RuntimeOrigin::signed(ALICE),
+				contract.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			OffchainComputingPool::add_rate_contract(RuntimeOrigin::root(), BOB),
+			Error::<Test>::ContractNotFound
+		);
+
+		assert_ok!(OffchainComputingPool::add_rate_contract(
+			RuntimeOrigin::root(),
+			contract.clone()
+		));
+		assert_ok!(OffchainComputingPool::update_job_policy_rate_strategy(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			JobRateStrategy::Contract(contract.clone()),
+		));
+
+		// The fee quoted when creating is held from the depositor
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 0);
+		assert_eq!(held(HoldReason::JobFeeReserve, &ALICE), 10 * DOLLARS);
+		assert_eq!(Jobs::<Test>::get(pool_id, job_id).unwrap().fee, 10 * DOLLARS);
+
+		// The worker earns the fee quoted when settling, and the rest is released
+		let alice_balance = Balances::free_balance(&ALICE);
+		let worker_balance = Balances::free_balance(&ALICE_WORKER);
+		process_job(ALICE_WORKER, pool_id, job_id);
+
+		assert_eq!(held(HoldReason::JobFeeReserve, &ALICE), 0);
+		assert_eq!(Balances::free_balance(&ALICE_WORKER), worker_balance + 4 * DOLLARS);
+		assert_eq!(Balances::free_balance(&ALICE), alice_balance + 6 * DOLLARS);
+		assert_eq!(Jobs::<Test>::get(pool_id, job_id).unwrap().fee, 0);
+
+		// Policies using the removed contract can't create new jobs
+		assert_ok!(OffchainComputingPool::remove_rate_contract(RuntimeOrigin::root(), contract));
+		assert_noop!(
+			OffchainComputingPool::create_job(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				None,
+				None,
+				1,
+				None,
+				EncryptionScheme::Plain,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::RateContractNotAllowed
+		);
+	});
+}
+
+#[test]
+fn sponsored_job_pays_fee_from_sponsorship() {
+	build_and_execute(|| {
+		set_balance(ALICE, 300 * DOLLARS, 0);
+		set_balance(BOB, 100 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		let contract = instantiate_rate_contract(ALICE);
+		assert_ok!(OffchainComputingPool::add_rate_contract(
+			RuntimeOrigin::root(),
+			contract.clone()
+		));
+		assert_ok!(OffchainComputingPool::update_job_policy_rate_strategy(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			JobRateStrategy::Contract(contract),
+		));
+
+		// The deposits and the fee quoted when creating
+		let cost = DOLLARS + 10 * CENTS + 10 * DOLLARS;
+		assert_ok!(OffchainComputingPool::fund_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			20 * DOLLARS,
+			cost - 1,
+		));
+		assert_noop!(
+			create_sponsored_job_for(ALICE, BOB, pool_id, policy_id),
+			Error::<Test>::JobSponsorshipAllowanceExceeded
+		);
+
+		assert_ok!(OffchainComputingPool::fund_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			0,
+			2 * cost,
+		));
+		assert_ok!(create_sponsored_job_for(ALICE, BOB, pool_id, policy_id));
+		assert_eq!(Jobs::<Test>::get(pool_id, 1).unwrap().fee, 10 * DOLLARS);
+		assert_eq!(held(HoldReason::JobFeeReserve, &BOB), 10 * DOLLARS);
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 20 * DOLLARS - cost);
+		assert_eq!(Balances::free_balance(&BOB), 80 * DOLLARS);
+		assert_noop!(
+			create_sponsored_job_for(ALICE, BOB, pool_id, policy_id),
+			Error::<Test>::JobSponsorshipBudgetExhausted
+		);
+
+		// The rest of the fee returns to the budget when settling
+		process_job(ALICE_WORKER, pool_id, 1);
+		assert_eq!(held(HoldReason::JobFeeReserve, &BOB), 0);
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 26 * DOLLARS - cost);
+		assert_eq!(
+			JobSponsorshipSpending::<Test>::get((pool_id, policy_id, BOB, ALICE)),
+			cost - 6 * DOLLARS
+		);
+
+		// Then the deposits return when destroying, the budget only pays the worker's fee
+		assert_ok!(OffchainComputingPool::destroy_job(RuntimeOrigin::signed(ALICE), pool_id, 1));
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 16 * DOLLARS);
+		assert_eq!(JobSponsorshipSpending::<Test>::get((pool_id, policy_id, BOB, ALICE)), 0);
+		assert_eq!(Balances::free_balance(&BOB), 80 * DOLLARS);
+	});
+}
+
+fn capability(key: &[u8], value: u64) -> WorkerCapability {
+	WorkerCapability { key: BoundedVec::try_from(key.to_vec()).unwrap(), value }
+}
+
+#[test]
+fn capability_aware_job_matching_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		mock_capable_worker(
+			ALICE,
+			BOB_WORKER,
+			pool_id,
+			BoundedVec::try_from(vec![capability(b"memory_mb", 16384), capability(b"gpu", 1)])
+				.unwrap(),
+			None,
+			None,
+		);
+		assert!(!crate::WorkerDeclaredCapabilities::<Test>::get(&BOB_WORKER).unwrap().attested);
+
+		let requirements = JobRequirements {
+			capabilities: BoundedVec::try_from(vec![CapabilityRequirement {
+				key: BoundedVec::try_from(b"memory_mb".to_vec()).unwrap(),
+				min_value: 8192,
+			}])
+			.unwrap(),
+			attested_only: false,
+		};
+		let job_id = crate::NextJobId::<Test>::get(pool_id).unwrap_or(1);
+		assert_ok!(OffchainComputingPool::create_job(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			None,
+			None,
+			1,
+			None,
+			EncryptionScheme::Plain,
+			None,
+			Some(requirements.clone()),
+			None,
+		));
+
+		// The worker without the capability can't take the job
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				Some(job_id),
+				true,
+				None,
+			),
+			Error::<Test>::WorkerCapabilitiesMismatched
+		);
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				None,
+				true,
+				None,
+			),
+			Error::<Test>::NoAssignableJob
+		);
+
+		// The capable worker picks it
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(BOB_WORKER),
+			pool_id,
+			None,
+			true,
+			None,
+		));
+		assert_eq!(Jobs::<Test>::get(pool_id, job_id).unwrap().assignee, Some(BOB_WORKER));
+
+		// The `OptOut` attestation doesn't attest the capabilities
+		let job_id = crate::NextJobId::<Test>::get(pool_id).unwrap_or(1);
+		assert_ok!(OffchainComputingPool::create_job(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			None,
+			None,
+			1,
+			None,
+			EncryptionScheme::Plain,
+			None,
+			Some(JobRequirements { attested_only: true, ..requirements }),
+			None,
+		));
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(BOB_WORKER),
+				pool_id,
+				Some(job_id),
+				true,
+				None,
+			),
+			Error::<Test>::WorkerCapabilitiesMismatched
+		);
+	});
+}
+
+#[test]
+fn take_job_scan_is_bounded() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+
+		// Fill the scan window with jobs the worker can't take
+		let requirements = JobRequirements {
+			capabilities: BoundedVec::try_from(vec![CapabilityRequirement {
+				key: BoundedVec::try_from(b"memory_mb".to_vec()).unwrap(),
+				min_value: 8192,
+			}])
+			.unwrap(),
+			attested_only: false,
+		};
+		let scan_limit = <Test as crate::Config>::TakeJobScanLimit::get();
+		for _ in 0..scan_limit {
+			assert_ok!(OffchainComputingPool::create_job(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				None,
+				None,
+				1,
+				None,
+				EncryptionScheme::Plain,
+				None,
+				Some(requirements.clone()),
+				None,
+			));
+		}
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 0);
+
+		// The job beyond the window isn't found by scanning, but could be taken by id
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				None,
+				true,
+				None,
+			),
+			Error::<Test>::NoAssignableJob
+		);
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			Some(job_id),
+			true,
+			None,
+		));
+		assert_eq!(Jobs::<Test>::get(pool_id, job_id).unwrap().assignee, Some(ALICE_WORKER));
+	});
+}
+
+#[test]
+fn worker_job_slots_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		mock_capable_worker(ALICE, BOB_WORKER, pool_id, Default::default(), Some(2), None);
+
+		// Declared slots are capped by `MaxAssignedJobsPerWorker`
+		let max_job_slots = <Test as crate::Config>::MaxAssignedJobsPerWorker::get();
+		assert_eq!(OffchainComputingPool::worker_job_slots(&ALICE_WORKER), max_job_slots);
+		assert_eq!(OffchainComputingPool::worker_job_slots(&BOB_WORKER), 2);
+
+		for _ in 0..3 {
+			create_job_for(ALICE, pool_id, policy_id, 0);
+		}
+		for _ in 0..2 {
+			assert_ok!(OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(BOB_WORKER),
+				pool_id,
+				None,
+				true,
+				None,
+			));
+		}
+		assert_eq!(OffchainComputingPool::worker_free_job_slots(&BOB_WORKER), 0);
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(BOB_WORKER),
+				pool_id,
+				None,
+				true,
+				None,
+			),
+			Error::<Test>::WorkerAssignedJobsLimitExceeded
+		);
+
+		// Others still could take the job
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			None,
+			true,
+			None,
+		));
+		assert_eq!(OffchainComputingPool::worker_free_job_slots(&ALICE_WORKER), max_job_slots - 1);
+	});
+}
+
+#[test]
+fn cancel_job_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 300 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		let contract = instantiate_rate_contract(ALICE);
+		assert_ok!(OffchainComputingPool::add_rate_contract(
+			RuntimeOrigin::root(),
+			contract.clone()
+		));
+		assert_ok!(OffchainComputingPool::update_job_policy_rate_strategy(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			JobRateStrategy::Contract(contract),
+		));
+		assert_noop!(
+			OffchainComputingPool::update_job_policy_cancellation_compensation(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				JobCancellationCompensation::Percent(101),
+			),
+			Error::<Test>::InvalidCancellationCompensation
+		);
+		assert_ok!(OffchainComputingPool::update_job_policy_cancellation_compensation(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			JobCancellationCompensation::ProRata,
+		));
+
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 0);
+		assert_noop!(
+			OffchainComputingPool::cancel_job(RuntimeOrigin::signed(ALICE), pool_id, job_id),
+			Error::<Test>::JobIsNotProcessing
+		);
+
+		// The job expires in 18 seconds after processing
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			Some(job_id),
+			true,
+			None,
+		));
+		assert_eq!(crate::CounterForWorkerAssignedJobs::<Test>::get(&ALICE_WORKER), 1);
+		assert_noop!(
+			OffchainComputingPool::destroy_job(RuntimeOrigin::signed(ALICE), pool_id, job_id),
+			Error::<Test>::JobIsProcessing
+		);
+		assert_noop!(
+			OffchainComputingPool::cancel_job(RuntimeOrigin::signed(BOB), pool_id, job_id),
+			Error::<Test>::NoPermission
+		);
+
+		run_to_block(10);
+
+		// The worker gets the half of the fee, and the rest is refunded
+		let alice_balance = Balances::free_balance(&ALICE);
+		let worker_balance = Balances::free_balance(&ALICE_WORKER);
+		assert_ok!(OffchainComputingPool::cancel_job(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id
+		));
+
+		assert_eq!(Balances::free_balance(&ALICE_WORKER), worker_balance + 5 * DOLLARS);
+		assert_eq!(Balances::free_balance(&ALICE), alice_balance + 5 * DOLLARS);
+		assert_eq!(held(HoldReason::JobFeeReserve, &ALICE), 0);
+		assert_eq!(crate::CounterForWorkerAssignedJobs::<Test>::get(&ALICE_WORKER), 0);
+
+		let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(job.status, JobStatus::Cancelled);
+		assert_eq!(job.fee, 0);
+
+		// The worker can't submit the result any more, but the depositor could destroy it
+		assert_noop!(
+			OffchainComputingPool::submit_job_result(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				job_id,
+				JobResult::Success,
+				None,
+				EncryptionScheme::Plain,
+				None,
+				None,
+			),
+			Error::<Test>::JobIsProcessed
+		);
+		assert_ok!(OffchainComputingPool::destroy_job(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id
+		));
+		assert_eq!(crate::CounterForWorkerAssignedJobs::<Test>::get(&ALICE_WORKER), 0);
+	});
+}
+
+fn submit_panicked_job_result(worker: AccountId, pool_id: PoolId, job_id: JobId) {
+	assert_ok!(OffchainComputingPool::submit_job_result(
+		RuntimeOrigin::signed(worker),
+		pool_id,
+		job_id,
+		JobResult::Panic,
+		None,
+		EncryptionScheme::Plain,
+		None,
+		None,
+	));
+}
+
+#[test]
+fn panicked_job_retry_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		mock_capable_worker(ALICE, BOB_WORKER, pool_id, Default::default(), None, None);
+
+		assert_noop!(
+			OffchainComputingPool::update_job_policy_max_attempts(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				4,
+			),
+			Error::<Test>::InvalidMaxAttempts
+		);
+		assert_ok!(OffchainComputingPool::update_job_policy_max_attempts(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			2,
+		));
+
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 0);
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			Some(job_id),
+			true,
+			None,
+		));
+		assert!(crate::WorkerAssignedJobs::<Test>::contains_key((ALICE_WORKER, pool_id, job_id)));
+
+		// The job goes back to pending, and the failed worker is excluded
+		submit_panicked_job_result(ALICE_WORKER, pool_id, job_id);
+		let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(job.status, JobStatus::Pending);
+		assert_eq!(job.attempts, 1);
+		assert_eq!(job.assignee, None);
+		assert_eq!(crate::CounterForWorkerAssignedJobs::<Test>::get(&ALICE_WORKER), 0);
+		assert!(System::events().into_iter().any(|r| matches!(
+			r.event,
+			RuntimeEvent::OffchainComputingPool(Event::JobRetried { attempts: 1, .. })
+		)));
+
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				Some(job_id),
+				true,
+				None,
+			),
+			Error::<Test>::WorkerAlreadyFailedJob
+		);
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(ALICE_WORKER),
+				pool_id,
+				None,
+				true,
+				None,
+			),
+			Error::<Test>::NoAssignableJob
+		);
+
+		// Attempts exhausted, the job ends
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(BOB_WORKER),
+			pool_id,
+			None,
+			true,
+			None,
+		));
+		submit_panicked_job_result(BOB_WORKER, pool_id, job_id);
+		let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(job.status, JobStatus::Processed);
+		assert_eq!(job.result, Some(JobResult::Panic));
+		assert_eq!(job.attempts, 2);
+		assert_eq!(crate::CounterForWorkerAssignedJobs::<Test>::get(&BOB_WORKER), 0);
+		// Both attempts are reported to the build's health
+		let health = pallet_offchain_computing_infra::ImplBuildHealths::<Test>::get(101, 1);
+		assert_eq!(health.jobs_succeeded, 0);
+		assert_eq!(health.jobs_failed, 2);
+
+		assert_ok!(OffchainComputingPool::destroy_job(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			job_id
+		));
+		assert!(!crate::JobFailedWorkers::<Test>::contains_key((pool_id, job_id, ALICE_WORKER)));
+	});
+}
+
+#[test]
+fn retried_job_ends_when_every_worker_failed() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		mock_capable_worker(ALICE, BOB_WORKER, pool_id, Default::default(), None, None);
+		assert_ok!(OffchainComputingPool::update_job_policy_max_attempts(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			3,
+		));
+
+		let job_id = create_job_for(ALICE, pool_id, policy_id, 0);
+		for worker in [ALICE_WORKER, BOB_WORKER] {
+			assert_ok!(OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(worker.clone()),
+				pool_id,
+				None,
+				true,
+				None,
+			));
+			submit_panicked_job_result(worker, pool_id, job_id);
+		}
+
+		// No one else in the pool could take the job, so it ends though attempts left
+		let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(job.status, JobStatus::Processed);
+		assert_eq!(job.result, Some(JobResult::Panic));
+		assert_eq!(job.attempts, 2);
+		assert!(!AssignableJobs::<Test>::contains_key((pool_id, 1, job_id)));
+	});
+}
+
+#[test]
+fn designated_job_retry_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, BOB_WORKER, pool_id);
+		mock_capable_worker(
+			ALICE,
+			ALICE_WORKER,
+			pool_id,
+			Default::default(),
+			None,
+			Some([7u8; 32]),
+		);
+		assert_ok!(OffchainComputingPool::update_job_policy_max_attempts(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id,
+			2,
+		));
+
+		let job_id = crate::NextJobId::<Test>::get(pool_id).unwrap_or(1);
+		assert_ok!(create_encrypted_job_for(
+			ALICE,
+			pool_id,
+			policy_id,
+			1,
+			inline_input(10),
+			EncryptionScheme::NaclBoxToWorker(ALICE_WORKER),
+		));
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			None,
+			true,
+			None,
+		));
+
+		// The designated worker retries the job, no one else could decrypt the input
+		submit_panicked_job_result(ALICE_WORKER, pool_id, job_id);
+		let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+		assert_eq!(job.status, JobStatus::Pending);
+		assert_eq!(job.attempts, 1);
+		assert!(DesignatedJobs::<Test>::contains_key((pool_id, ALICE_WORKER, job_id)));
+		assert!(!crate::JobFailedWorkers::<Test>::contains_key((pool_id, job_id, ALICE_WORKER)));
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			None,
+			true,
+			None,
+		));
+
+		let pending_job_id = crate::NextJobId::<Test>::get(pool_id).unwrap_or(1);
+		assert_ok!(create_encrypted_job_for(
+			ALICE,
+			pool_id,
+			policy_id,
+			1,
+			inline_input(10),
+			EncryptionScheme::NaclBoxToWorker(ALICE_WORKER),
+		));
+
+		// The worker's jobs are discarded when it goes offline, the encryption key will change
+		assert_ok!(OffchainComputingInfra::force_offline(RuntimeOrigin::signed(ALICE_WORKER)));
+		for job_id in [job_id, pending_job_id] {
+			let job = Jobs::<Test>::get(pool_id, job_id).unwrap();
+			assert_eq!(job.status, JobStatus::Discarded);
+			assert!(!DesignatedJobs::<Test>::contains_key((pool_id, ALICE_WORKER, job_id)));
+		}
+		assert_eq!(crate::CounterForWorkerAssignedJobs::<Test>::get(&ALICE_WORKER), 0);
+
+		assert_ok!(OffchainComputingPool::destroy_job(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			pending_job_id
+		));
+		assert_job_deposits_consistent(&ALICE);
+	});
+}
+
+#[test]
+fn paginated_pool_destruction_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		set_balance(BOB, 20 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		mock_capable_worker(ALICE, BOB_WORKER, pool_id, Default::default(), None, None);
+		let job_ids = (0..3)
+			.map(|_| create_job_for(ALICE, pool_id, policy_id, 10))
+			.collect::<Vec<_>>();
+		assert_ok!(OffchainComputingPool::fund_job_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			10 * DOLLARS,
+			DOLLARS,
+		));
+		// Funding nothing can't open a sponsorship
+		assert_noop!(
+			OffchainComputingPool::fund_job_sponsorship(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				0,
+				DOLLARS,
+			),
+			Error::<Test>::JobSponsorshipNotFound
+		);
+		assert_ok!(OffchainComputingPool::create_job_policy(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			ApplicableScope::AllowList,
+			None,
+			None,
+		));
+		assert_ok!(OffchainComputingPool::authorize_account(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			policy_id + 1,
+			BOB,
+		));
+		assert_ok!(OffchainComputingPool::take_job(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id,
+			Some(job_ids[0]),
+			true,
+			None,
+		));
+
+		assert_noop!(
+			OffchainComputingPool::destroy_pool_jobs(RuntimeOrigin::signed(BOB), pool_id),
+			Error::<Test>::PoolIsLive
+		);
+		assert_noop!(
+			OffchainComputingPool::start_destroy_pool(RuntimeOrigin::signed(BOB), pool_id),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(OffchainComputingPool::start_destroy_pool(
+			RuntimeOrigin::signed(ALICE),
+			pool_id
+		));
+
+		// The pool is frozen
+		assert_noop!(
+			OffchainComputingPool::take_job(
+				RuntimeOrigin::signed(BOB_WORKER),
+				pool_id,
+				None,
+				true,
+				None,
+			),
+			Error::<Test>::PoolIsDestroying
+		);
+		assert_noop!(
+			OffchainComputingPool::create_job(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id,
+				None,
+				None,
+				1,
+				None,
+				EncryptionScheme::Plain,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::PoolCreateNewJobUnavailable
+		);
+		assert_noop!(
+			OffchainComputingPool::fund_job_sponsorship(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				policy_id,
+				DOLLARS,
+				DOLLARS,
+			),
+			Error::<Test>::PoolIsDestroying
+		);
+		assert_noop!(
+			OffchainComputingPool::authorize_account(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				policy_id + 1,
+				ALICE,
+			),
+			Error::<Test>::PoolIsDestroying
+		);
+		assert_noop!(
+			OffchainComputingPool::finish_destroy_pool(RuntimeOrigin::signed(BOB), pool_id),
+			Error::<Test>::PoolNotEmpty
+		);
+
+		// Anyone could advance the destruction, at most `RemoveItemsLimit` items per call
+		assert_ok!(OffchainComputingPool::destroy_pool_jobs(RuntimeOrigin::signed(BOB), pool_id));
+		assert_eq!(crate::Pools::<Test>::get(pool_id).unwrap().jobs_count, 1);
+		assert_ok!(OffchainComputingPool::destroy_pool_jobs(RuntimeOrigin::signed(BOB), pool_id));
+		assert_eq!(crate::Pools::<Test>::get(pool_id).unwrap().jobs_count, 0);
+		assert_eq!(crate::CounterForWorkerAssignedJobs::<Test>::get(&ALICE_WORKER), 0);
+		assert_eq!(held(HoldReason::JobDepositorReserve, &ALICE), 0);
+		assert_eq!(held(HoldReason::JobStorageReserve, &ALICE), 0);
+
+		// The rest of the limit returns the sponsorship to the sponsor
+		assert!(!JobSponsorships::<Test>::contains_key((pool_id, policy_id, BOB)));
+		assert_eq!(held(HoldReason::JobSponsorshipReserve, &BOB), 0);
+		System::assert_has_event(
+			Event::<Test>::JobSponsorshipWithdrawn {
+				pool_id,
+				policy_id,
+				sponsor: BOB,
+				amount: 10 * DOLLARS,
+				budget: 0,
+			}
+			.into(),
+		);
+
+		// The authorized accounts must be removed before finishing
+		assert_ok!(OffchainComputingPool::revoke_pool_workers(RuntimeOrigin::signed(BOB), pool_id));
+		assert_noop!(
+			OffchainComputingPool::finish_destroy_pool(RuntimeOrigin::signed(BOB), pool_id),
+			Error::<Test>::PoolNotEmpty
+		);
+		assert_ok!(OffchainComputingPool::destroy_pool_jobs(RuntimeOrigin::signed(BOB), pool_id));
+		assert!(!crate::JobPolicyAuthorizedAccounts::<Test>::contains_key((
+			pool_id,
+			policy_id + 1,
+			BOB
+		)));
+
+		assert_eq!(crate::Pools::<Test>::get(pool_id).unwrap().workers_count, 0);
+		assert!(!crate::WorkerSubscribedPools::<Test>::contains_key(&ALICE_WORKER, pool_id));
+
+		assert_ok!(OffchainComputingPool::finish_destroy_pool(RuntimeOrigin::signed(BOB), pool_id));
+		assert!(!crate::Pools::<Test>::contains_key(pool_id));
+		assert!(!crate::NextJobId::<Test>::contains_key(pool_id));
+		assert!(!crate::NextJobPolicyId::<Test>::contains_key(pool_id));
+		assert_eq!(held(HoldReason::PoolCreationReserve, &ALICE), 0);
+	});
+}
+
+#[test]
+fn pool_team_roles_work() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		set_balance(BOB, 10 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, policy_id) = mock_pool_and_policy(ALICE);
+
+		assert_noop!(
+			OffchainComputingPool::set_pool_team(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				Some(BOB),
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			OffchainComputingPool::create_job_policy(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				ApplicableScope::Public,
+				None,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(OffchainComputingPool::set_pool_team(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			None,
+			None,
+			Some(BOB),
+			Some(BOB),
+		));
+		assert_ok!(OffchainComputingPool::create_job_policy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ApplicableScope::Public,
+			None,
+			None,
+		));
+		assert_ok!(OffchainComputingPool::update_job_policy_cancellation_compensation(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			policy_id,
+			JobCancellationCompensation::ProRata,
+		));
+		assert_noop!(
+			OffchainComputingPool::update_pool_metadata(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				Some(BoundedVec::try_from(b"bob".to_vec()).unwrap()),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			OffchainComputingPool::authorize_worker(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				BOB_WORKER,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// The root could change the team, the previous roles are cleared
+		assert_ok!(OffchainComputingPool::set_pool_team(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOB),
+			None,
+			None,
+			None,
+		));
+		assert_ok!(OffchainComputingPool::update_pool_metadata(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			Some(BoundedVec::try_from(b"bob".to_vec()).unwrap()),
+		));
+		assert_noop!(
+			OffchainComputingPool::destroy_job_policy(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				policy_id
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn pool_ownership_transfer_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+		set_balance(BOB, 10 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, _) = mock_pool_and_policy(ALICE);
+		assert_ok!(OffchainComputingPool::update_pool_metadata(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			Some(BoundedVec::try_from(b"alice".to_vec()).unwrap()),
+		));
+		let metadata_deposit = held(HoldReason::PoolMetadataStorageReserve, &ALICE);
+		assert!(metadata_deposit > 0);
+		assert_ok!(OffchainComputingPool::set_pool_team(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			None,
+			None,
+			Some(ALICE_WORKER),
+			None,
+		));
+
+		assert_noop!(
+			OffchainComputingPool::transfer_pool_ownership(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				BOB,
+			),
+			Error::<Test>::Unaccepted
+		);
+
+		assert_ok!(OffchainComputingPool::set_accept_pool_ownership(
+			RuntimeOrigin::signed(BOB),
+			Some(pool_id),
+		));
+		assert_eq!(System::consumers(&BOB), 1);
+		assert_noop!(
+			OffchainComputingPool::transfer_pool_ownership(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				BOB,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(OffchainComputingPool::transfer_pool_ownership(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			BOB,
+		));
+
+		let pool_info = crate::Pools::<Test>::get(pool_id).unwrap();
+		assert_eq!(pool_info.owner, BOB);
+		assert!(crate::AccountOwningPools::<Test>::contains_key(&BOB, pool_id));
+		assert!(!crate::AccountOwningPools::<Test>::contains_key(&ALICE, pool_id));
+		assert!(!crate::PoolOwnershipAcceptance::<Test>::contains_key(&BOB));
+		assert_eq!(held(HoldReason::PoolCreationReserve, &ALICE), 0);
+		assert_eq!(held(HoldReason::PoolCreationReserve, &BOB), pool_info.owner_deposit);
+		assert_eq!(held(HoldReason::PoolMetadataStorageReserve, &ALICE), 0);
+		assert_eq!(held(HoldReason::PoolMetadataStorageReserve, &BOB), metadata_deposit);
+		assert_eq!(crate::PoolMetadata::<Test>::get(pool_id).unwrap().depositor, BOB);
+
+		// The previous owner and its team lost the permissions
+		assert!(!OffchainComputingPool::has_pool_role(
+			&pool_id,
+			&ALICE_WORKER,
+			crate::PoolRole::PolicyManager
+		));
+		assert_noop!(
+			OffchainComputingPool::create_job_policy(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				ApplicableScope::Public,
+				None,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// The destroying pool can't be transferred
+		assert_ok!(OffchainComputingPool::set_accept_pool_ownership(
+			RuntimeOrigin::signed(ALICE),
+			Some(pool_id),
+		));
+		assert_ok!(OffchainComputingPool::start_destroy_pool(RuntimeOrigin::signed(BOB), pool_id));
+		assert_noop!(
+			OffchainComputingPool::transfer_pool_ownership(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				ALICE,
+			),
+			Error::<Test>::PoolIsDestroying
+		);
+		assert_ok!(OffchainComputingPool::destroy_pool(RuntimeOrigin::signed(BOB), pool_id));
+		assert_eq!(held(HoldReason::PoolCreationReserve, &BOB), 0);
+		assert_eq!(held(HoldReason::PoolMetadataStorageReserve, &BOB), 0);
+	});
+}
+
+#[test]
+fn pool_min_worker_deposit_works() {
+	build_and_execute(|| {
+		set_balance(ALICE, 1000 * DOLLARS, 0);
+		run_to_block(1);
+
+		let (pool_id, _) = mock_pool_and_policy(ALICE);
+		assert_ok!(OffchainComputingInfra::register_impl_build(
+			RuntimeOrigin::signed(ALICE),
+			101,
+			1,
+			None,
+		));
+		assert_ok!(OffchainComputingInfra::register_worker(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_WORKER,
+			101,
+			101 * DOLLARS,
+		));
+
+		assert_noop!(
+			OffchainComputingPool::set_pool_min_worker_deposit(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				Some(150 * DOLLARS),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(OffchainComputingPool::set_pool_min_worker_deposit(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			Some(150 * DOLLARS),
+		));
+		assert_noop!(
+			OffchainComputingPool::authorize_worker(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				ALICE_WORKER,
+			),
+			Error::<Test>::WorkerDepositTooLow
+		);
+
+		assert_ok!(OffchainComputingInfra::increase_worker_deposit(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_WORKER,
+			50 * DOLLARS,
+		));
+		assert_ok!(OffchainComputingPool::authorize_worker(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			ALICE_WORKER,
+		));
+		assert_ok!(OffchainComputingPool::subscribe_pool(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id
+		));
+
+		// The subscribed pool keeps the worker's deposit above its minimum
+		assert_noop!(
+			OffchainComputingInfra::decrease_worker_deposit(
+				RuntimeOrigin::signed(ALICE),
+				ALICE_WORKER,
+				10 * DOLLARS,
+			),
+			Error::<Test>::WorkerDepositTooLow
+		);
+		assert_ok!(OffchainComputingPool::unsubscribe_pool(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id
+		));
+		assert_ok!(OffchainComputingInfra::decrease_worker_deposit(
+			RuntimeOrigin::signed(ALICE),
+			ALICE_WORKER,
+			10 * DOLLARS,
+		));
+		assert_noop!(
+			OffchainComputingPool::subscribe_pool(RuntimeOrigin::signed(ALICE_WORKER), pool_id),
+			Error::<Test>::WorkerDepositTooLow
+		);
+	});
+}
+
+#[test]
+fn unsubscribing_and_revoking_release_subscribed_pools_counter() {
+	build_and_execute(|| {
+		set_balance(ALICE, 500 * DOLLARS, 0);
+
+		let (pool_id, _policy_id) = mock_pool_and_policy(ALICE);
+		mock_subscribed_worker(ALICE, ALICE_WORKER, pool_id);
+		assert_eq!(crate::CounterForWorkerSubscribedPools::<Test>::get(&ALICE_WORKER), 1);
+
+		assert_ok!(OffchainComputingPool::unsubscribe_pool(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id
+		));
+		assert_eq!(crate::CounterForWorkerSubscribedPools::<Test>::get(&ALICE_WORKER), 0);
+
+		assert_ok!(OffchainComputingPool::subscribe_pool(
+			RuntimeOrigin::signed(ALICE_WORKER),
+			pool_id
+		));
+		assert_ok!(OffchainComputingPool::revoke_worker(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			ALICE_WORKER
+		));
+		assert_eq!(crate::CounterForWorkerSubscribedPools::<Test>::get(&ALICE_WORKER), 0);
+		assert_eq!(crate::CounterForWorkerAddedPools::<Test>::get(&ALICE_WORKER), 0);
+	});
+}
+
+#[test]
+fn migrate_v1_to_v2_works() {
+	#[cfg(feature = "try-runtime")]
+	use crate::migrations::v2::VersionUncheckedMigrateV1ToV2;
+	use crate::{
+		migrations::v2::{v1, MigrateV1ToV2},
+		CounterForWorkerAddedPools, JobPolicies, PoolAuthorizedWorkers, PoolWorkers, Pools,
+	};
+	use frame_support::traits::{
+		fungible::MutateHold, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+	};
+
+	build_and_execute(|| {
+		StorageVersion::new(1).put::<OffchainComputingPool>();
+		// The deposits were held before the migration
+		for (who, reason) in [
+			(ALICE, HoldReason::PoolCreationReserve),
+			(BOB, HoldReason::JobDepositorReserve),
+			(BOB, HoldReason::JobStorageReserve),
+			(ALICE_WORKER, HoldReason::JobStorageReserve),
+		] {
+			set_balance(who.clone(), 10 * DOLLARS, 0);
+			let reason = RuntimeHoldReason::OffchainComputingPool(reason);
+			assert_ok!(Balances::hold(&reason, &who, DOLLARS));
+		}
+
+		v1::Pools::<Test>::insert(
+			101,
+			v1::PoolInfo {
+				id: 101,
+				owner: ALICE,
+				owner_deposit: DOLLARS,
+				impl_id: 101,
+				job_scheduler: JobScheduler::DemoOnly,
+				create_job_enabled: true,
+				auto_destroy_processed_job_enabled: false,
+				min_impl_spec_version: 1,
+				max_impl_spec_version: 1,
+				job_policies_count: 1,
+				jobs_count: 1,
+				workers_count: 1,
+			},
+		);
+		PoolAuthorizedWorkers::<Test>::insert(ALICE_WORKER, 101, ());
+		v1::JobPolicies::<Test>::insert(
+			101,
+			1,
+			v1::JobPolicy {
+				id: 1,
+				enabled: true,
+				applicable_scope: ApplicableScope::Public,
+				start_block: None,
+				end_block: Some(100),
+				jobs_count: 1,
+			},
+		);
+		v1::Jobs::<Test>::insert(
+			101,
+			1,
+			v1::JobInfo {
+				id: 1,
+				unique_track_id: None,
+				policy_id: 1,
+				depositor: BOB,
+				deposit: DOLLARS,
+				beneficiary: BOB,
+				impl_build_version: None,
+				impl_spec_version: 1,
+				status: JobStatus::Pending,
+				result: None,
+				expires_at: 1000,
+				created_at: 0,
+				assignee: None,
+				assigned_at: None,
+				processing_at: None,
+				ended_at: None,
+			},
+		);
+		let input = BoundedVec::try_from(b"input".to_vec()).unwrap();
+		v1::JobInputs::<Test>::insert(
+			101,
+			1,
+			ChainStoredData {
+				depositor: BOB,
+				actual_deposit: DOLLARS,
+				surplus_deposit: 0,
+				data: input.clone(),
+			},
+		);
+		let output = BoundedVec::try_from(b"output".to_vec()).unwrap();
+		v1::JobOutputs::<Test>::insert(
+			101,
+			1,
+			ChainStoredData {
+				depositor: ALICE_WORKER,
+				actual_deposit: DOLLARS,
+				surplus_deposit: 0,
+				data: output.clone(),
+			},
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state =
+			VersionUncheckedMigrateV1ToV2::<Test>::pre_upgrade().expect("pre-upgrade should pass");
+
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(VersionUncheckedMigrateV1ToV2::<Test>::post_upgrade(state));
+
+		assert_eq!(OffchainComputingPool::on_chain_storage_version(), 2);
+
+		let pool_info = Pools::<Test>::get(101).unwrap();
+		assert_eq!(pool_info.jobs_count, 1);
+		assert_eq!(pool_info.workers_count, 1);
+		assert_eq!(pool_info.status, PoolStatus::Live);
+		assert!(PoolWorkers::<Test>::contains_key(101, ALICE_WORKER));
+		assert_eq!(CounterForWorkerAddedPools::<Test>::get(ALICE_WORKER), 1);
+
+		let policy = JobPolicies::<Test>::get(101, 1).unwrap();
+		assert_eq!(policy.end_block, Some(100));
+		assert_eq!(policy.rate_strategy, JobRateStrategy::Free);
+		assert_eq!(policy.cancellation_compensation, JobCancellationCompensation::Nothing);
+		assert_eq!(policy.max_attempts, 1);
+
+		let job = Jobs::<Test>::get(101, 1).unwrap();
+		assert_eq!(job.depositor, BOB);
+		assert_eq!(job.attempts, 0);
+		assert_eq!(job.fee, 0);
+		assert_eq!(job.callback, None);
+
+		let input_entry = JobInputs::<Test>::get(101, 1).unwrap();
+		assert_eq!(input_entry.encryption, EncryptionScheme::Plain);
+		assert_eq!(input_entry.data, JobPayload::Inline(input));
+		let output_entry = JobOutputs::<Test>::get(101, 1).unwrap();
+		assert_eq!(output_entry.depositor, ALICE_WORKER);
+		assert_eq!(output_entry.data, JobPayload::Inline(output));
+
+		// The migration only runs once
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(OffchainComputingPool::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn genesis_config_works() {
+	use crate::{
+		GenesisConfig, JobPolicies, NextJobPolicyId, NextPoolId, PoolAuthorizedWorkers,
+		PoolMetadata, Pools,
+	};
+	use frame_support::traits::BuildGenesisConfig;
+
+	build_and_execute(|| {
+		set_balance(ALICE, 1000 * DOLLARS, 0);
+		set_balance(ALICE_WORKER, 1000 * DOLLARS, 0);
+
+		pallet_offchain_computing_infra::GenesisConfig::<Test> {
+			impls: vec![(101, ALICE, AttestationMethod::OptOut)],
+			impl_metadata: vec![],
+			impl_builds: vec![(101, 1, None)],
+			workers: vec![(ALICE, ALICE_WORKER, 101)],
+		}
+		.build();
+		GenesisConfig::<Test> {
+			pools: vec![(200, ALICE, 101, JobScheduler::DemoOnly, true, false)],
+			pool_metadata: vec![(200, b"metadata".to_vec())],
+			pool_impl_spec_versions: vec![(200, 1, 2)],
+			job_policies: vec![
+				(200, 1, ApplicableScope::Public, None, None),
+				(200, 3, ApplicableScope::Owner, Some(10), Some(20)),
+			],
+			authorized_workers: vec![(200, ALICE_WORKER)],
+		}
+		.build();
+
+		let pool_info = Pools::<Test>::get(200).unwrap();
+		assert_eq!(pool_info.owner, ALICE);
+		assert_eq!(pool_info.create_job_enabled, true);
+		assert_eq!(pool_info.min_impl_spec_version, 1);
+		assert_eq!(pool_info.max_impl_spec_version, 2);
+		assert_eq!(pool_info.job_policies_count, 2);
+		assert_eq!(pool_info.workers_count, 1);
+		assert_eq!(PoolMetadata::<Test>::get(200).unwrap().data.to_vec(), b"metadata".to_vec());
+		assert_eq!(JobPolicies::<Test>::get(200, 3).unwrap().start_block, Some(10));
+		assert!(PoolAuthorizedWorkers::<Test>::contains_key(ALICE_WORKER, 200));
+		assert_eq!(held(HoldReason::PoolCreationReserve, &ALICE), DOLLARS);
+
+		// Pools and policies created later won't collide with the genesis ones
+		assert_eq!(NextPoolId::<Test>::get(), Some(201));
+		assert_eq!(NextJobPolicyId::<Test>::get(200), Some(4));
+
+		run_to_block(1);
+		assert_ok!(OffchainComputingPool::create_job_policy(
+			RuntimeOrigin::signed(ALICE),
+			200,
+			ApplicableScope::Public,
+			None,
+			None,
+		));
+		assert!(JobPolicies::<Test>::contains_key(200, 4));
+	});
+}
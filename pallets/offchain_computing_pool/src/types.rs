@@ -0,0 +1,145 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+//! This module contains the pallet-level types that depend on the pallet's `Config`.
+
+use crate::*;
+use enumflags2::{bitflags, BitFlags};
+use frame_support::{pallet_prelude::MaxEncodedLen, weights::Weight};
+use scale_codec::{Decode, Encode, EncodeLike};
+use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// A type alias for the pre-signed job creation request.
+pub type PreSignedJobOf<T> = PreSignedJob<
+	<T as Config>::PoolId,
+	<T as Config>::PolicyId,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+>;
+
+/// The tag prepended to the signed message of a pre-signed job, so its signature can't be used
+/// as a signature of anything else.
+pub const PRE_SIGNED_JOB_DOMAIN_TAG: &[u8] = b"<cybros-offchain-computing-pool-pre-signed-job>";
+
+/// A job creation request signed by the user off-chain, which could be relayed by anyone.
+///
+/// The signed message is built by [`Pallet::pre_signed_job_message`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PreSignedJob<PoolId, PolicyId, AccountId, Deadline> {
+	/// The pool that the job will be created in.
+	pub pool_id: PoolId,
+	/// The policy that the job will apply.
+	pub policy_id: PolicyId,
+	pub unique_track_id: Option<UniqueTrackId>,
+	/// The beneficiary of the job, the signer if not given.
+	pub beneficiary: Option<AccountId>,
+	/// The implementation spec version
+	pub impl_spec_version: ImplSpecVersion,
+	/// The job's input, it must not longer than `InputLimit`.
+	pub input: Option<Vec<u8>>,
+	/// How the job's input encrypted.
+	pub input_encryption: EncryptionScheme<AccountId>,
+	pub soft_expires_in: Option<u64>,
+	/// Restrict the relaying to a particular account.
+	pub only_relayer: Option<AccountId>,
+	/// A deadline for the signature.
+	pub deadline: Deadline,
+	/// Must equal to the signer's next pre-signed job nonce, this prevents replaying.
+	pub nonce: u64,
+}
+
+/// Request to call a contract message when the job processed.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct JobCallbackRequest<AccountId, Balance> {
+	/// The contract address.
+	pub contract: AccountId,
+	/// The selector of the message, it will be called with
+	/// `(pool_id, job_id, result, output_hash)`.
+	pub selector: [u8; 4],
+	/// The gas limit, the fee will be prepaid by the job's depositor.
+	pub gas_limit: Weight,
+	/// The storage deposit limit of the call, it will be held from the job's depositor.
+	pub storage_deposit_limit: Balance,
+}
+
+/// Permissions that the pool's owner could delegate to team members.
+#[bitflags]
+#[repr(u8)]
+#[derive(Copy, Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum PoolRole {
+	/// Can update the pool's metadata and settings.
+	Admin,
+	/// Can authorize and revoke workers.
+	WorkerManager,
+	/// Can manage job policies and their authorized accounts.
+	PolicyManager,
+	/// Can change how jobs are charged and compensated.
+	Finance,
+}
+
+/// A wrapper type that implements `Codec`.
+#[derive(Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct PoolRoles(pub BitFlags<PoolRole>);
+
+impl PoolRoles {
+	pub fn none() -> Self {
+		Self(BitFlags::EMPTY)
+	}
+	pub fn has_role(&self, role: PoolRole) -> bool {
+		self.0.contains(role)
+	}
+	pub fn add_role(&mut self, role: PoolRole) {
+		self.0.insert(role);
+	}
+	pub fn max_roles() -> u8 {
+		let all: BitFlags<PoolRole> = BitFlags::all();
+		all.len() as u8
+	}
+}
+
+impl MaxEncodedLen for PoolRoles {
+	fn max_encoded_len() -> usize {
+		u8::max_encoded_len()
+	}
+}
+impl Encode for PoolRoles {
+	fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+		self.0.bits().using_encoded(f)
+	}
+}
+impl EncodeLike for PoolRoles {}
+impl Decode for PoolRoles {
+	fn decode<I: scale_codec::Input>(
+		input: &mut I,
+	) -> sp_std::result::Result<Self, scale_codec::Error> {
+		let field = u8::decode(input)?;
+		Ok(Self(BitFlags::from_bits(field).map_err(|_| "invalid value")?))
+	}
+}
+impl TypeInfo for PoolRoles {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::builder()
+			.path(Path::new("BitFlags", module_path!()))
+			.type_params(vec![TypeParameter::new("T", Some(meta_type::<PoolRole>()))])
+			.composite(Fields::unnamed().field(|f| f.ty::<u8>().type_name("PoolRole")))
+	}
+}
@@ -0,0 +1,1067 @@
+// This file is part of Cybros.
+
+// Copyright (C) Jun Jiang.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+// Cybros is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cybros is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Estimated weights for pallet_offchain_computing_pool
+//!
+//! THE VALUES ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS.
+//! They follow the storage accesses of each extrinsic and should be replaced by running the
+//! benchmarks of the pallet on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_offchain_computing_pool.
+pub trait WeightInfo {
+    fn create_pool() -> Weight;
+    fn destroy_pool(p: u32, ) -> Weight;
+    fn update_pool_metadata(m: u32, ) -> Weight;
+    fn update_pool_settings() -> Weight;
+    fn create_job_policy() -> Weight;
+    fn authorize_account() -> Weight;
+    fn revoke_account() -> Weight;
+    fn destroy_job_policy() -> Weight;
+    fn update_job_policy_enablement() -> Weight;
+    fn authorize_worker() -> Weight;
+    fn revoke_worker() -> Weight;
+    fn subscribe_pool() -> Weight;
+    fn unsubscribe_pool() -> Weight;
+    fn create_job(i: u32, ) -> Weight;
+    fn destroy_job() -> Weight;
+    fn take_job(n: u32, ) -> Weight;
+    fn resign_job() -> Weight;
+    fn submit_job_result(o: u32, p: u32, ) -> Weight;
+    fn create_job_pre_signed(i: u32, ) -> Weight;
+    fn fund_job_sponsorship() -> Weight;
+    fn withdraw_job_sponsorship() -> Weight;
+    fn create_sponsored_job(i: u32, ) -> Weight;
+    fn update_job_input(i: u32, ) -> Weight;
+    fn reclaim_job_surplus_deposit() -> Weight;
+    fn top_up_job_deposit() -> Weight;
+    fn add_rate_contract() -> Weight;
+    fn remove_rate_contract() -> Weight;
+    fn update_job_policy_rate_strategy() -> Weight;
+    fn cancel_job() -> Weight;
+    fn update_job_policy_cancellation_compensation() -> Weight;
+    fn update_job_policy_max_attempts() -> Weight;
+    fn start_destroy_pool() -> Weight;
+    fn destroy_pool_jobs(n: u32, ) -> Weight;
+    fn revoke_pool_workers(n: u32, ) -> Weight;
+    fn finish_destroy_pool(p: u32, ) -> Weight;
+    fn set_pool_team() -> Weight;
+    fn set_accept_pool_ownership() -> Weight;
+    fn transfer_pool_ownership() -> Weight;
+    fn set_pool_min_worker_deposit() -> Weight;
+}
+
+/// Weights for pallet_offchain_computing_pool using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::NextPoolId` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Pools` (r:0 w:1)
+    fn create_pool() -> Weight {
+        Weight::from_parts(41_000_000, 3604)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:0 w:8)
+    /// Storage: `OffchainComputingPool::NextJobPolicyId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:4 w:4)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:1)
+    /// The range of component `p` is `[0, 8]`.
+    fn destroy_pool(p: u32, ) -> Weight {
+        Weight::from_parts(71_000_000, 5599)
+            .saturating_add(Weight::from_parts(1_700_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(13_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `m` is `[1, 2048]`.
+    fn update_pool_metadata(m: u32, ) -> Weight {
+        Weight::from_parts(41_000_000, 5599)
+            .saturating_add(Weight::from_parts(2_300, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    fn update_pool_settings() -> Weight {
+        Weight::from_parts(15_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::NextJobPolicyId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:0 w:1)
+    fn create_job_policy() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:1)
+    fn authorize_account() -> Weight {
+        Weight::from_parts(18_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:1)
+    fn revoke_account() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn destroy_job_policy() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_enablement() -> Weight {
+        Weight::from_parts(16_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAddedPools` (r:1 w:1)
+    fn authorize_worker() -> Weight {
+        Weight::from_parts(27_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAddedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:0 w:1)
+    fn revoke_worker() -> Weight {
+        Weight::from_parts(36_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:0 w:1)
+    fn subscribe_pool() -> Weight {
+        Weight::from_parts(29_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:0 w:1)
+    fn unsubscribe_pool() -> Weight {
+        Weight::from_parts(26_000_000, 3655)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:0)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// The range of component `i` is `[0, 2048]`.
+    fn create_job(i: u32, ) -> Weight {
+        Weight::from_parts(83_000_000, 5260)
+            .saturating_add(Weight::from_parts(1_900, 0).saturating_mul(i.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobOutputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobProofs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn destroy_job() -> Weight {
+        Weight::from_parts(93_000_000, 5653)
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(17_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:64 w:0)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:64 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:65 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:128 w:0)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:128 w:0)
+    /// Storage: `OffchainComputingPool::WorkerDeclaredCapabilities` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// The range of component `n` is `[1, 64]`.
+    fn take_job(n: u32, ) -> Weight {
+        Weight::from_parts(44_000_000, 5653)
+            .saturating_add(Weight::from_parts(11_000_000, 5200).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    fn resign_job() -> Weight {
+        Weight::from_parts(27_000_000, 3788)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobOutputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobProofs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:0 w:1)
+    /// The range of component `o` is `[0, 2048]`.
+    /// The range of component `p` is `[0, 2048]`.
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn submit_job_result(o: u32, p: u32, ) -> Weight {
+        Weight::from_parts(152_000_000, 5653)
+            .saturating_add(Weight::from_parts(2_100, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(1_800, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(17_u64))
+            .saturating_add(T::DbWeight::get().writes(21_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::PreSignedJobNonces` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// The range of component `i` is `[0, 2048]`.
+    fn create_job_pre_signed(i: u32, ) -> Weight {
+        Weight::from_parts(121_000_000, 3604)
+            .saturating_add(Weight::from_parts(4_100, 0).saturating_mul(i.into()))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn fund_job_sponsorship() -> Weight {
+        Weight::from_parts(45_000_000, 3604)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn withdraw_job_sponsorship() -> Weight {
+        Weight::from_parts(44_000_000, 3604)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `i` is `[0, 2048]`.
+    fn create_sponsored_job(i: u32, ) -> Weight {
+        Weight::from_parts(94_000_000, 3617)
+            .saturating_add(Weight::from_parts(1_900, 0).saturating_mul(i.into()))
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().writes(14_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `i` is `[1, 2048]`.
+    fn update_job_input(i: u32, ) -> Weight {
+        Weight::from_parts(42_000_000, 5653)
+            .saturating_add(Weight::from_parts(2_200, 0).saturating_mul(i.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn reclaim_job_surplus_deposit() -> Weight {
+        Weight::from_parts(40_000_000, 5653)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn top_up_job_deposit() -> Weight {
+        Weight::from_parts(43_000_000, 3788)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::RateContracts` (r:1 w:1)
+    fn add_rate_contract() -> Weight {
+        Weight::from_parts(14_000_000, 5260)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::RateContracts` (r:1 w:1)
+    fn remove_rate_contract() -> Weight {
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::RateContracts` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_rate_strategy() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn cancel_job() -> Weight {
+        Weight::from_parts(88_000_000, 3788)
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_cancellation_compensation() -> Weight {
+        Weight::from_parts(16_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_max_attempts() -> Weight {
+        Weight::from_parts(16_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    fn start_destroy_pool() -> Weight {
+        Weight::from_parts(13_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:100 w:100)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobOutputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobProofs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:0 w:1)
+    /// The range of component `n` is `[0, 100]`.
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn destroy_pool_jobs(n: u32, ) -> Weight {
+        Weight::from_parts(14_000_000, 5653)
+            .saturating_add(Weight::from_parts(68_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((17_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2188).saturating_mul(n.into()))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:101 w:100)
+    /// Storage: `OffchainComputingInfra::Workers` (r:100 w:0)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:0 w:100)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAddedPools` (r:100 w:100)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:100 w:100)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:100 w:100)
+    /// The range of component `n` is `[0, 100]`.
+    fn revoke_pool_workers(n: u32, ) -> Weight {
+        Weight::from_parts(16_000_000, 3655)
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2543).saturating_mul(n.into()))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:0 w:8)
+    /// Storage: `OffchainComputingPool::NextJobPolicyId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:4 w:4)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:1)
+    /// The range of component `p` is `[0, 8]`.
+    fn finish_destroy_pool(p: u32, ) -> Weight {
+        Weight::from_parts(70_000_000, 5599)
+            .saturating_add(Weight::from_parts(1_700_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(13_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:8 w:8)
+    fn set_pool_team() -> Weight {
+        Weight::from_parts(43_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// Storage: `OffchainComputingPool::PoolOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn set_accept_pool_ownership() -> Weight {
+        Weight::from_parts(12_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingPool::PoolOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:2)
+    fn transfer_pool_ownership() -> Weight {
+        Weight::from_parts(88_000_000, 5599)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:0 w:1)
+    fn set_pool_min_worker_deposit() -> Weight {
+        Weight::from_parts(14_000_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `OffchainComputingInfra::Impls` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::NextPoolId` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Pools` (r:0 w:1)
+    fn create_pool() -> Weight {
+        Weight::from_parts(41_000_000, 3604)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:0 w:8)
+    /// Storage: `OffchainComputingPool::NextJobPolicyId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:4 w:4)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:1)
+    /// The range of component `p` is `[0, 8]`.
+    fn destroy_pool(p: u32, ) -> Weight {
+        Weight::from_parts(71_000_000, 5599)
+            .saturating_add(Weight::from_parts(1_700_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(12_u64))
+            .saturating_add(RocksDbWeight::get().writes(13_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `m` is `[1, 2048]`.
+    fn update_pool_metadata(m: u32, ) -> Weight {
+        Weight::from_parts(41_000_000, 5599)
+            .saturating_add(Weight::from_parts(2_300, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    fn update_pool_settings() -> Weight {
+        Weight::from_parts(15_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::NextJobPolicyId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:0 w:1)
+    fn create_job_policy() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:1)
+    fn authorize_account() -> Weight {
+        Weight::from_parts(18_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:1)
+    fn revoke_account() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn destroy_job_policy() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_enablement() -> Weight {
+        Weight::from_parts(16_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAddedPools` (r:1 w:1)
+    fn authorize_worker() -> Weight {
+        Weight::from_parts(27_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAddedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:0 w:1)
+    fn revoke_worker() -> Weight {
+        Weight::from_parts(36_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:0 w:1)
+    fn subscribe_pool() -> Weight {
+        Weight::from_parts(29_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:0 w:1)
+    fn unsubscribe_pool() -> Weight {
+        Weight::from_parts(26_000_000, 3655)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:0)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// The range of component `i` is `[0, 2048]`.
+    fn create_job(i: u32, ) -> Weight {
+        Weight::from_parts(83_000_000, 5260)
+            .saturating_add(Weight::from_parts(1_900, 0).saturating_mul(i.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobOutputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobProofs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn destroy_job() -> Weight {
+        Weight::from_parts(93_000_000, 5653)
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(17_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:64 w:0)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:64 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:65 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:128 w:0)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:128 w:0)
+    /// Storage: `OffchainComputingPool::WorkerDeclaredCapabilities` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// The range of component `n` is `[1, 64]`.
+    fn take_job(n: u32, ) -> Weight {
+        Weight::from_parts(44_000_000, 5653)
+            .saturating_add(Weight::from_parts(11_000_000, 5200).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    fn resign_job() -> Weight {
+        Weight::from_parts(27_000_000, 3788)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingInfra::Workers` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobOutputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobProofs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:0 w:1)
+    /// The range of component `o` is `[0, 2048]`.
+    /// The range of component `p` is `[0, 2048]`.
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn submit_job_result(o: u32, p: u32, ) -> Weight {
+        Weight::from_parts(152_000_000, 5653)
+            .saturating_add(Weight::from_parts(2_100, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(1_800, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(17_u64))
+            .saturating_add(RocksDbWeight::get().writes(21_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::PreSignedJobNonces` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// The range of component `i` is `[0, 2048]`.
+    fn create_job_pre_signed(i: u32, ) -> Weight {
+        Weight::from_parts(121_000_000, 3604)
+            .saturating_add(Weight::from_parts(4_100, 0).saturating_mul(i.into()))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn fund_job_sponsorship() -> Weight {
+        Weight::from_parts(45_000_000, 3604)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn withdraw_job_sponsorship() -> Weight {
+        Weight::from_parts(44_000_000, 3604)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `i` is `[0, 2048]`.
+    fn create_sponsored_job(i: u32, ) -> Weight {
+        Weight::from_parts(94_000_000, 3617)
+            .saturating_add(Weight::from_parts(1_900, 0).saturating_mul(i.into()))
+            .saturating_add(RocksDbWeight::get().reads(13_u64))
+            .saturating_add(RocksDbWeight::get().writes(14_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// The range of component `i` is `[1, 2048]`.
+    fn update_job_input(i: u32, ) -> Weight {
+        Weight::from_parts(42_000_000, 5653)
+            .saturating_add(Weight::from_parts(2_200, 0).saturating_mul(i.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn reclaim_job_surplus_deposit() -> Weight {
+        Weight::from_parts(40_000_000, 5653)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn top_up_job_deposit() -> Weight {
+        Weight::from_parts(43_000_000, 3788)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::RateContracts` (r:1 w:1)
+    fn add_rate_contract() -> Weight {
+        Weight::from_parts(14_000_000, 5260)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::RateContracts` (r:1 w:1)
+    fn remove_rate_contract() -> Weight {
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::RateContracts` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_rate_strategy() -> Weight {
+        Weight::from_parts(19_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::Jobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `OffchainComputingPool::WorkerAssignedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAssignedJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn cancel_job() -> Weight {
+        Weight::from_parts(88_000_000, 3788)
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_cancellation_compensation() -> Weight {
+        Weight::from_parts(16_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    fn update_job_policy_max_attempts() -> Weight {
+        Weight::from_parts(16_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    fn start_destroy_pool() -> Weight {
+        Weight::from_parts(13_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Jobs` (r:100 w:100)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobInputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobOutputs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobProofs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobWorkerRequirements` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobFailedWorkers` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::IndexedJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AccountBeneficialJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::AssignableJobs` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::DesignatedJobs` (r:0 w:1)
+    /// The range of component `n` is `[0, 100]`.
+    /// Storage: `OffchainComputingPool::SponsoredJobs` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:1)
+    fn destroy_pool_jobs(n: u32, ) -> Weight {
+        Weight::from_parts(14_000_000, 5653)
+            .saturating_add(Weight::from_parts(68_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((17_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2188).saturating_mul(n.into()))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolWorkers` (r:101 w:100)
+    /// Storage: `OffchainComputingInfra::Workers` (r:100 w:0)
+    /// Storage: `OffchainComputingPool::PoolAuthorizedWorkers` (r:0 w:100)
+    /// Storage: `OffchainComputingPool::CounterForWorkerAddedPools` (r:100 w:100)
+    /// Storage: `OffchainComputingPool::WorkerSubscribedPools` (r:100 w:100)
+    /// Storage: `OffchainComputingPool::CounterForWorkerSubscribedPools` (r:100 w:100)
+    /// The range of component `n` is `[0, 100]`.
+    fn revoke_pool_workers(n: u32, ) -> Weight {
+        Weight::from_parts(16_000_000, 3655)
+            .saturating_add(Weight::from_parts(29_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2543).saturating_mul(n.into()))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::JobPolicies` (r:0 w:8)
+    /// Storage: `OffchainComputingPool::NextJobPolicyId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::NextJobId` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::JobSponsorships` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobSponsorshipSpending` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::JobPolicyAuthorizedAccounts` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:4 w:4)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:0 w:1)
+    /// Storage: `OffchainComputingPool::CounterForPools` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:1)
+    /// The range of component `p` is `[0, 8]`.
+    fn finish_destroy_pool(p: u32, ) -> Weight {
+        Weight::from_parts(70_000_000, 5599)
+            .saturating_add(Weight::from_parts(1_700_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(12_u64))
+            .saturating_add(RocksDbWeight::get().writes(13_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:8 w:8)
+    fn set_pool_team() -> Weight {
+        Weight::from_parts(43_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    /// Storage: `OffchainComputingPool::PoolOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    fn set_accept_pool_ownership() -> Weight {
+        Weight::from_parts(12_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `OffchainComputingPool::PoolOwnershipAcceptance` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `OffchainComputingPool::PoolMetadata` (r:1 w:1)
+    /// Storage: `OffchainComputingPool::AccountOwningPools` (r:0 w:2)
+    fn transfer_pool_ownership() -> Weight {
+        Weight::from_parts(88_000_000, 5599)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+    /// Storage: `OffchainComputingPool::Pools` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolRoleOf` (r:1 w:0)
+    /// Storage: `OffchainComputingPool::PoolMinWorkerDeposits` (r:0 w:1)
+    fn set_pool_min_worker_deposit() -> Weight {
+        Weight::from_parts(14_000_000, 3565)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -186,6 +186,13 @@ function createSubstrateApi(rpcUrl: string): ApiPromise | null {
         impl_spec_version: "u32",
         impl_build_version: "u32",
         impl_build_magic_bytes: "BoundedVec<u8, 64>",
+        encryption_public_key: "Option<[u8; 32]>",
+        capabilities: "BoundedVec<WorkerCapability, 16>",
+        job_slots: "Option<u32>",
+      },
+      WorkerCapability: {
+        key: "BoundedVec<u8, 32>",
+        value: "u64",
       },
       AttestationMethod: {
         _enum: ["OptOut"],
@@ -315,10 +322,10 @@ async function handleJob() {
     }
 
     const jobResult = api.createType("JobResult", parsedOut ? parsedOut.result : "Success");
-    const jobOutput = out && out.length > 0 ? api.createType("JobOutput", out) : null;
+    const jobOutput = out && out.length > 0 ? { Inline: api.createType("JobOutput", out) } : null;
 
     logger.info(`Sending "offchain_computing.submitJobResult()`);
-    const txPromise = api.tx.offchainComputingPool.submitJobResult(globalThis.subscribePool, job.id, jobResult, jobOutput, null, null);
+    const txPromise = api.tx.offchainComputingPool.submitJobResult(globalThis.subscribePool, job.id, jobResult, jobOutput, "Plain", null, null);
     logger.debug(`Call hash: ${txPromise.inner.toHex()}`);
     const txHash = await txPromise.signAndSend(globalThis.workerKeyPair, { nonce: -1 });
     logger.info(`Transaction hash: ${txHash.toHex()}`);
@@ -567,6 +574,10 @@ await globalThis.substrateApi.rpc.chain.subscribeNewHeads(async (latestHeader) =
       "impl_spec_version": implSpecVersion,
       "impl_build_version": IMPL_BUILD_VERSION,
       "impl_build_magic_bytes": IMPL_BUILD_MAGIC_BYTES,
+      // TODO: Publish a curve25519 key to accept encrypted jobs
+      "encryption_public_key": null,
+      "capabilities": [],
+      "job_slots": null,
     });
     const payloadSig = globalThis.workerKeyPair.sign(payload.toU8a());
     const attestation = createAttestation(api, u8aToHex(payloadSig));
@@ -738,7 +749,8 @@ await globalThis.substrateApi.rpc.chain.subscribeNewHeads(async (latestHeader) =
       if ((job && globalThis.locals.currentJob === undefined) || globalThis.locals.currentJob.id == job.id) {
         const input = (await api.query.offchainComputingPool.jobInputs(globalThis.subscribePool, job.id)).unwrapOr(null);
         // console.log(input);
-        job.input = input !== null ? u8aToHex(input.data) : "";
+        // TODO: Fetch and verify the referenced input
+        job.input = input !== null && input.data.isInline ? u8aToHex(input.data.asInline) : "";
         job.rawInput = input;
         // console.log(job.input);
 
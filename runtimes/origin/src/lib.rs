@@ -123,7 +123,7 @@ pub fn native_version() -> NativeVersion {
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = migrations::Unreleased;
 
 /// The address format for describing accounts.
 pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
@@ -714,5 +714,6 @@ mod benches {
 		[pallet_sudo, Sudo]
 		[pallet_contracts, Contracts]
 		[pallet_offchain_computing_infra, OffchainComputingInfra]
+		[pallet_offchain_computing_pool, OffchainComputingPool]
 	);
 }
@@ -38,3 +38,9 @@ use frame_support::traits::OnRuntimeUpgrade;
 // are still performed in an offline try-runtime test.
 
 // Add migrations here
+
+/// Migrations that haven't been applied on any live chain yet.
+pub type Unreleased = (
+	pallet_offchain_computing_infra::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_offchain_computing_pool::migrations::v2::MigrateV1ToV2<Runtime>,
+);
@@ -18,7 +18,7 @@
 
 use crate::*;
 use frame_support::traits::{ConstBool, ConstU128, ConstU32};
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 
 impl pallet_offchain_computing_infra::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -28,14 +28,20 @@ impl pallet_offchain_computing_infra::Config for Runtime {
 	type Randomness = pallet_insecure_randomness_collective_flip::Pallet<Runtime>;
 	type ImplId = u32;
 	type RegisterImplOrigin = EnsureSigned<Self::AccountId>;
+	type WorkerForceOrigin = EnsureRoot<Self::AccountId>;
 	type RegisterWorkerDeposit = ConstU128<{ 100 * DOLLARS }>;
 	type RegisterImplDeposit = ConstU128<{ 100 * DOLLARS }>;
 	type ImplMetadataDepositBase = ConstU128<{ DOLLARS }>;
 	type ImplMetadataDepositPerByte = ConstU128<{ CENTS }>;
 	type ImplMetadataLimit = ConstU32<2048>; // 2KiB
 	type MaxImplBuilds = ConstU32<8>;
+	type MaxImplBuildPublishers = ConstU32<8>;
 	type HandleUnresponsivePerBlockLimit = ConstU32<100>;
 	type CollectingHeartbeatsDurationInBlocks = ConstU32<300>; // 30min * 60 / 6
+	type MinCollectingHeartbeatsDurationInBlocks = ConstU32<150>; // 15min * 60 / 6
+	type WorkerDepositUnbondingDurationInBlocks = ConstU32<14400>; // 24h * 60 * 60 / 6
+	type ReapWorkerAfterInactiveBlocks = ConstU32<432000>; // 30d * 24h * 60 * 60 / 6
+	type WorkerReaperFee = ConstU128<{ DOLLARS }>;
 	type DisallowOptOutAttestation = ConstBool<false>;
 	type WeightInfo = pallet_offchain_computing_infra::weights::SubstrateWeight<Runtime>;
 	type OffchainWorkerLifecycleHooks = pallet_offchain_computing_pool::Pallet<Runtime>;
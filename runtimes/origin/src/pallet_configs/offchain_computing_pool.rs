@@ -17,8 +17,17 @@
 // along with Cybros.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
-use frame_support::traits::{ConstU128, ConstU32, ConstU64};
-use frame_system::EnsureSigned;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+
+parameter_types! {
+	pub MaxJobCallbackGasLimit: Weight = Weight::from_parts(100_000_000_000, 256 * 1024);
+	pub RateContractGasLimit: Weight = Weight::from_parts(10_000_000_000, 128 * 1024);
+}
 
 impl pallet_offchain_computing_pool::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -38,6 +47,7 @@ impl pallet_offchain_computing_pool::Config for Runtime {
 	type MaxPoliciesPerPool = ConstU32<8>;
 	type MaxJobsPerPool = ConstU32<1000>;
 	type MaxWorkersPerPool = ConstU32<100>;
+	type TakeJobScanLimit = ConstU32<64>;
 	type MinJobExpiresIn = ConstU64<600>; // ~ 10 min
 	type MaxJobExpiresIn = ConstU64<86400>; // ~ 1 day
 	type DefaultJobExpiresIn = ConstU64<3600>; // ~ 1 hour
@@ -45,4 +55,14 @@ impl pallet_offchain_computing_pool::Config for Runtime {
 	type InputLimit = ConstU32<2048>; // 2KiB
 	type OutputLimit = ConstU32<2048>; // 2KiB
 	type ProofLimit = ConstU32<2048>; // 2KiB
+	type MaxJobCallbackGasLimit = MaxJobCallbackGasLimit;
+	type RemoveItemsLimit = ConstU32<100>;
+	type MaxJobAttempts = ConstU32<5>;
+	type RateContractGasLimit = RateContractGasLimit;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as traits::Verify>::Signer;
+	type WeightInfo = pallet_offchain_computing_pool::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }